@@ -0,0 +1,35 @@
+//! Memory footprint and conversion speed of packed sounds on a large pronunciation dictionary.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use ipa_sounds::{corpus, CompactIpa, Ipa, Sound};
+
+/// Dictionary of about a million sounds, one entry per corpus string, repeated.
+fn dictionary() -> Vec<Ipa> {
+    let entries: Vec<Ipa> = corpus::strings().map(|entry| Ipa::try_from(entry).unwrap()).collect();
+    let sounds: usize = entries.iter().map(|entry| entry.len()).sum();
+    entries.iter().cycle().take(entries.len() * (1_000_000 / sounds.max(1) + 1)).cloned().collect()
+}
+
+fn compact(criterion: &mut Criterion) {
+    let dictionary = dictionary();
+    let compact: Vec<CompactIpa> = dictionary.iter().map(|entry| CompactIpa::from(&**entry)).collect();
+    let sounds: usize = dictionary.iter().map(|entry| entry.len()).sum();
+    println!(
+        "{} sounds: {} bytes as Sound, {} bytes packed",
+        sounds,
+        sounds * size_of::<Sound>(),
+        compact.iter().map(|entry| size_of_val(entry.as_codes())).sum::<usize>()
+    );
+    let mut group = criterion.benchmark_group("compact");
+    group.throughput(Throughput::Elements(sounds as u64));
+    group.bench_function("pack", |bencher| {
+        bencher.iter(|| black_box(&dictionary).iter().map(|entry| CompactIpa::from(&**entry)).collect::<Vec<_>>())
+    });
+    group.bench_function("unpack", |bencher| {
+        bencher.iter(|| black_box(&compact).iter().map(CompactIpa::to_ipa).collect::<Vec<_>>())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, compact);
+criterion_main!(benches);
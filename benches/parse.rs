@@ -0,0 +1,76 @@
+//! Parsing speed on large inputs, compared against the previous char-based parser.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use ipa_sounds::{corpus, Consonants, Error, Ipa, Sound, Vowels};
+
+/// Bases and superscripts of every reduced sound, as the parser writes them.
+fn superscripts() -> Vec<(char, char)> {
+    corpus::sounds()
+        .filter_map(|sound| {
+            let base = match sound {
+                Sound::Vowel { phoneme, is_long: false, is_reduced: true } => char::from(phoneme),
+                Sound::Consonant { phoneme, is_long: false, is_palatalized: false, is_reduced: true, release: None } => {
+                    char::from(phoneme)
+                },
+                _ => return None
+            };
+            Some((base, sound.to_string().chars().next()?))
+        })
+        .collect()
+}
+
+/// Previous approach: collect chars, then probe both enums (and scan for superscripts) per symbol.
+fn char_probing(ipa: &str, superscripts: &[(char, char)]) -> Result<Vec<Sound>, Error> {
+    let ipa: Vec<_> = ipa.chars().collect();
+    (0..ipa.len()).filter_map(|i| {
+        let is_palatalized = ipa.get(i + 1) == Some(&'ʲ');
+        let is_long = ipa.get(i + 1 + is_palatalized as usize) == Some(&'ː');
+        match ipa[i] {
+            'ʲ' | 'ː' => None,
+
+            ' ' => Some(Ok(Sound::Space)),
+
+            symbol => {
+                let (base, is_reduced) = superscripts.iter()
+                    .find(|&&(_, superscript)| superscript == symbol)
+                    .map_or((symbol, false), |&(base, _)| (base, true));
+                Some(match (Consonants::try_from(base), Vowels::try_from(base)) {
+                    (Ok(phoneme), _) => Ok(Sound::Consonant { phoneme, is_long, is_palatalized, is_reduced, release: None }),
+                    (_, Ok(phoneme)) => if is_palatalized {
+                        Err(Error::PalatalizedVowel(symbol))
+                    } else {
+                        Ok(Sound::Vowel { phoneme, is_long, is_reduced })
+                    },
+                    _ => Err(Error::NotYetImplemented(symbol))
+                })
+            }
+        }
+    })
+    .collect()
+}
+
+fn parse(criterion: &mut Criterion) {
+    let superscripts = superscripts();
+    // The previous parser predates releases and tones.
+    let sample = corpus::sounds()
+        .filter(|sound| !matches!(sound, Sound::Consonant { release: Some(_), .. } | Sound::Tone { .. }))
+        .map(|sound| sound.to_string())
+        .collect::<Vec<_>>()
+        .join(" ");
+    assert_eq!(char_probing(&sample, &superscripts).map(Ipa::from_iter), Ipa::try_from(sample.as_str()));
+    let mut group = criterion.benchmark_group("parse");
+    [1, 100].into_iter().for_each(|repeats| {
+        let input = vec![sample.as_str(); repeats].join(" ");
+        group.throughput(Throughput::Bytes(input.len() as u64));
+        group.bench_with_input(BenchmarkId::new("bytes", input.len()), &input, |bencher, input| {
+            bencher.iter(|| Ipa::try_from(black_box(input.as_str())))
+        });
+        group.bench_with_input(BenchmarkId::new("char_probing", input.len()), &input, |bencher, input| {
+            bencher.iter(|| char_probing(black_box(input), &superscripts))
+        });
+    });
+    group.finish();
+}
+
+criterion_group!(benches, parse);
+criterion_main!(benches);
@@ -0,0 +1,54 @@
+//! Allocations avoided by inline and interned storage on a large pronunciation dictionary.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use ipa_sounds::{Generator, Inventory, Ipa, IpaInterner, Phonotactics, SmallIpa, Sound};
+
+/// Dictionary of about a million sounds in generated words of one to six syllables, so that entries
+/// range from a single sound to more than fit inline.
+fn dictionary() -> Vec<String> {
+    let inventory: Inventory = Ipa::try_from("pbtdkɡmnŋfvszʃhlɹjwiɪeɛæaɑɔoʊuʌə").unwrap().iter().copied().collect();
+    let mut sounds = 0;
+    Generator::new(&inventory, Phonotactics::new()).syllables(1..=6)
+        .take_while(|word| {
+            sounds += word.len();
+            sounds < 1_000_000
+        })
+        .map(|word| word.to_string())
+        .collect()
+}
+
+fn small(criterion: &mut Criterion) {
+    let dictionary = dictionary();
+    let ipa: Vec<Ipa> = dictionary.iter().map(|entry| Ipa::try_from(entry.as_str()).unwrap()).collect();
+    let small: Vec<SmallIpa> = ipa.iter().map(|entry| SmallIpa::from(&**entry)).collect();
+    let mut interner = IpaInterner::new();
+    let interned: Vec<_> = ipa.iter().map(|entry| interner.intern(entry)).collect();
+    let sounds: usize = ipa.iter().map(|entry| entry.len()).sum();
+    println!(
+        "{} entries of {} sounds: {} allocations as Ipa, {} as SmallIpa, {} distinct interned in {} bytes",
+        ipa.len(),
+        sounds,
+        ipa.iter().filter(|entry| !entry.is_empty()).count(),
+        small.iter().filter(|entry| entry.spilled()).count(),
+        interner.len(),
+        interner.sound_count() * size_of::<Sound>() + size_of_val(interned.as_slice())
+    );
+    let mut group = criterion.benchmark_group("small");
+    group.throughput(Throughput::Elements(dictionary.len() as u64));
+    group.bench_function("parse ipa", |bencher| {
+        bencher.iter(|| black_box(&dictionary).iter().map(|entry| Ipa::try_from(entry.as_str()).unwrap()).collect::<Vec<_>>())
+    });
+    group.bench_function("parse small", |bencher| {
+        bencher.iter(|| black_box(&dictionary).iter().map(|entry| SmallIpa::try_from(entry.as_str()).unwrap()).collect::<Vec<_>>())
+    });
+    group.bench_function("intern", |bencher| {
+        bencher.iter(|| {
+            let mut interner = IpaInterner::new();
+            black_box(&ipa).iter().map(|entry| interner.intern(entry)).collect::<Vec<_>>()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, small);
+criterion_main!(benches);
@@ -0,0 +1,8 @@
+#![no_main]
+
+use ipa_sounds::Ipa;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|ipa: Ipa| {
+    assert_eq!(Ipa::try_from(ipa.to_string()), Ok(ipa));
+});
@@ -0,0 +1,157 @@
+//! Alignment of two sequences of sounds with phonetic substitution costs.
+
+use alloc::{vec, vec::Vec};
+use crate::{IpaSlice, Sound};
+
+/// Cost of inserting or deleting a sound; substituting costs [`Sound::distance`], which is at most twice as much.
+const GAP_COST: f32 = 0.5;
+
+/// One step of an [`Alignment`], from the first sequence to the second.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AlignmentStep {
+    /// Same sound in both sequences.
+    Match(Sound),
+    /// Sound of the first sequence replaced by a sound of the second.
+    Substitution(Sound, Sound),
+    /// Sound only in the second sequence.
+    Insertion(Sound),
+    /// Sound only in the first sequence.
+    Deletion(Sound)
+}
+
+impl AlignmentStep {
+    /// Cost of the step.
+    pub fn cost(&self) -> f32 {
+        match self {
+            AlignmentStep::Match(_) => 0.0,
+            AlignmentStep::Substitution(from, to) => from.distance(to),
+            AlignmentStep::Insertion(_) | AlignmentStep::Deletion(_) => GAP_COST
+        }
+    }
+}
+
+/// Optimal global alignment of two sequences of sounds, as computed by [`IpaSlice::align`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Alignment {
+    steps: Vec<AlignmentStep>,
+    distance: f32
+}
+
+impl Alignment {
+    /// Steps in order of the sequences.
+    pub fn steps(&self) -> &[AlignmentStep] {
+        &self.steps
+    }
+
+    /// Total cost of the steps: 0.0 for identical sequences.
+    pub fn distance(&self) -> f32 {
+        self.distance
+    }
+}
+
+impl IpaSlice {
+    /// Aligns the sounds with another sequence (Needleman–Wunsch), substituting similar sounds rather than inserting and deleting.
+    ///
+    /// Among equally good alignments, substitutions are preferred to deletions and deletions to insertions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ipa_sounds::{AlignmentStep, Ipa};
+    ///
+    /// let ipa = Ipa::try_from("nʲæn").unwrap();
+    /// let alignment = ipa.align(&Ipa::try_from("æm").unwrap());
+    /// assert_eq!(alignment.steps(), [
+    ///     AlignmentStep::Deletion(ipa[0]),
+    ///     AlignmentStep::Match(ipa[1]),
+    ///     AlignmentStep::Substitution(ipa[2], Ipa::try_from("m").unwrap()[0])
+    /// ]);
+    /// assert!(alignment.distance() > 0.5);
+    /// ```
+    pub fn align(&self, other: &IpaSlice) -> Alignment {
+        #[derive(Clone, Copy)]
+        enum Move { Diagonal, Up, Left }
+
+        let (rows, columns) = (self.len() + 1, other.len() + 1);
+        let mut costs = vec![0.0f32; rows * columns];
+        let mut moves = vec![Move::Diagonal; rows * columns];
+        for i in 1..rows {
+            costs[i * columns] = i as f32 * GAP_COST;
+            moves[i * columns] = Move::Up;
+        }
+        for j in 1..columns {
+            costs[j] = j as f32 * GAP_COST;
+            moves[j] = Move::Left;
+        }
+        for i in 1..rows {
+            for j in 1..columns {
+                let diagonal = costs[(i - 1) * columns + j - 1] + self[i - 1].distance(&other[j - 1]);
+                let up = costs[(i - 1) * columns + j] + GAP_COST;
+                let left = costs[i * columns + j - 1] + GAP_COST;
+                (costs[i * columns + j], moves[i * columns + j]) = if diagonal <= up && diagonal <= left {
+                    (diagonal, Move::Diagonal)
+                } else if up <= left {
+                    (up, Move::Up)
+                } else {
+                    (left, Move::Left)
+                };
+            }
+        }
+
+        let mut steps = Vec::with_capacity(rows.max(columns));
+        let (mut i, mut j) = (rows - 1, columns - 1);
+        while i > 0 || j > 0 {
+            match moves[i * columns + j] {
+                Move::Diagonal => {
+                    i -= 1;
+                    j -= 1;
+                    steps.push(if self[i] == other[j] {
+                        AlignmentStep::Match(self[i])
+                    } else {
+                        AlignmentStep::Substitution(self[i], other[j])
+                    });
+                }
+                Move::Up => {
+                    i -= 1;
+                    steps.push(AlignmentStep::Deletion(self[i]));
+                }
+                Move::Left => {
+                    j -= 1;
+                    steps.push(AlignmentStep::Insertion(other[j]));
+                }
+            }
+        }
+        steps.reverse();
+        Alignment { steps, distance: costs[rows * columns - 1] }
+    }
+}
+
+#[cfg(test)]
+mod align_tests {
+    use super::*;
+    use crate::Ipa;
+
+    #[test]
+    fn test_identical_and_empty() {
+        let ipa = Ipa::try_from("nʲæ mi").unwrap();
+        let alignment = ipa.align(&ipa);
+        assert_eq!(alignment.distance(), 0.0);
+        assert!(alignment.steps().iter().all(|step| matches!(step, AlignmentStep::Match(_))));
+        let empty = Ipa::default();
+        assert_eq!(ipa.align(&empty).distance(), 2.5);
+        assert_eq!(empty.align(&ipa).steps().len(), 5);
+        assert!(empty.align(&empty).steps().is_empty());
+    }
+
+    #[test]
+    fn test_distance_is_sum_of_costs() {
+        let first = Ipa::try_from("pami").unwrap();
+        let second = Ipa::try_from("mæjin").unwrap();
+        let alignment = first.align(&second);
+        let total: f32 = alignment.steps().iter().map(AlignmentStep::cost).sum();
+        assert!((alignment.distance() - total).abs() < 1e-6);
+        assert_eq!(alignment.distance(), second.align(&first).distance());
+    }
+}
@@ -0,0 +1,201 @@
+//! ARPAbet, the notation of English phonemes used by CMUdict and most English speech toolchains.
+
+use alloc::{string::String, vec::Vec};
+use crate::{transliteration::TransliterationError, Ipa, IpaSlice, Sound, Stress, Utterance, Word};
+
+/// ARPAbet phonemes and the IPA they stand for; vowels are marked.
+const ARPABET: &[(&str, &str, bool)] = &[
+    ("AA", "ɑ", true), ("AE", "æ", true), ("AH", "ʌ", true), ("AO", "ɔ", true), ("AW", "aʊ", true),
+    ("AY", "aɪ", true), ("EH", "ɛ", true), ("ER", "ɜɹ", true), ("EY", "eɪ", true), ("IH", "ɪ", true),
+    ("IY", "i", true), ("OW", "oʊ", true), ("OY", "ɔɪ", true), ("UH", "ʊ", true), ("UW", "u", true),
+    ("AX", "ə", true), ("AXR", "əɹ", true), ("IX", "ɨ", true), ("UX", "ʉ", true),
+    ("B", "b", false), ("CH", "tʃ", false), ("D", "d", false), ("DH", "ð", false), ("DX", "ɾ", false),
+    ("F", "f", false), ("G", "ɡ", false), ("HH", "h", false), ("JH", "dʒ", false), ("K", "k", false),
+    ("L", "l", false), ("M", "m", false), ("N", "n", false), ("NG", "ŋ", false), ("P", "p", false),
+    ("R", "ɹ", false), ("S", "s", false), ("SH", "ʃ", false), ("T", "t", false), ("TH", "θ", false), ("V", "v", false),
+    ("W", "w", false), ("Y", "j", false), ("Z", "z", false), ("ZH", "ʒ", false)
+];
+
+/// Options for the ambiguous parts of converting between ARPAbet and IPA.
+///
+/// # Examples
+///
+/// ```
+/// use ipa_sounds::{ArpabetOptions, Ipa};
+///
+/// let options = ArpabetOptions::new().rhotic(false);
+/// assert_eq!(Ipa::from_arpabet_with("B ER1 D", &options).unwrap().to_string(), "ˈbɜːd");
+/// assert_eq!(Ipa::from_arpabet("AH0 B AH1 V").unwrap().to_string(), "əˈbʌv");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ArpabetOptions {
+    schwa_for_unstressed_ah: bool,
+    rhotic: bool
+}
+
+impl Default for ArpabetOptions {
+    fn default() -> Self {
+        Self { schwa_for_unstressed_ah: true, rhotic: true }
+    }
+}
+
+impl ArpabetOptions {
+    /// Creates options reading unstressed "AH0" as \[ə\] and "ER" as rhotic \[ɜɹ\], as in General American.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reads unstressed "AH0" as \[ə\] rather than \[ʌ\].
+    pub fn schwa_for_unstressed_ah(mut self, schwa_for_unstressed_ah: bool) -> Self {
+        self.schwa_for_unstressed_ah = schwa_for_unstressed_ah;
+        self
+    }
+
+    /// Reads "ER" as \[ɜɹ\] (unstressed \[əɹ\]) rather than the non-rhotic \[ɜː\] (unstressed \[ə\]).
+    pub fn rhotic(mut self, rhotic: bool) -> Self {
+        self.rhotic = rhotic;
+        self
+    }
+
+    /// IPA of a phoneme with the given stress.
+    fn ipa(&self, phoneme: &str, stress: Stress) -> Option<&'static str> {
+        let is_stressed = stress != Stress::Unstressed;
+        match phoneme {
+            "AH" if !is_stressed && self.schwa_for_unstressed_ah => Some("ə"),
+            "ER" => Some(match (self.rhotic, is_stressed) {
+                (true, true) => "ɜɹ",
+                (true, false) => "əɹ",
+                (false, true) => "ɜː",
+                (false, false) => "ə"
+            }),
+            _ => ARPABET.iter().find(|&&(known, _, _)| known == phoneme).map(|&(_, ipa, _)| ipa)
+        }
+    }
+}
+
+impl Ipa {
+    /// Reads a word of ARPAbet phonemes separated by whitespace, with stress digits on vowels, using
+    /// the default options.
+    pub fn from_arpabet(arpabet: &str) -> Result<Ipa, TransliterationError> {
+        Ipa::from_arpabet_with(arpabet, &ArpabetOptions::default())
+    }
+
+    /// Reads a word of ARPAbet phonemes with the given options.
+    ///
+    /// Stress digits 1 and 2 give primary and secondary stress; stress marks are placed at the start
+    /// of the syllables as [`IpaSlice::syllabify`] divides them. Unknown phonemes, and phonemes of
+    /// sounds the crate does not support, such as syllabic "EL", are reported by their first letter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ipa_sounds::{Ipa, TransliterationError};
+    ///
+    /// let ipa = Ipa::from_arpabet("F AH0 N EH1 T IH0 K").unwrap();
+    /// assert_eq!(ipa.to_string(), "fəˈnɛtɪk");
+    /// assert_eq!(ipa.to_arpabet(), Ok("F AH0 N EH1 T IH0 K".into()));
+    /// assert_eq!(Ipa::from_arpabet("HH AY1").unwrap().to_string(), "ˈhaɪ");
+    /// assert_eq!(Ipa::from_arpabet("B AA1 T EL0"), Err(TransliterationError::UnknownSymbol('E')));
+    /// ```
+    pub fn from_arpabet_with(arpabet: &str, options: &ArpabetOptions) -> Result<Ipa, TransliterationError> {
+        let mut text = String::new();
+        let mut stresses = Vec::new();
+        for token in arpabet.split_whitespace() {
+            let unknown = TransliterationError::UnknownSymbol(token.chars().next().unwrap_or(' '));
+            let phoneme = token.trim_end_matches(|symbol: char| symbol.is_ascii_digit());
+            let is_vowel = ARPABET.iter().any(|&(known, _, is_vowel)| known == phoneme && is_vowel);
+            let stress = match &token[phoneme.len()..] {
+                "" | "0" if is_vowel || token.len() == phoneme.len() => Stress::Unstressed,
+                "1" if is_vowel => Stress::Primary,
+                "2" if is_vowel => Stress::Secondary,
+                _ => return Err(unknown)
+            };
+            let ipa = options.ipa(phoneme, stress).ok_or(unknown)?;
+            if is_vowel {
+                stresses.push(stress);
+                stresses.extend(ipa.chars().skip(1).filter(|symbol| !"ɹː".contains(*symbol)).map(|_| Stress::Unstressed));
+            }
+            text.push_str(ipa);
+        }
+        let mut stresses = stresses.into_iter();
+        let word = Word::new(Ipa::try_from(text.as_str())?.syllabify().into_iter().flatten()
+            .map(|mut syllable| {
+                syllable.stress = stresses.next().unwrap_or_default();
+                syllable
+            })
+            .collect());
+        Ok(Utterance::new(Vec::from([word])).to_ipa())
+    }
+}
+
+/// Phonemes written for the IPA of the non-rhotic and unstressed readings; later entries win.
+const WRITTEN: &[(&str, &str, bool)] = &[("AH", "ə", true), ("ER", "ɜ", true), ("ER", "əɹ", true)];
+
+/// Symbol of the phoneme of a vowel or consonant.
+fn base(sound: &Sound) -> Option<char> {
+    match *sound {
+        Sound::Vowel { phoneme, .. } => Some(char::from(phoneme)),
+        Sound::Consonant { phoneme, .. } => Some(char::from(phoneme)),
+        Sound::Space | Sound::Stress { .. } | Sound::Tone { .. } => None
+    }
+}
+
+impl IpaSlice {
+    /// Writes the sounds as ARPAbet phonemes separated by spaces, with stress digits on vowels.
+    ///
+    /// Length, palatalization and reduction are dropped, and so are spaces, since ARPAbet transcribes
+    /// single words. Sounds without an ARPAbet phoneme are reported by their symbol.
+    pub fn to_arpabet(&self) -> Result<String, TransliterationError> {
+        let mut tokens: Vec<String> = Vec::new();
+        let mut stress = '0';
+        let mut i = 0;
+        while i < self.len() {
+            match self[i] {
+                Sound::Stress { is_primary } => {
+                    stress = if is_primary { '1' } else { '2' };
+                    i += 1;
+                    continue;
+                },
+                Sound::Space => {
+                    i += 1;
+                    continue;
+                },
+                _ => {}
+            }
+            let (phoneme, length, is_vowel) = ARPABET.iter()
+                .chain(WRITTEN)
+                .filter(|&&(_, ipa, _)| {
+                    let count = ipa.chars().count();
+                    self.get(i..i + count).is_some_and(|sounds| sounds.iter().map(base).eq(ipa.chars().map(Some)))
+                })
+                .map(|&(phoneme, ipa, is_vowel)| (phoneme, ipa.chars().count(), is_vowel))
+                // The last of the longest matches wins.
+                .max_by_key(|&(_, length, _)| length)
+                .ok_or_else(|| TransliterationError::UnknownSymbol(base(&self[i]).unwrap_or(' ')))?;
+            let mut token = String::from(phoneme);
+            if is_vowel {
+                token.push(stress);
+                stress = '0';
+            }
+            tokens.push(token);
+            i += length;
+        }
+        Ok(tokens.join(" "))
+    }
+}
+
+#[cfg(test)]
+mod arpabet_tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        for word in ["AH0 B AW1 T", "HH AH0 L OW1", "CH IH1 L D R AH0 N", "M Y UW2 Z IH0 K AA1 L AH0 JH IY0", "B ER1 D ER0"] {
+            let ipa = Ipa::from_arpabet(word).unwrap();
+            assert_eq!(ipa.to_arpabet().as_deref(), Ok(word), "{}", ipa);
+        }
+        assert_eq!(Ipa::from_arpabet("AH1 B AH0").unwrap().to_string(), "ˈʌbə");
+        assert_eq!(Ipa::from_arpabet("K1"), Err(TransliterationError::UnknownSymbol('K')));
+        assert_eq!(Ipa::try_from("ʁ").unwrap().to_arpabet(), Err(TransliterationError::UnknownSymbol('ʁ')));
+    }
+}
@@ -0,0 +1,399 @@
+//! Articulatory description of phonemes, following the rows and columns of the IPA chart.
+
+use crate::{Consonants, Sound, Vowels};
+
+/// Place of articulation of a consonant, in chart order, followed by the places of the chart's other symbols.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Place {
+    Bilabial,
+    Labiodental,
+    Dental,
+    Alveolar,
+    Postalveolar,
+    Retroflex,
+    Palatal,
+    Velar,
+    Uvular,
+    Pharyngeal,
+    Epiglottal,
+    Glottal,
+    AlveoloPalatal,
+    LabialPalatal,
+    LabialVelar
+}
+
+/// Manner of articulation of a consonant, in chart order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Manner {
+    Plosive,
+    Nasal,
+    Trill,
+    Tap,
+    Fricative,
+    LateralFricative,
+    Approximant,
+    LateralApproximant
+}
+
+/// Voicing of a consonant.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Voicing {
+    Voiceless,
+    Voiced
+}
+
+/// Height of a vowel (openness of the jaw), from close to open.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Height {
+    Close,
+    NearClose,
+    CloseMid,
+    Mid,
+    OpenMid,
+    NearOpen,
+    Open
+}
+
+/// Backness of a vowel, from front to back.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Backness {
+    Front,
+    NearFront,
+    Central,
+    NearBack,
+    Back
+}
+
+/// Articulatory features of a consonant, one cell of the consonant chart.
+///
+/// # Examples
+///
+/// ```
+/// use ipa_sounds::{ConsonantFeatures, Consonants, Manner, Place, Voicing};
+///
+/// let features = ConsonantFeatures { place: Place::Bilabial, manner: Manner::Nasal, voicing: Voicing::Voiced };
+/// assert_eq!(Consonants::try_from(features), Ok(Consonants::VoicedBilabialNasal));
+/// assert_eq!(ConsonantFeatures::from(Consonants::VoicedBilabialNasal), features);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ConsonantFeatures {
+    pub place: Place,
+    pub manner: Manner,
+    pub voicing: Voicing
+}
+
+/// Articulatory features of a vowel, one point of the vowel chart.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VowelFeatures {
+    pub height: Height,
+    pub backness: Backness,
+    pub rounded: bool
+}
+
+impl Consonants {
+    /// Articulatory features.
+    pub const fn features(self) -> ConsonantFeatures {
+        use {Manner::*, Place::*, Voicing::*};
+        let (voicing, place, manner) = match self {
+            Consonants::VoicedAlveolarNasal => (Voiced, Alveolar, Nasal),
+            Consonants::VoicedBilabialNasal => (Voiced, Bilabial, Nasal),
+            Consonants::VoicedPalatalApproximant => (Voiced, Palatal, Approximant),
+            Consonants::VoicelessBilabialPlosive => (Voiceless, Bilabial, Plosive),
+            Consonants::VoicedAlveolarApproximant => (Voiced, Alveolar, Approximant),
+            Consonants::VoicedAlveolarFricative => (Voiced, Alveolar, Fricative),
+            Consonants::VoicedAlveolarLateralApproximant => (Voiced, Alveolar, LateralApproximant),
+            Consonants::VoicedAlveolarLateralFricative => (Voiced, Alveolar, LateralFricative),
+            Consonants::VoicedAlveolarPlosive => (Voiced, Alveolar, Plosive),
+            Consonants::VoicedAlveolarTap => (Voiced, Alveolar, Tap),
+            Consonants::VoicedAlveolarTrill => (Voiced, Alveolar, Trill),
+            Consonants::VoicedAlveoloPalatalFricative => (Voiced, AlveoloPalatal, Fricative),
+            Consonants::VoicedBilabialFricative => (Voiced, Bilabial, Fricative),
+            Consonants::VoicedBilabialPlosive => (Voiced, Bilabial, Plosive),
+            Consonants::VoicedBilabialTrill => (Voiced, Bilabial, Trill),
+            Consonants::VoicedDentalFricative => (Voiced, Dental, Fricative),
+            Consonants::VoicedLabialPalatalApproximant => (Voiced, LabialPalatal, Approximant),
+            Consonants::VoicedLabialVelarApproximant => (Voiced, LabialVelar, Approximant),
+            Consonants::VoicedLabiodentalApproximant => (Voiced, Labiodental, Approximant),
+            Consonants::VoicedLabiodentalFricative => (Voiced, Labiodental, Fricative),
+            Consonants::VoicedLabiodentalNasal => (Voiced, Labiodental, Nasal),
+            Consonants::VoicedLabiodentalTap => (Voiced, Labiodental, Tap),
+            Consonants::VoicedPalatalFricative => (Voiced, Palatal, Fricative),
+            Consonants::VoicedPalatalLateralApproximant => (Voiced, Palatal, LateralApproximant),
+            Consonants::VoicedPalatalNasal => (Voiced, Palatal, Nasal),
+            Consonants::VoicedPalatalPlosive => (Voiced, Palatal, Plosive),
+            Consonants::VoicedPharyngealFricative => (Voiced, Pharyngeal, Fricative),
+            Consonants::VoicedPostalveolarFricative => (Voiced, Postalveolar, Fricative),
+            Consonants::VoicedRetroflexApproximant => (Voiced, Retroflex, Approximant),
+            Consonants::VoicedRetroflexFricative => (Voiced, Retroflex, Fricative),
+            Consonants::VoicedRetroflexLateralApproximant => (Voiced, Retroflex, LateralApproximant),
+            Consonants::VoicedRetroflexNasal => (Voiced, Retroflex, Nasal),
+            Consonants::VoicedRetroflexPlosive => (Voiced, Retroflex, Plosive),
+            Consonants::VoicedRetroflexTap => (Voiced, Retroflex, Tap),
+            Consonants::VoicedUvularFricative => (Voiced, Uvular, Fricative),
+            Consonants::VoicedUvularNasal => (Voiced, Uvular, Nasal),
+            Consonants::VoicedUvularPlosive => (Voiced, Uvular, Plosive),
+            Consonants::VoicedUvularTrill => (Voiced, Uvular, Trill),
+            Consonants::VoicedVelarApproximant => (Voiced, Velar, Approximant),
+            Consonants::VoicedVelarFricative => (Voiced, Velar, Fricative),
+            Consonants::VoicedVelarLateralApproximant => (Voiced, Velar, LateralApproximant),
+            Consonants::VoicedVelarNasal => (Voiced, Velar, Nasal),
+            Consonants::VoicedVelarPlosive => (Voiced, Velar, Plosive),
+            Consonants::VoicelessAlveolarFricative => (Voiceless, Alveolar, Fricative),
+            Consonants::VoicelessAlveolarLateralFricative => (Voiceless, Alveolar, LateralFricative),
+            Consonants::VoicelessAlveolarPlosive => (Voiceless, Alveolar, Plosive),
+            Consonants::VoicelessAlveoloPalatalFricative => (Voiceless, AlveoloPalatal, Fricative),
+            Consonants::VoicelessBilabialFricative => (Voiceless, Bilabial, Fricative),
+            Consonants::VoicelessDentalFricative => (Voiceless, Dental, Fricative),
+            Consonants::VoicelessLabialVelarFricative => (Voiceless, LabialVelar, Fricative),
+            Consonants::VoicelessLabiodentalFricative => (Voiceless, Labiodental, Fricative),
+            Consonants::VoicelessPalatalFricative => (Voiceless, Palatal, Fricative),
+            Consonants::VoicelessPalatalPlosive => (Voiceless, Palatal, Plosive),
+            Consonants::VoicelessPharyngealFricative => (Voiceless, Pharyngeal, Fricative),
+            Consonants::VoicelessPostalveolarFricative => (Voiceless, Postalveolar, Fricative),
+            Consonants::VoicelessRetroflexFricative => (Voiceless, Retroflex, Fricative),
+            Consonants::VoicelessRetroflexPlosive => (Voiceless, Retroflex, Plosive),
+            Consonants::VoicelessUvularFricative => (Voiceless, Uvular, Fricative),
+            Consonants::VoicelessUvularPlosive => (Voiceless, Uvular, Plosive),
+            Consonants::VoicelessVelarFricative => (Voiceless, Velar, Fricative),
+            Consonants::VoicelessVelarPlosive => (Voiceless, Velar, Plosive),
+            Consonants::VoicelessGlottalPlosive => (Voiceless, Glottal, Plosive),
+            Consonants::VoicelessGlottalFricative => (Voiceless, Glottal, Fricative),
+            Consonants::VoicedGlottalFricative => (Voiced, Glottal, Fricative),
+            Consonants::VoicelessEpiglottalPlosive => (Voiceless, Epiglottal, Plosive),
+            Consonants::VoicelessEpiglottalFricative => (Voiceless, Epiglottal, Fricative),
+            Consonants::VoicedEpiglottalFricative => (Voiced, Epiglottal, Fricative)
+        };
+        ConsonantFeatures { place, manner, voicing }
+    }
+
+    /// Place of articulation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ipa_sounds::{Consonants, Place};
+    ///
+    /// assert_eq!(Consonants::VoicedBilabialNasal.place(), Place::Bilabial);
+    /// assert_eq!(Consonants::VoicelessGlottalPlosive.place(), Place::Glottal);
+    /// ```
+    pub const fn place(self) -> Place {
+        self.features().place
+    }
+
+    /// Manner of articulation.
+    pub const fn manner(self) -> Manner {
+        self.features().manner
+    }
+
+    /// Whether the vocal folds vibrate.
+    pub const fn voicing(self) -> Voicing {
+        self.features().voicing
+    }
+}
+
+impl Vowels {
+    /// Articulatory features.
+    pub const fn features(self) -> VowelFeatures {
+        use {Backness::*, Height::*};
+        let (height, backness, rounded) = match self {
+            Vowels::CloseBackRounded => (Close, Back, true),
+            Vowels::CloseBackUnrounded => (Close, Back, false),
+            Vowels::CloseCentralRounded => (Close, Central, true),
+            Vowels::CloseCentralUnrounded => (Close, Central, false),
+            Vowels::CloseFrontRounded => (Close, Front, true),
+            Vowels::CloseFrontUnrounded => (Close, Front, false),
+            Vowels::CloseMidBackRounded => (CloseMid, Back, true),
+            Vowels::CloseMidBackUnrounded => (CloseMid, Back, false),
+            Vowels::CloseMidCentralRounded => (CloseMid, Central, true),
+            Vowels::CloseMidCentralUnrounded => (CloseMid, Central, false),
+            Vowels::CloseMidFrontRounded => (CloseMid, Front, true),
+            Vowels::CloseMidFrontUnrounded => (CloseMid, Front, false),
+            Vowels::MidCentral => (Mid, Central, false),
+            Vowels::NearCloseNearBackRounded => (NearClose, NearBack, true),
+            Vowels::NearCloseNearFrontRounded => (NearClose, NearFront, true),
+            Vowels::NearCloseNearFrontUnrounded => (NearClose, NearFront, false),
+            Vowels::NearOpenFrontUrounded => (NearOpen, Front, false),
+            Vowels::OpenBackUnrounded => (Open, Back, false),
+            Vowels::OpenFrontUnrounded => (Open, Front, false),
+            Vowels::OpenMidBackUnrounded => (OpenMid, Back, false),
+            Vowels::NearOpenCentral => (NearOpen, Central, false),
+            Vowels::OpenBackRounded => (Open, Back, true),
+            Vowels::OpenFrontRounded => (Open, Front, true),
+            Vowels::OpenMidBackRounded => (OpenMid, Back, true),
+            Vowels::OpenMidCentralRounded => (OpenMid, Central, true),
+            Vowels::OpenMidCentralUnrounded => (OpenMid, Central, false),
+            Vowels::OpenMidFrontRounded => (OpenMid, Front, true),
+            Vowels::OpenMidFrontUnrounded => (OpenMid, Front, false)
+        };
+        VowelFeatures { height, backness, rounded }
+    }
+
+    /// Height of the tongue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ipa_sounds::{Backness, Height, Vowels};
+    ///
+    /// let vowel = Vowels::NearOpenFrontUrounded;
+    /// assert_eq!((vowel.height(), vowel.backness(), vowel.is_rounded()), (Height::NearOpen, Backness::Front, false));
+    /// ```
+    pub const fn height(self) -> Height {
+        self.features().height
+    }
+
+    /// Position of the tongue relative to the back of the mouth.
+    pub const fn backness(self) -> Backness {
+        self.features().backness
+    }
+
+    /// Whether the lips are rounded; the mid central vowel counts as unrounded.
+    pub const fn is_rounded(self) -> bool {
+        self.features().rounded
+    }
+}
+
+/// Shorthands for filtering sounds by their features; spaces, stress marks and tones have none of them.
+///
+/// # Examples
+///
+/// ```
+/// let ipa = ipa_sounds::Ipa::try_from("ˈbʲyn").unwrap();
+/// assert_eq!(ipa.iter().filter(|sound| sound.is_consonant()).count(), 2);
+/// assert!(ipa[1].is_plosive() && ipa[1].is_voiced());
+/// assert!(ipa[2].is_front() && ipa[2].is_rounded());
+/// assert!(ipa[3].is_nasal());
+/// assert!(!ipa[0].is_vowel() && !ipa[0].is_voiced());
+/// ```
+impl Sound {
+    /// Whether the sound is a vowel.
+    pub const fn is_vowel(&self) -> bool {
+        matches!(self, Sound::Vowel { .. })
+    }
+
+    /// Whether the sound is a consonant.
+    pub const fn is_consonant(&self) -> bool {
+        matches!(self, Sound::Consonant { .. })
+    }
+
+    /// Whether the sound is a nasal consonant.
+    pub const fn is_nasal(&self) -> bool {
+        matches!(self, Sound::Consonant { phoneme, .. } if matches!(phoneme.manner(), Manner::Nasal))
+    }
+
+    /// Whether the sound is a plosive consonant.
+    pub const fn is_plosive(&self) -> bool {
+        matches!(self, Sound::Consonant { phoneme, .. } if matches!(phoneme.manner(), Manner::Plosive))
+    }
+
+    /// Whether the sound is a front or near-front vowel.
+    pub const fn is_front(&self) -> bool {
+        matches!(self, Sound::Vowel { phoneme, .. } if matches!(phoneme.backness(), Backness::Front | Backness::NearFront))
+    }
+
+    /// Whether the sound is a rounded vowel.
+    pub const fn is_rounded(&self) -> bool {
+        matches!(self, Sound::Vowel { phoneme, .. } if phoneme.is_rounded())
+    }
+
+    /// Whether the sound is a vowel or a voiced consonant.
+    pub const fn is_voiced(&self) -> bool {
+        match self {
+            Sound::Vowel { .. } => true,
+            Sound::Consonant { phoneme, .. } => matches!(phoneme.voicing(), Voicing::Voiced),
+            Sound::Space | Sound::Stress { .. } | Sound::Tone { .. } => false
+        }
+    }
+}
+
+impl From<Consonants> for ConsonantFeatures {
+    fn from(consonant: Consonants) -> Self {
+        consonant.features()
+    }
+}
+
+/// Fails with the features themselves if no supported consonant has them.
+impl TryFrom<ConsonantFeatures> for Consonants {
+    type Error = ConsonantFeatures;
+
+    fn try_from(features: ConsonantFeatures) -> Result<Self, Self::Error> {
+        Self::ALL.iter().copied()
+            .find(|consonant| consonant.features() == features)
+            .ok_or(features)
+    }
+}
+
+impl From<Vowels> for VowelFeatures {
+    fn from(vowel: Vowels) -> Self {
+        vowel.features()
+    }
+}
+
+/// Fails with the features themselves if no supported vowel has them.
+impl TryFrom<VowelFeatures> for Vowels {
+    type Error = VowelFeatures;
+
+    fn try_from(features: VowelFeatures) -> Result<Self, Self::Error> {
+        Self::ALL.iter().copied()
+            .find(|vowel| vowel.features() == features)
+            .ok_or(features)
+    }
+}
+
+#[cfg(test)]
+mod articulation_tests {
+    use super::*;
+
+    #[test]
+    fn test_consonants_match_names() {
+        Consonants::ALL.iter().for_each(|&consonant| {
+            let name = format!("{:?}{:?}{:?}", consonant.voicing(), consonant.place(), consonant.manner());
+            assert_eq!(name.to_lowercase(), consonant.name().replace([' ', '-'], ""));
+        });
+    }
+
+    #[test]
+    fn test_vowels_match_names() {
+        Vowels::ALL.iter().for_each(|&vowel| {
+            let words: Vec<_> = vowel.name().split([' ', '-']).collect();
+            assert_eq!(words.contains(&"rounded"), vowel.is_rounded(), "{:?}", vowel);
+            assert_eq!(words.contains(&"front"), vowel.backness() <= Backness::NearFront, "{:?}", vowel);
+            assert_eq!(words.contains(&"open"), vowel.height() >= Height::OpenMid, "{:?}", vowel);
+        });
+    }
+
+    #[test]
+    fn test_features_round_trip() {
+        Consonants::ALL.iter().for_each(|&consonant| {
+            assert_eq!(Consonants::try_from(ConsonantFeatures::from(consonant)), Ok(consonant));
+        });
+        Vowels::ALL.iter().for_each(|&vowel| assert_eq!(Vowels::try_from(VowelFeatures::from(vowel)), Ok(vowel)));
+        let impossible = ConsonantFeatures { place: Place::Pharyngeal, manner: Manner::Nasal, voicing: Voicing::Voiced };
+        assert_eq!(Consonants::try_from(impossible), Err(impossible));
+    }
+
+    #[test]
+    fn test_sound_predicates() {
+        Vowels::ALL.iter().for_each(|&phoneme| {
+            let sound = Sound::Vowel { phoneme, is_long: false, is_reduced: false };
+            assert!(sound.is_vowel() && sound.is_voiced() && !sound.is_consonant() && !sound.is_nasal());
+            assert_eq!(sound.is_front(), phoneme.backness() <= Backness::NearFront);
+            assert_eq!(sound.is_rounded(), phoneme.is_rounded());
+        });
+        Consonants::ALL.iter().for_each(|&phoneme| {
+            let sound = Sound::Consonant { phoneme, is_long: false, is_palatalized: false, is_reduced: false, release: None };
+            assert!(sound.is_consonant() && !sound.is_vowel() && !sound.is_front() && !sound.is_rounded());
+            assert_eq!(sound.is_nasal(), phoneme.manner() == Manner::Nasal);
+            assert_eq!(sound.is_plosive(), phoneme.manner() == Manner::Plosive);
+            assert_eq!(sound.is_voiced(), phoneme.voicing() == Voicing::Voiced);
+        });
+        assert!(!Sound::Space.is_voiced() && !Sound::Space.is_consonant());
+    }
+}
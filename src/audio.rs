@@ -0,0 +1,280 @@
+//! Rough audio rendering of transcriptions by formant synthesis.
+//!
+//! Vowels and sonorants are a pulse train filtered by resonators at their formants; obstruents are
+//! filtered noise, with a closure before the burst of plosives. The result is intelligible at best,
+//! but enough to hear a transcription.
+
+use alloc::vec::Vec;
+use core::f32::consts::PI;
+use crate::{Consonants, IpaSlice, Manner, Place, Sound, Voicing, Vowels};
+
+/// Second-order resonator, a band-pass filter around a frequency.
+#[derive(Clone, Copy)]
+struct Resonator {
+    a: f32,
+    b: f32,
+    c: f32,
+    outputs: [f32; 2]
+}
+
+impl Resonator {
+    fn new(frequency: f32, bandwidth: f32, sample_rate: f32) -> Self {
+        let c = -(-2.0 * PI * bandwidth / sample_rate).exp();
+        let b = 2.0 * (-PI * bandwidth / sample_rate).exp() * (2.0 * PI * frequency / sample_rate).cos();
+        Resonator { a: 1.0 - b - c, b, c, outputs: [0.0; 2] }
+    }
+
+    fn next(&mut self, input: f32) -> f32 {
+        let output = self.a * input + self.b * self.outputs[0] + self.c * self.outputs[1];
+        self.outputs = [output, self.outputs[0]];
+        output
+    }
+}
+
+/// Source and filters of a stretch of sound.
+#[derive(Clone, Copy)]
+struct Segment {
+    /// Length as a share of the duration of a short sound.
+    length: f32,
+    voicing: f32,
+    noise: f32,
+    /// Formants of the voiced part; without them it is a low voice bar.
+    formants: Option<[f32; 3]>,
+    /// Center frequency of the noise.
+    frequency: f32,
+    /// Rate of the amplitude modulation of trills in Hz.
+    modulation: f32,
+    /// Multiplier of the pitch, raised for stressed vowels.
+    pitch: f32
+}
+
+impl Segment {
+    const SILENCE: Segment = Segment { length: 1.0, voicing: 0.0, noise: 0.0, formants: None, frequency: 1000.0, modulation: 0.0, pitch: 1.0 };
+
+    fn voiced(length: f32, formants: [f32; 3]) -> Self {
+        Segment { length, voicing: 0.6, formants: Some(formants), ..Segment::SILENCE }
+    }
+}
+
+/// Renderer of transcriptions into mono PCM samples from -1.0 to 1.0.
+///
+/// # Examples
+///
+/// ```
+/// use ipa_sounds::{Ipa, Synthesizer};
+///
+/// let synthesizer = Synthesizer::new().sample_rate(8000).duration(100);
+/// let samples = synthesizer.render(&Ipa::try_from("ˈmaː").unwrap());
+/// assert_eq!(samples.len(), 2400);
+/// assert!(samples.iter().all(|sample| sample.abs() <= 1.0));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Synthesizer {
+    sample_rate: u32,
+    duration: u32,
+    pitch: f32
+}
+
+impl Default for Synthesizer {
+    fn default() -> Self {
+        Self { sample_rate: 16000, duration: 90, pitch: 120.0 }
+    }
+}
+
+impl Synthesizer {
+    /// Creates a synthesizer at 16 kHz with short sounds of 90 ms and a pitch of 120 Hz.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the number of samples per second.
+    pub fn sample_rate(mut self, sample_rate: u32) -> Self {
+        self.sample_rate = sample_rate;
+        self
+    }
+
+    /// Sets the duration of a short sound and of a pause between words in milliseconds.
+    pub fn duration(mut self, duration: u32) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Sets the pitch of unstressed voiced sounds in Hz.
+    pub fn pitch(mut self, pitch: f32) -> Self {
+        self.pitch = pitch;
+        self
+    }
+
+    /// Renders the sounds, normalized to a peak of 0.9.
+    ///
+    /// Long sounds last twice as long and reduced ones half as long, palatalized consonants end in a
+    /// short \[j\] glide, and vowels after a stress mark are louder and higher. Tones are not rendered.
+    pub fn render(&self, ipa: &IpaSlice) -> Vec<f32> {
+        let mut samples = Vec::new();
+        let mut state = State { phase: 0.0, noise: 0x2545_F491 };
+        let mut stress = 1.0;
+        for &sound in ipa.iter() {
+            let (segments, scale): (Vec<Segment>, f32) = match sound {
+                Sound::Stress { is_primary } => {
+                    stress = if is_primary { 1.15 } else { 1.07 };
+                    continue;
+                },
+                Sound::Tone { .. } => continue,
+                Sound::Space => (Vec::from([Segment::SILENCE]), 1.0),
+                Sound::Vowel { phoneme, is_long, is_reduced } => {
+                    let segment = Segment { voicing: stress, pitch: stress, ..Segment::voiced(1.0, vowel_formants(phoneme)) };
+                    stress = 1.0;
+                    (Vec::from([segment]), length(is_long, is_reduced))
+                },
+                Sound::Consonant { phoneme, is_long, is_palatalized, is_reduced, .. } => {
+                    let mut segments = consonant(phoneme, is_palatalized);
+                    if is_palatalized {
+                        let glide = Segment::voiced(0.25, vowel_formants(Vowels::CloseFrontUnrounded));
+                        segments.push(Segment { voicing: 0.4, ..glide });
+                    }
+                    (segments, length(is_long, is_reduced))
+                }
+            };
+            for segment in segments {
+                self.render_segment(&mut samples, &mut state, &segment, scale);
+            }
+        }
+        let peak = samples.iter().fold(0.0, |peak: f32, sample| peak.max(sample.abs()));
+        if peak > 0.0 {
+            samples.iter_mut().for_each(|sample| *sample *= 0.9 / peak);
+        }
+        samples
+    }
+
+    fn render_segment(&self, samples: &mut Vec<f32>, state: &mut State, segment: &Segment, scale: f32) {
+        let rate = self.sample_rate as f32;
+        let pitch = self.pitch * segment.pitch;
+        let count = (segment.length * scale * self.duration as f32 * rate / 1000.0) as usize;
+        let mut formants = segment.formants.unwrap_or([250.0, 250.0, 250.0])
+            .map(|frequency| Resonator::new(frequency.min(rate / 2.0 - 1.0), 50.0 + frequency / 20.0, rate));
+        let mut noise = Resonator::new(segment.frequency.min(rate / 2.0 - 1.0), segment.frequency / 3.0, rate);
+        let fade = (0.005 * rate) as usize;
+        for i in 0..count {
+            state.phase = (state.phase + pitch / rate) % 1.0;
+            let pulse = 2.0 * state.phase - 1.0;
+            let voiced = match segment.formants {
+                Some(_) => formants.iter_mut().fold(pulse, |signal, resonator| resonator.next(signal)),
+                None => formants[0].next(pulse)
+            };
+            let mut sample = segment.voicing * voiced + segment.noise * noise.next(state.next_noise());
+            if segment.modulation > 0.0 {
+                sample *= 0.6 + 0.4 * (2.0 * PI * segment.modulation * i as f32 / rate).cos();
+            }
+            let edge = i.min(count - 1 - i);
+            if edge < fade {
+                sample *= edge as f32 / fade as f32;
+            }
+            samples.push(sample);
+        }
+    }
+}
+
+/// Phase of the pulse train and state of the noise generator, carried across sounds.
+struct State {
+    phase: f32,
+    noise: u32
+}
+
+impl State {
+    /// White noise from -1.0 to 1.0 by xorshift.
+    fn next_noise(&mut self) -> f32 {
+        self.noise ^= self.noise << 13;
+        self.noise ^= self.noise >> 17;
+        self.noise ^= self.noise << 5;
+        self.noise as f32 / u32::MAX as f32 * 2.0 - 1.0
+    }
+}
+
+/// Length multiplier of a sound.
+fn length(is_long: bool, is_reduced: bool) -> f32 {
+    match (is_long, is_reduced) {
+        (true, _) => 2.0,
+        (false, true) => 0.5,
+        (false, false) => 1.0
+    }
+}
+
+/// Formants of a vowel for the resonators.
+fn vowel_formants(vowel: Vowels) -> [f32; 3] {
+    let formants = vowel.formants();
+    [formants.f1, formants.f2, formants.f3].map(|frequency| frequency as f32)
+}
+
+/// Center frequency of the noise of obstruents at a place of articulation.
+fn noise_frequency(place: Place) -> f32 {
+    match place {
+        Place::Bilabial | Place::LabialVelar => 1000.0,
+        Place::Labiodental => 6500.0,
+        Place::Dental => 7000.0,
+        Place::Alveolar => 5000.0,
+        Place::Postalveolar => 3000.0,
+        Place::Retroflex => 2500.0,
+        Place::AlveoloPalatal => 3200.0,
+        Place::Palatal => 3500.0,
+        Place::LabialPalatal => 2000.0,
+        Place::Velar => 1800.0,
+        Place::Uvular => 1200.0,
+        Place::Pharyngeal => 900.0,
+        Place::Epiglottal => 800.0,
+        Place::Glottal => 1500.0
+    }
+}
+
+/// Segments of a consonant; palatalization raises the noise towards the palatal region.
+fn consonant(consonant: Consonants, is_palatalized: bool) -> Vec<Segment> {
+    let place = consonant.place();
+    let is_voiced = consonant.voicing() == Voicing::Voiced;
+    let frequency = noise_frequency(place) * if is_palatalized { 1.2 } else { 1.0 };
+    let voicing = if is_voiced { 0.25 } else { 0.0 };
+    match consonant.manner() {
+        Manner::Plosive => Vec::from([
+            Segment { length: 0.7, voicing: voicing / 2.0, ..Segment::SILENCE },
+            Segment { length: 0.3, voicing, noise: 0.6, frequency, ..Segment::SILENCE }
+        ]),
+        Manner::Nasal => {
+            let f2 = match place {
+                Place::Bilabial | Place::Labiodental => 1100.0,
+                Place::Dental | Place::Alveolar | Place::Postalveolar | Place::Retroflex => 1700.0,
+                _ => 2300.0
+            };
+            Vec::from([Segment { voicing: 0.5, ..Segment::voiced(1.0, [250.0, f2, 2700.0]) }])
+        },
+        Manner::Trill => Vec::from([Segment { modulation: 25.0, ..Segment::voiced(1.0, [400.0, 1300.0, 2500.0]) }]),
+        Manner::Tap => Vec::from([Segment::voiced(0.35, [400.0, 1400.0, 2500.0])]),
+        Manner::Fricative => Vec::from([Segment { voicing, noise: 0.5, frequency, ..Segment::SILENCE }]),
+        Manner::LateralFricative => Vec::from([Segment { voicing, noise: 0.4, frequency: 4000.0, ..Segment::SILENCE }]),
+        Manner::Approximant => {
+            let formants = match place {
+                Place::Palatal => vowel_formants(Vowels::CloseFrontUnrounded),
+                Place::LabialPalatal => vowel_formants(Vowels::CloseFrontRounded),
+                Place::LabialVelar => vowel_formants(Vowels::CloseBackRounded),
+                Place::Velar => vowel_formants(Vowels::CloseBackUnrounded),
+                _ => [350.0, 1200.0, 1700.0]
+            };
+            Vec::from([Segment::voiced(1.0, formants)])
+        },
+        Manner::LateralApproximant => Vec::from([Segment::voiced(1.0, [360.0, 1300.0, 2700.0])])
+    }
+}
+
+#[cfg(test)]
+mod audio_tests {
+    use super::*;
+    use crate::Ipa;
+
+    #[test]
+    fn test_render() {
+        let synthesizer = Synthesizer::new().sample_rate(1000).duration(100);
+        let render = |ipa: &str| synthesizer.render(&Ipa::try_from(ipa).unwrap());
+        assert_eq!(render("a").len(), 100);
+        assert_eq!(render("aː").len(), 200);
+        assert_eq!(render("tʲ").len(), 125);
+        assert!(render(" ").iter().all(|&sample| sample == 0.0));
+        assert!(render("").is_empty());
+    }
+}
@@ -0,0 +1,126 @@
+//! Command-line interface: validates, converts and describes IPA, one line of text at a time.
+
+use std::{io::{self, BufRead, Write}, process::ExitCode};
+use clap::{Parser, ValueEnum};
+use ipa_sounds::Ipa;
+
+#[derive(Parser)]
+#[command(name = "ipa", about = "Validate, convert and describe IPA", version)]
+struct Cli {
+    #[arg(value_enum)]
+    command: Command,
+    /// Text to process; lines of stdin are read if it is missing.
+    text: Vec<String>
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Command {
+    /// Checks that every line is valid IPA, printing nothing for valid lines.
+    Validate,
+    /// Converts X-SAMPA to IPA.
+    FromXsampa,
+    /// Converts IPA to X-SAMPA.
+    ToXsampa,
+    /// Converts ARPAbet to IPA.
+    FromArpabet,
+    /// Converts IPA to ARPAbet.
+    ToArpabet,
+    /// Divides IPA into syllables separated by ".".
+    Syllabify,
+    /// Describes every sound of IPA, as tab-separated sounds and descriptions.
+    Describe
+}
+
+impl Command {
+    /// Output for a line of input, `None` if the command prints nothing for it.
+    fn run(&self, line: &str) -> Result<Option<String>, String> {
+        let parse = |line: &str| Ipa::try_from(line).map_err(|error| error.to_string());
+        let output = match self {
+            Command::Validate => return parse(line).map(|_| None),
+            Command::FromXsampa => Ipa::from_xsampa(line).map(|ipa| ipa.to_string()).map_err(|error| error.to_string()),
+            Command::ToXsampa => parse(line).map(|ipa| ipa.to_xsampa()),
+            Command::FromArpabet => Ipa::from_arpabet(line).map(|ipa| ipa.to_string()).map_err(|error| error.to_string()),
+            Command::ToArpabet => parse(line)?.to_arpabet().map_err(|error| error.to_string()),
+            Command::Syllabify => parse(line).map(|ipa| {
+                let words: Vec<String> = ipa.syllabify().iter()
+                    .map(|word| word.iter().map(ToString::to_string).collect::<Vec<_>>().join("."))
+                    .collect();
+                words.join(" ")
+            }),
+            Command::Describe => parse(line).map(|ipa| {
+                let sounds: Vec<String> = ipa.iter().map(|sound| format!("{}\t{:#}", sound, sound)).collect();
+                sounds.join("\t")
+            })
+        };
+        output.map(Some)
+    }
+}
+
+/// Runs the command on every line of the input as it is read, with one output line per input line,
+/// empty for lines that failed, so that outputs line up with their inputs; validation only reports
+/// errors. Returns whether every line succeeded.
+fn process(command: Command, input: impl BufRead, output: &mut impl Write, errors: &mut impl Write) -> io::Result<bool> {
+    let mut is_success = true;
+    for (i, line) in input.lines().enumerate() {
+        let line = line.map_err(|error| io::Error::new(error.kind(), format!("line {}: {}", i + 1, error)))?;
+        match command.run(line.trim()) {
+            Ok(None) => {},
+            Ok(Some(converted)) => writeln!(output, "{}", converted)?,
+            Err(error) => {
+                writeln!(errors, "line {}: {}", i + 1, error)?;
+                if !matches!(command, Command::Validate) {
+                    writeln!(output)?;
+                }
+                is_success = false;
+            }
+        }
+    }
+    Ok(is_success)
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let (mut output, mut errors) = (io::stdout().lock(), io::stderr().lock());
+    let result = if cli.text.is_empty() {
+        process(cli.command, io::stdin().lock(), &mut output, &mut errors)
+    } else {
+        process(cli.command, cli.text.join(" ").as_bytes(), &mut output, &mut errors)
+    };
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(error) => {
+            let _ = writeln!(errors, "error: {}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod cli_tests {
+    use super::*;
+
+    /// Output, errors and success of the command on the input.
+    fn run(command: Command, input: &str) -> (String, String, bool) {
+        let (mut output, mut errors) = (Vec::new(), Vec::new());
+        let is_success = process(command, input.as_bytes(), &mut output, &mut errors).unwrap();
+        (String::from_utf8(output).unwrap(), String::from_utf8(errors).unwrap(), is_success)
+    }
+
+    #[test]
+    fn test_lines() {
+        assert_eq!(run(Command::ToXsampa, "nʲæ\n\nʃ\n"), ("n'{\n\nS\n".into(), String::new(), true));
+        assert_eq!(run(Command::Validate, "ma\nma#\n"), (String::new(), "line 2: '#' is not yet implemented\n".into(), false));
+        assert_eq!(run(Command::Syllabify, "ˈpaˈmi nʲæ").0, "ˈpa.ˈmi nʲæ\n");
+        assert_eq!(run(Command::FromArpabet, "HH AY1").0, "ˈhaɪ\n");
+        assert_eq!(run(Command::ToXsampa, "ma#\nʃ"), ("\nS\n".into(), "line 1: '#' is not yet implemented\n".into(), false));
+        assert_eq!(run(Command::Describe, "ma\nʃ").0.lines().count(), 2);
+    }
+
+    #[test]
+    fn test_invalid_utf8() {
+        let error = process(Command::Validate, &b"ma\n\xff\n"[..], &mut Vec::new(), &mut Vec::new()).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert!(error.to_string().starts_with("line 2: "));
+    }
+}
@@ -0,0 +1,68 @@
+//! IPA Braille, the transcription of IPA in Unicode braille patterns.
+
+use alloc::string::String;
+use crate::{transliteration::{self, Table}, IpaSlice};
+
+/// Braille cells of the supported sounds and modifiers.
+///
+/// Latin letters are written as in literary braille. Other symbols get a cell of their own or a
+/// prefix cell for the modification of a letter: ⠠ for retroflex, ⠸ for small capital, ⠰ for
+/// turned, ⠘ for barred, ⠨ for hooked and ⠐ for other derived letters.
+const BRAILLE: &Table = &[
+    // Vowels
+    ("⠊", "i"), ("⠽", "y"), ("⠘⠊", "ɨ"), ("⠘⠥", "ʉ"), ("⠰⠍", "ɯ"), ("⠥", "u"),
+    ("⠌", "ɪ"), ("⠸⠽", "ʏ"), ("⠳", "ʊ"),
+    ("⠑", "e"), ("⠿", "ø"), ("⠘⠑", "ɘ"), ("⠘⠕", "ɵ"), ("⠨⠛", "ɤ"), ("⠕", "o"),
+    ("⠢", "ə"),
+    ("⠣", "ɛ"), ("⠷", "œ"), ("⠰⠣", "ɜ"), ("⠘⠣", "ɞ"), ("⠬", "ʌ"), ("⠪", "ɔ"),
+    ("⠜", "æ"), ("⠰⠁", "ɐ"),
+    ("⠁", "a"), ("⠸⠷", "ɶ"), ("⠡", "ɑ"), ("⠰⠡", "ɒ"),
+    // Plosives
+    ("⠏", "p"), ("⠃", "b"), ("⠞", "t"), ("⠙", "d"), ("⠠⠞", "ʈ"), ("⠠⠙", "ɖ"), ("⠉", "c"), ("⠘⠚", "ɟ"),
+    ("⠅", "k"), ("⠛", "ɡ"), ("⠟", "q"), ("⠸⠛", "ɢ"), ("⠆", "ʔ"), ("⠘⠆", "ʡ"),
+    // Nasals
+    ("⠍", "m"), ("⠨⠍", "ɱ"), ("⠝", "n"), ("⠠⠝", "ɳ"), ("⠨⠝", "ɲ"), ("⠻", "ŋ"), ("⠸⠝", "ɴ"),
+    // Trills and taps
+    ("⠸⠃", "ʙ"), ("⠗", "r"), ("⠸⠗", "ʀ"), ("⠨⠗", "ɾ"), ("⠠⠗", "ɽ"), ("⠨⠧", "ⱱ"),
+    // Fricatives
+    ("⠨⠋", "ɸ"), ("⠨⠃", "β"), ("⠋", "f"), ("⠧", "v"), ("⠹", "θ"), ("⠫", "ð"), ("⠎", "s"), ("⠵", "z"),
+    ("⠩", "ʃ"), ("⠴", "ʒ"), ("⠠⠎", "ʂ"), ("⠠⠵", "ʐ"), ("⠨⠉", "ç"), ("⠨⠚", "ʝ"), ("⠭", "x"), ("⠐⠛", "ɣ"),
+    ("⠐⠭", "χ"), ("⠰⠸⠗", "ʁ"), ("⠘⠓", "ħ"), ("⠐⠆", "ʕ"), ("⠸⠓", "ʜ"), ("⠘⠐⠆", "ʢ"), ("⠓", "h"), ("⠨⠓", "ɦ"),
+    ("⠘⠇", "ɬ"), ("⠐⠇", "ɮ"),
+    ("⠨⠎", "ɕ"), ("⠨⠵", "ʑ"), ("⠰⠺", "ʍ"),
+    // Approximants
+    ("⠐⠧", "ʋ"), ("⠰⠗", "ɹ"), ("⠠⠰⠗", "ɻ"), ("⠚", "j"), ("⠐⠍", "ɰ"),
+    ("⠇", "l"), ("⠠⠇", "ɭ"), ("⠰⠽", "ʎ"), ("⠸⠇", "ʟ"), ("⠺", "w"), ("⠰⠓", "ɥ"),
+    // Modifiers
+    ("⠒", "ː"), ("⠈⠚", "ʲ"), ("⠄", "ˈ"), ("⠠⠄", "ˌ"), (" ", " ")
+];
+
+impl IpaSlice {
+    /// Writes the sounds in IPA Braille; words stay separated by spaces.
+    ///
+    /// Reduced sounds are written as full ones; releases and tone letters, which have no cells, are kept
+    /// as IPA.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let ipa = ipa_sounds::Ipa::try_from("ˈʃiːp ˈtʲaʈ").unwrap();
+    /// assert_eq!(ipa.to_braille(), "⠄⠩⠊⠒⠏ ⠄⠞⠈⠚⠁⠠⠞");
+    /// ```
+    pub fn to_braille(&self) -> String {
+        transliteration::write(self, BRAILLE)
+    }
+}
+
+#[cfg(test)]
+mod braille_tests {
+    use crate::{corpus, Ipa, Sound};
+
+    #[test]
+    fn test_all_sounds() {
+        let ipa: Ipa = corpus::sounds()
+            .filter(|sound| !matches!(sound, Sound::Space | Sound::Consonant { release: Some(_), .. } | Sound::Tone { .. }))
+            .collect();
+        assert!(ipa.to_braille().chars().all(|cell| ('\u{2800}'..='\u{28FF}').contains(&cell)));
+    }
+}
@@ -0,0 +1,120 @@
+//! Broad transcriptions: sounds without narrow phonetic detail.
+
+use crate::{Ipa, IpaSlice, Sound};
+
+/// Narrow details removed by [`IpaSlice::to_broad_with`].
+///
+/// # Examples
+///
+/// ```
+/// use ipa_sounds::{BroadOptions, Ipa};
+///
+/// let ipa = Ipa::try_from("ˈnʲaːᵊ").unwrap();
+/// assert_eq!(ipa.to_broad().to_string(), "ˈnaə");
+/// assert_eq!(ipa.to_broad_with(&BroadOptions::new().length(false).stress(true)).to_string(), "naːə");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BroadOptions {
+    length: bool,
+    palatalization: bool,
+    reduction: bool,
+    release: bool,
+    stress: bool,
+    tones: bool
+}
+
+impl Default for BroadOptions {
+    fn default() -> Self {
+        Self { length: true, palatalization: true, reduction: true, release: true, stress: false, tones: false }
+    }
+}
+
+impl BroadOptions {
+    /// Creates options removing length, palatalization, reduction and release but keeping stress and tones.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Removes length, e.g. "aː" becomes "a".
+    pub fn length(mut self, length: bool) -> Self {
+        self.length = length;
+        self
+    }
+
+    /// Removes palatalization, e.g. "nʲ" becomes "n".
+    pub fn palatalization(mut self, palatalization: bool) -> Self {
+        self.palatalization = palatalization;
+        self
+    }
+
+    /// Removes reduction, e.g. "ᵊ" becomes "ə".
+    pub fn reduction(mut self, reduction: bool) -> Self {
+        self.reduction = reduction;
+        self
+    }
+
+    /// Removes release, e.g. "t̚" becomes "t".
+    pub fn release(mut self, release: bool) -> Self {
+        self.release = release;
+        self
+    }
+
+    /// Removes stress marks.
+    pub fn stress(mut self, stress: bool) -> Self {
+        self.stress = stress;
+        self
+    }
+
+    /// Removes tones, e.g. "ma˥˩" becomes "ma".
+    pub fn tones(mut self, tones: bool) -> Self {
+        self.tones = tones;
+        self
+    }
+}
+
+impl IpaSlice {
+    /// Broad transcription of the sounds with the default options, for comparing or indexing
+    /// transcriptions at the phonemic level.
+    pub fn to_broad(&self) -> Ipa {
+        self.to_broad_with(&BroadOptions::default())
+    }
+
+    /// Broad transcription of the sounds without the details selected by the options.
+    pub fn to_broad_with(&self, options: &BroadOptions) -> Ipa {
+        self.iter().filter_map(|&sound| match sound {
+            Sound::Vowel { phoneme, is_long, is_reduced } => Some(Sound::Vowel {
+                phoneme,
+                is_long: is_long && !options.length,
+                is_reduced: is_reduced && !options.reduction
+            }),
+            Sound::Consonant { phoneme, is_long, is_palatalized, is_reduced, release } => Some(Sound::Consonant {
+                phoneme,
+                is_long: is_long && !options.length,
+                is_palatalized: is_palatalized && !options.palatalization,
+                is_reduced: is_reduced && !options.reduction,
+                release: release.filter(|_| !options.release)
+            }),
+            Sound::Stress { .. } if options.stress => None,
+            Sound::Tone { .. } if options.tones => None,
+            sound => Some(sound)
+        })
+        .collect()
+    }
+}
+
+#[cfg(test)]
+mod broad_tests {
+    use super::*;
+
+    #[test]
+    fn test_to_broad() {
+        let ipa = Ipa::try_from("ˌtʲːaⁿ ˈmaː").unwrap();
+        assert_eq!(ipa.to_broad().to_string(), "ˌtan ˈma");
+        assert_eq!(ipa.to_broad(), Ipa::try_from("ˌtaⁿ ˈma").unwrap().to_broad());
+        let nothing = BroadOptions::new().length(false).palatalization(false).reduction(false).release(false);
+        assert_eq!(ipa.to_broad_with(&nothing), ipa);
+        let tonal = Ipa::try_from("ma˧˥ ma˥˩").unwrap();
+        assert_eq!(tonal.to_broad(), tonal);
+        assert_eq!(tonal.to_broad_with(&BroadOptions::new().tones(true)).to_string(), "ma ma");
+    }
+}
@@ -0,0 +1,185 @@
+//! Layout of the IPA charts, for rendering them from crate data.
+//!
+//! Vowels have positions on the trapezoid, see [`Vowels::chart_position`], and consonants a grid of
+//! cells, see [`Consonants::chart`].
+
+use crate::{ConsonantFeatures, Consonants, Manner, Place, Voicing, VowelFeatures, Vowels};
+
+/// Columns of the pulmonic consonant chart.
+const PLACES: [Place; 11] = [
+    Place::Bilabial, Place::Labiodental, Place::Dental, Place::Alveolar, Place::Postalveolar, Place::Retroflex,
+    Place::Palatal, Place::Velar, Place::Uvular, Place::Pharyngeal, Place::Glottal
+];
+
+/// Rows of the pulmonic consonant chart.
+const MANNERS: [Manner; 8] = [
+    Manner::Plosive, Manner::Nasal, Manner::Trill, Manner::Tap, Manner::Fricative, Manner::LateralFricative,
+    Manner::Approximant, Manner::LateralApproximant
+];
+
+/// Half of a cell of the consonant chart.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Slot {
+    Consonant(Consonants),
+    /// Possible articulation without a symbol in the crate.
+    Empty,
+    /// Articulation judged impossible, shaded on the chart.
+    Impossible
+}
+
+/// Cell of the consonant chart: the voiceless consonant is written left of the voiced one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Cell {
+    pub voiceless: Slot,
+    pub voiced: Slot
+}
+
+/// Pulmonic consonant chart: a row per manner and a column per place of articulation.
+///
+/// Places outside the chart's columns, such as alveolo-palatal, belong to its other symbols and are
+/// left out.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Table {
+    pub places: [Place; 11],
+    pub manners: [Manner; 8],
+    /// Cells by row and then column.
+    pub cells: [[Cell; 11]; 8]
+}
+
+impl Table {
+    /// Cell of the place and manner, `None` outside the chart.
+    pub fn cell(&self, place: Place, manner: Manner) -> Option<&Cell> {
+        let column = self.places.iter().position(|&known| known == place)?;
+        let row = self.manners.iter().position(|&known| known == manner)?;
+        Some(&self.cells[row][column])
+    }
+}
+
+/// Whether the IPA chart shades the articulation as impossible.
+fn is_impossible(place: Place, manner: Manner, voicing: Voicing) -> bool {
+    use {Manner::*, Place::*};
+    match manner {
+        Plosive => voicing == Voicing::Voiced && matches!(place, Pharyngeal | Glottal),
+        Nasal => matches!(place, Pharyngeal | Glottal),
+        Trill | Tap => matches!(place, Velar | Glottal),
+        Fricative => false,
+        LateralFricative | LateralApproximant => matches!(place, Bilabial | Labiodental | Pharyngeal | Glottal),
+        Approximant => place == Glottal
+    }
+}
+
+impl Consonants {
+    /// Pulmonic consonant chart of the supported consonants.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ipa_sounds::{chart::{Cell, Slot}, Consonants, Manner, Place};
+    ///
+    /// let chart = Consonants::chart();
+    /// assert_eq!(chart.cell(Place::Bilabial, Manner::Plosive), Some(&Cell {
+    ///     voiceless: Slot::Consonant(Consonants::VoicelessBilabialPlosive),
+    ///     voiced: Slot::Consonant(Consonants::VoicedBilabialPlosive)
+    /// }));
+    /// assert_eq!(chart.cell(Place::Pharyngeal, Manner::Nasal).unwrap().voiced, Slot::Impossible);
+    /// assert_eq!(chart.cell(Place::Bilabial, Manner::Tap).unwrap().voiceless, Slot::Empty);
+    /// ```
+    pub fn chart() -> Table {
+        let slot = |place, manner, voicing| match Consonants::try_from(ConsonantFeatures { place, manner, voicing }) {
+            Ok(consonant) => Slot::Consonant(consonant),
+            Err(_) if is_impossible(place, manner, voicing) => Slot::Impossible,
+            Err(_) => Slot::Empty
+        };
+        Table {
+            places: PLACES,
+            manners: MANNERS,
+            cells: MANNERS.map(|manner| PLACES.map(|place| Cell {
+                voiceless: slot(place, manner, Voicing::Voiceless),
+                voiced: slot(place, manner, Voicing::Voiced)
+            }))
+        }
+    }
+}
+
+/// Distance of the vowels of a rounded and unrounded pair from the point between them on the chart.
+const PAIR_OFFSET: f32 = 0.04;
+
+impl Vowels {
+    /// Position on the vowel trapezoid as (x, y), both from 0.0 to 1.0: x from front to back and y from
+    /// close to open.
+    ///
+    /// The points of the chart lie on a trapezoid whose front edge slants from x = 0.04 at the top to
+    /// x = 0.5 at the bottom and whose back edge is upright at x = 0.96. As on the IPA chart, the
+    /// unrounded vowel of a pair is left of its point and the rounded one right of it, so the outer
+    /// vowels of the pairs reach x = 0.0 and x = 1.0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ipa_sounds::Vowels;
+    ///
+    /// assert_eq!(Vowels::MidCentral.chart_position(), (0.615, 0.5));
+    /// assert_eq!(Vowels::CloseFrontUnrounded.chart_position(), (0.0, 0.0));
+    /// assert_eq!(Vowels::OpenBackRounded.chart_position(), (1.0, 1.0));
+    /// ```
+    pub fn chart_position(self) -> (f32, f32) {
+        let y = self.height() as usize as f32 / 6.0;
+        let front = 0.5 * y;
+        let x = front + (1.0 - front) * self.backness() as usize as f32 / 4.0;
+        // Leaves room for the offsets of the pairs at both edges.
+        let x = PAIR_OFFSET + (1.0 - 2.0 * PAIR_OFFSET) * x;
+        let counterpart = VowelFeatures { rounded: !self.is_rounded(), ..self.features() };
+        let offset = match Vowels::try_from(counterpart) {
+            Ok(_) if self.is_rounded() => PAIR_OFFSET,
+            Ok(_) => -PAIR_OFFSET,
+            Err(_) => 0.0
+        };
+        (x + offset, y)
+    }
+
+    /// Every vowel with its symbol and [`Vowels::chart_position`], from close to open and front to back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let points: Vec<_> = ipa_sounds::Vowels::chart_points().collect();
+    /// assert_eq!(points.len(), 28);
+    /// assert_eq!(points[0], ('i', (0.0, 0.0)));
+    /// ```
+    pub fn chart_points() -> impl Iterator<Item = (char, (f32, f32))> {
+        let mut vowels = Vowels::ALL.to_vec();
+        vowels.sort();
+        vowels.into_iter().map(|vowel| (char::from(vowel), vowel.chart_position()))
+    }
+}
+
+#[cfg(test)]
+mod chart_tests {
+    use super::*;
+
+    #[test]
+    fn test_vowel_positions() {
+        for &vowel in Vowels::ALL {
+            let (x, y) = vowel.chart_position();
+            assert!((0.0..=1.0).contains(&x) && (0.0..=1.0).contains(&y), "{:?}", vowel);
+            assert!(x >= (0.5 - PAIR_OFFSET) * y, "{:?}", vowel);
+        }
+        let (schwa, open) = (Vowels::MidCentral.chart_position(), Vowels::NearOpenCentral.chart_position());
+        assert!(schwa.1 < open.1 && schwa.0 < open.0);
+    }
+
+    #[test]
+    fn test_consonant_chart() {
+        let chart = Consonants::chart();
+        let slots = chart.cells.iter().flatten().flat_map(|cell| [cell.voiceless, cell.voiced]);
+        let charted = slots.filter(|slot| matches!(slot, Slot::Consonant(_))).count();
+        let outside = Consonants::ALL.iter().filter(|consonant| !PLACES.contains(&consonant.place())).count();
+        assert_eq!(charted + outside, Consonants::ALL.len());
+        assert!(Consonants::ALL.iter()
+            .all(|consonant| !is_impossible(consonant.place(), consonant.manner(), consonant.voicing())));
+        assert_eq!(chart.cell(Place::LabialVelar, Manner::Approximant), None);
+    }
+}
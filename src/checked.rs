@@ -0,0 +1,166 @@
+//! Construction of sequences from sounds with the guarantees of parsing.
+
+use alloc::vec::Vec;
+use core::{fmt, ops::Range};
+use crate::{to_superscript, Consonants, Ipa, Release, Sound};
+
+/// Reason a vector of sounds was rejected by [`Ipa::from_sounds`] or an edit by [`Ipa::try_push`] and
+/// the like.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SoundsError {
+    /// Reduced sound at the index without a superscript symbol, which no text can write.
+    UnwritableReduction(usize),
+    /// Lateral or nasal release at the index on a consonant other than a plosive, which is read as
+    /// a separate sound.
+    UnwritableRelease(usize),
+    /// Reduced \[n\] at the index after a plain plosive, which is read as its nasal release.
+    AmbiguousNasal(usize),
+    /// Tone at the index after a tone of fewer than three levels, which is read as one contour with it.
+    AdjacentTone(usize),
+    /// Space at the index at either end or after another space.
+    MisplacedSpace(usize)
+}
+
+impl fmt::Display for SoundsError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SoundsError::UnwritableReduction(position) => {
+                write!(formatter, "Reduced sound at {} has no superscript symbol", position)
+            },
+            SoundsError::UnwritableRelease(position) => {
+                write!(formatter, "Release at {} is only written after plosives", position)
+            },
+            SoundsError::AmbiguousNasal(position) => {
+                write!(formatter, "Reduced nasal at {} is read as the release of the plosive before it", position)
+            },
+            SoundsError::AdjacentTone(position) => {
+                write!(formatter, "Tone at {} is read as part of the tone before it", position)
+            },
+            SoundsError::MisplacedSpace(position) => {
+                write!(formatter, "Space at {} is at an end or doubled", position)
+            }
+        }
+    }
+}
+
+impl core::error::Error for SoundsError {}
+
+impl Ipa {
+    /// Constructs a sequence from sounds, checking that writing and parsing it gives it back, and
+    /// with `single_spaces` also that spaces only separate words, one at a time.
+    ///
+    /// Besides reductions and releases that cannot be written, this rejects sounds the parser would
+    /// join with the previous one: a reduced \[n\] after a plain plosive, read as its nasal release,
+    /// and a tone after a tone of fewer than three levels, read as one contour. [`Ipa::canonicalize`]
+    /// rewrites such sequences instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ipa_sounds::{Ipa, Sound, SoundsError};
+    ///
+    /// let sounds: Vec<Sound> = Ipa::try_from("ma  ᵊm").unwrap().into_iter().collect();
+    /// assert_eq!(Ipa::from_sounds(sounds.clone(), false).unwrap().to_string(), "ma  ᵊm");
+    /// assert_eq!(Ipa::from_sounds(sounds, true), Err(SoundsError::MisplacedSpace(3)));
+    /// ```
+    pub fn from_sounds(sounds: Vec<Sound>, single_spaces: bool) -> Result<Ipa, SoundsError> {
+        check(&sounds, 0..sounds.len(), single_spaces)?;
+        Ok(Ipa(sounds, None))
+    }
+
+    /// Constructs a sequence from sounds without any checks, like collecting them.
+    pub fn from_sounds_unchecked(sounds: Vec<Sound>) -> Ipa {
+        Ipa(sounds, None)
+    }
+}
+
+/// Checks the sounds at the positions as [`Ipa::from_sounds`] does, looking at the sounds before them.
+pub(crate) fn check(sounds: &[Sound], positions: Range<usize>, single_spaces: bool) -> Result<(), SoundsError> {
+    for position in positions {
+        let is_unwritable = match sounds[position] {
+            Sound::Vowel { phoneme, is_reduced, .. } => is_reduced && to_superscript(char::from(phoneme)).is_none(),
+            Sound::Consonant { phoneme, is_reduced, release, .. } => {
+                if release.is_some_and(|release| !release.is_allowed(phoneme)) {
+                    return Err(SoundsError::UnwritableRelease(position));
+                }
+                if phoneme == Consonants::VoicedAlveolarNasal && is_reduced && position > 0
+                    && is_plain_plosive(sounds[position - 1]) {
+                    return Err(SoundsError::AmbiguousNasal(position));
+                }
+                is_reduced && to_superscript(char::from(phoneme)).is_none()
+            },
+            Sound::Space => {
+                let is_misplaced = position == 0 || position + 1 == sounds.len()
+                    || sounds[position - 1] == Sound::Space;
+                if single_spaces && is_misplaced {
+                    return Err(SoundsError::MisplacedSpace(position));
+                }
+                false
+            },
+            Sound::Tone { .. } => {
+                if let Some(Sound::Tone { contour }) = position.checked_sub(1).map(|previous| sounds[previous]) {
+                    if contour.levels().len() < 3 {
+                        return Err(SoundsError::AdjacentTone(position));
+                    }
+                }
+                false
+            },
+            Sound::Stress { .. } => false
+        };
+        if is_unwritable {
+            return Err(SoundsError::UnwritableReduction(position));
+        }
+    }
+    Ok(())
+}
+
+/// Whether a reduced [n] written after the sound is read as its nasal release.
+fn is_plain_plosive(sound: Sound) -> bool {
+    matches!(sound, Sound::Consonant { phoneme, is_long: false, is_palatalized: false, release: None, .. }
+        if Release::Nasal.is_allowed(phoneme))
+}
+
+/// Checks the sounds as [`Ipa::from_sounds`] does, allowing any spaces.
+impl TryFrom<Vec<Sound>> for Ipa {
+    type Error = SoundsError;
+
+    fn try_from(sounds: Vec<Sound>) -> Result<Self, Self::Error> {
+        Ipa::from_sounds(sounds, false)
+    }
+}
+
+#[cfg(test)]
+mod checked_tests {
+    use super::*;
+    use crate::Tone;
+
+    #[test]
+    fn test_from_sounds() {
+        let reduced = Sound::Consonant {
+            phoneme: Consonants::VoicedAlveolarTap,
+            is_long: false,
+            is_palatalized: false,
+            is_reduced: true,
+            release: None
+        };
+        assert_eq!(Ipa::try_from(Vec::from([Sound::Space, reduced])), Err(SoundsError::UnwritableReduction(1)));
+        assert_eq!(Ipa::from_sounds(Vec::from([Sound::Space]), true), Err(SoundsError::MisplacedSpace(0)));
+        assert_eq!(Ipa::from_sounds_unchecked(Vec::from([reduced])).len(), 1);
+        let released = Sound::Consonant {
+            phoneme: Consonants::VoicedAlveolarTap,
+            is_long: false,
+            is_palatalized: false,
+            is_reduced: false,
+            release: Some(Release::Lateral)
+        };
+        assert_eq!(Ipa::try_from(Vec::from([released])), Err(SoundsError::UnwritableRelease(0)));
+        let ipa = Ipa::try_from("ˈnʲa ⁿa").unwrap();
+        assert_eq!(Ipa::from_sounds(ipa.iter().copied().collect(), true), Ok(ipa));
+        let sounds = Ipa::try_from("t ⁿ").unwrap().into_iter().filter(|&sound| sound != Sound::Space).collect();
+        assert_eq!(Ipa::from_sounds(sounds, false), Err(SoundsError::AmbiguousNasal(1)));
+        let tone = |levels: &[u8]| Sound::Tone { contour: Tone::new(levels).unwrap() };
+        assert_eq!(Ipa::try_from(Vec::from([tone(&[5]), tone(&[1])])), Err(SoundsError::AdjacentTone(1)));
+        assert!(Ipa::try_from(Vec::from([tone(&[5, 1, 5]), tone(&[1])])).is_ok());
+    }
+}
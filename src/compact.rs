@@ -0,0 +1,197 @@
+//! Packed 16-bit encoding of sounds for storing large numbers of them.
+
+use alloc::vec::Vec;
+use core::fmt;
+use crate::{format::write_sound, Consonants, FormatOptions, Ipa, IpaSlice, Release, Sound, Tone, Vowels};
+
+/// Bits holding the index of the phoneme in [`Vowels::ALL`] or [`Consonants::ALL`].
+const PHONEME: u16 = 0x7F;
+const LONG: u16 = 1 << 7;
+const PALATALIZED: u16 = 1 << 8;
+const REDUCED: u16 = 1 << 9;
+/// Shift of the two bits of the release: none or the position in [`Release::ALL`] plus one.
+const RELEASE_SHIFT: u16 = 10;
+const PRIMARY: u16 = 1;
+/// Bits of each level of a tone, lowest first.
+const LEVEL_BITS: u16 = 3;
+/// Shift of the three bits of the kind of sound: vowel, consonant, space, stress mark or tone.
+const KIND_SHIFT: u16 = 13;
+
+impl Sound {
+    /// Packs the sound into 16 bits, less than half the size of `Sound`, which [`Sound::unpack`] reverses.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ipa_sounds::{Ipa, Sound};
+    ///
+    /// let sound = Ipa::try_from("nʲː").unwrap()[0];
+    /// assert_eq!(Sound::unpack(sound.pack()), Some(sound));
+    /// assert_eq!(Sound::unpack(u16::MAX), None);
+    /// ```
+    pub fn pack(self) -> u16 {
+        match self {
+            Sound::Vowel { phoneme, is_long, is_reduced } => {
+                phoneme as u16 | flag(is_long, LONG) | flag(is_reduced, REDUCED)
+            },
+            Sound::Consonant { phoneme, is_long, is_palatalized, is_reduced, release } => {
+                let release = release.map_or(0, |release| release as u16 + 1);
+                1 << KIND_SHIFT | phoneme as u16 | flag(is_long, LONG) | flag(is_palatalized, PALATALIZED)
+                    | flag(is_reduced, REDUCED) | release << RELEASE_SHIFT
+            },
+            Sound::Space => 2 << KIND_SHIFT,
+            Sound::Stress { is_primary } => 3 << KIND_SHIFT | flag(is_primary, PRIMARY),
+            Sound::Tone { contour } => contour.levels().iter().enumerate()
+                .fold(4 << KIND_SHIFT, |code, (i, &level)| code | (level as u16) << (i as u16 * LEVEL_BITS))
+        }
+    }
+
+    /// Unpacks a sound packed by [`Sound::pack`], `None` for codes it never produces.
+    pub fn unpack(code: u16) -> Option<Sound> {
+        let index = (code & PHONEME) as usize;
+        let has = |bit| code & bit != 0;
+        let sound = match code >> KIND_SHIFT {
+            0 => Sound::Vowel { phoneme: *Vowels::ALL.get(index)?, is_long: has(LONG), is_reduced: has(REDUCED) },
+            1 => Sound::Consonant {
+                phoneme: *Consonants::ALL.get(index)?,
+                is_long: has(LONG),
+                is_palatalized: has(PALATALIZED),
+                is_reduced: has(REDUCED),
+                release: match code >> RELEASE_SHIFT & 3 {
+                    0 => None,
+                    release => Some(Release::ALL[release as usize - 1])
+                }
+            },
+            2 => Sound::Space,
+            3 => Sound::Stress { is_primary: has(PRIMARY) },
+            4 => {
+                let levels = [0, 1, 2].map(|i| (code >> (i * LEVEL_BITS) & ((1 << LEVEL_BITS) - 1)) as u8);
+                let len = levels.iter().position(|&level| level == 0).unwrap_or(levels.len());
+                Sound::Tone { contour: Tone::new(&levels[..len])? }
+            },
+            _ => return None
+        };
+        // Codes with stray bits unpack to a sound that packs differently.
+        (sound.pack() == code).then_some(sound)
+    }
+}
+
+fn flag(is_set: bool, bit: u16) -> u16 {
+    if is_set { bit } else { 0 }
+}
+
+/// Sequence of sounds stored packed, for keeping millions of segments such as a pronunciation
+/// dictionary in memory; it converts to and from [`Ipa`] for everything else.
+///
+/// # Examples
+///
+/// ```
+/// use ipa_sounds::{CompactIpa, Ipa};
+///
+/// let ipa = Ipa::try_from("ˈnʲæ nʲæn").unwrap();
+/// let compact = CompactIpa::from(&*ipa);
+/// assert_eq!(compact.len(), ipa.len());
+/// assert_eq!(compact.get(1), Some(ipa[1]));
+/// assert_eq!(compact.to_string(), "ˈnʲæ nʲæn");
+/// assert_eq!(compact.to_ipa(), ipa);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CompactIpa(Vec<u16>);
+
+impl CompactIpa {
+    /// Creates an empty sequence.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of sounds.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Whether there are no sounds.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Sound at the index.
+    pub fn get(&self, index: usize) -> Option<Sound> {
+        self.0.get(index).map(|&code| unpacked(code))
+    }
+
+    /// Appends a sound.
+    pub fn push(&mut self, sound: Sound) {
+        self.0.push(sound.pack());
+    }
+
+    /// Iterator over the unpacked sounds.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = Sound> + ExactSizeIterator + '_ {
+        self.0.iter().map(|&code| unpacked(code))
+    }
+
+    /// Packed codes of the sounds, as produced by [`Sound::pack`].
+    pub fn as_codes(&self) -> &[u16] {
+        &self.0
+    }
+
+    /// Unpacks the sounds into a new sequence.
+    pub fn to_ipa(&self) -> Ipa {
+        self.iter().collect()
+    }
+}
+
+/// Unpacks a code stored by `CompactIpa`, which only stores codes of sounds.
+fn unpacked(code: u16) -> Sound {
+    Sound::unpack(code).expect("CompactIpa stores only packed sounds")
+}
+
+impl From<&IpaSlice> for CompactIpa {
+    fn from(ipa: &IpaSlice) -> Self {
+        ipa.iter().copied().collect()
+    }
+}
+
+impl From<&CompactIpa> for Ipa {
+    fn from(compact: &CompactIpa) -> Self {
+        compact.to_ipa()
+    }
+}
+
+impl FromIterator<Sound> for CompactIpa {
+    fn from_iter<I: IntoIterator<Item = Sound>>(sounds: I) -> Self {
+        CompactIpa(sounds.into_iter().map(Sound::pack).collect())
+    }
+}
+
+impl Extend<Sound> for CompactIpa {
+    fn extend<I: IntoIterator<Item = Sound>>(&mut self, sounds: I) {
+        self.0.extend(sounds.into_iter().map(Sound::pack))
+    }
+}
+
+impl fmt::Display for CompactIpa {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.iter().try_for_each(|sound| write_sound(formatter, sound, &FormatOptions::default()))
+    }
+}
+
+#[cfg(test)]
+mod compact_tests {
+    use super::*;
+    use crate::corpus;
+
+    #[test]
+    fn test_pack_round_trip() {
+        assert!(Vowels::ALL.iter().enumerate().all(|(i, &phoneme)| phoneme as usize == i));
+        assert!(Consonants::ALL.iter().enumerate().all(|(i, &phoneme)| phoneme as usize == i));
+        let tone = Sound::Tone { contour: Tone::new(&[2, 1, 4]).unwrap() };
+        let marks = [Sound::Space, Sound::Stress { is_primary: true }, Sound::Stress { is_primary: false }, tone];
+        for sound in corpus::sounds().chain(marks) {
+            assert_eq!(Sound::unpack(sound.pack()), Some(sound), "{}", sound);
+        }
+        let codes: Vec<u16> = (0..=u16::MAX).filter(|&code| Sound::unpack(code).is_some()).collect();
+        assert!(codes.iter().all(|&code| Sound::unpack(code).unwrap().pack() == code));
+        assert_eq!(Sound::unpack(PALATALIZED), None);
+        assert!(core::mem::size_of::<Sound>() > 2 * core::mem::size_of::<u16>());
+    }
+}
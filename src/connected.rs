@@ -0,0 +1,242 @@
+//! Connected speech: elision and liaison between words, and assimilation (sandhi) processes.
+//!
+//! The processes are [`Derivation`]s built from ordinary [`Rule`]s, so they can be inspected, extended
+//! or combined with other rules. Each takes whether it applies across word boundaries.
+
+use alloc::vec::Vec;
+use crate::{natural_class::segments, Change, Derivation, Error, Feature, FeatureSpec, Ipa, Matcher, Rule, Sound};
+
+/// Word as listed in a lexicon, with the sounds that depend on the onset of the following word.
+///
+/// # Examples
+///
+/// ```
+/// use ipa_sounds::connected::{connect, LexicalWord};
+///
+/// let words = [
+///     LexicalWord::parse("nə").unwrap().elides(true),
+///     LexicalWord::parse("pəmi(n)").unwrap(),
+///     LexicalWord::parse("ami").unwrap(),
+/// ];
+/// assert_eq!(format!("{}", connect(&words)), "nə pəmin ami");
+/// assert_eq!(format!("{}", connect(&words[1..])), "pəmin ami");
+/// assert_eq!(format!("{}", connect(&[words[0].clone(), words[2].clone()])), "nami");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LexicalWord {
+    /// Sounds that are always pronounced.
+    pub ipa: Ipa,
+    /// Word-final latent sounds, pronounced only before a vowel-initial word (liaison).
+    pub latent: Vec<Sound>,
+    /// Whether the final vowel is deleted before a vowel-initial word (elision).
+    pub elides: bool,
+    /// Whether the word blocks liaison and elision despite starting with a vowel (like French h aspiré).
+    pub blocks_linking: bool
+}
+
+impl LexicalWord {
+    /// Creates a word without latent sounds, elision or linking restrictions.
+    pub fn new(ipa: Ipa) -> Self {
+        LexicalWord {
+            ipa,
+            latent: Vec::new(),
+            elides: false,
+            blocks_linking: false
+        }
+    }
+
+    /// Parses a lexicon entry where latent final sounds are enclosed in parentheses, e.g. "pəmi(n)".
+    pub fn parse(entry: &str) -> Result<Self, Error> {
+        let (ipa, latent) = match entry.strip_suffix(')').and_then(|entry| entry.split_once('(')) {
+            Some((ipa, latent)) => (ipa, Ipa::try_from(latent)?.0),
+            None => (entry, Vec::new())
+        };
+        Ok(LexicalWord {
+            latent,
+            ..Self::new(Ipa::try_from(ipa)?)
+        })
+    }
+
+    /// Sets whether the final vowel is deleted before a vowel-initial word.
+    pub fn elides(mut self, elides: bool) -> Self {
+        self.elides = elides;
+        self
+    }
+
+    /// Sets whether the word blocks liaison and elision of the preceding word.
+    pub fn blocks_linking(mut self, blocks_linking: bool) -> Self {
+        self.blocks_linking = blocks_linking;
+        self
+    }
+
+    fn allows_linking(&self) -> bool {
+        !self.blocks_linking && matches!(self.ipa.first(), Some(Sound::Vowel { .. }))
+    }
+}
+
+/// Connects a sequence of words into a single utterance.
+///
+/// Before a word that starts with a vowel (and does not block linking), latent sounds of the preceding
+/// word surface and its final vowel is elided if it elides, joining the two words without a space.
+/// Elsewhere latent sounds are deleted and words are separated with spaces.
+pub fn connect(words: &[LexicalWord]) -> Ipa {
+    let mut sounds = Vec::new();
+    words.iter().enumerate().for_each(|(i, word)| {
+        let is_linked = words.get(i + 1).is_some_and(LexicalWord::allows_linking);
+        let is_elided = is_linked && word.elides && matches!(word.ipa.last(), Some(Sound::Vowel { .. }));
+        if is_elided {
+            sounds.extend_from_slice(&word.ipa[..word.ipa.len() - 1]);
+        } else {
+            sounds.extend_from_slice(&word.ipa);
+        }
+        if is_linked {
+            sounds.extend_from_slice(&word.latent);
+        }
+        if i + 1 < words.len() && !is_elided {
+            sounds.push(Sound::Space);
+        }
+    });
+    Ipa(sounds, None)
+}
+
+/// Features giving the place of articulation of a consonant.
+const PLACE: [Feature; 10] = [
+    Feature::Labial, Feature::Round, Feature::Labiodental, Feature::Coronal, Feature::Anterior,
+    Feature::Distributed, Feature::Dorsal, Feature::High, Feature::Low, Feature::Back
+];
+
+/// Obstruents: stops and fricatives.
+const OBSTRUENT: FeatureSpec = FeatureSpec::new().plus(Feature::Consonantal).minus(Feature::Sonorant);
+
+/// Right contexts of a rule: directly before the trigger and, across words, before a space and the trigger.
+fn contexts(rule: Rule, trigger: Matcher, across_words: bool) -> impl Iterator<Item = Rule> {
+    let across = across_words.then(|| rule.clone().right([Matcher::WordBoundary, trigger]));
+    [rule.right([trigger])].into_iter().chain(across)
+}
+
+/// Nasals take the place of articulation of a following obstruent, e.g. "input" becomes "imput".
+///
+/// A nasal stays as it is if there is no nasal at the place of the obstruent, e.g. before \[ʃ\].
+///
+/// # Examples
+///
+/// ```
+/// use ipa_sounds::{connected, Ipa};
+///
+/// let ipa = Ipa::try_from("ɪnpʊt ɪn kɑː").unwrap();
+/// assert_eq!(connected::nasal_place_assimilation(false).apply(&ipa).to_string(), "ɪmpʊt ɪn kɑː");
+/// assert_eq!(connected::nasal_place_assimilation(true).apply(&ipa).to_string(), "ɪmpʊt ɪŋ kɑː");
+/// ```
+pub fn nasal_place_assimilation(across_words: bool) -> Derivation {
+    let mut places: Vec<FeatureSpec> = Vec::new();
+    for features in segments().filter(|sound| sound.matches(&OBSTRUENT.minus(Feature::Long))).filter_map(|sound| sound.features()) {
+        let place = PLACE.iter().fold(FeatureSpec::new(), |spec, &feature| {
+            if features.has(feature) { spec.plus(feature) } else { spec.minus(feature) }
+        });
+        if !places.contains(&place) {
+            places.push(place);
+        }
+    }
+    let nasal = FeatureSpec::new().plus(Feature::Nasal);
+    Derivation {
+        rules: places.into_iter()
+            .flat_map(|place| {
+                let trigger = Matcher::Class(PLACE.iter().fold(OBSTRUENT, |spec, &feature| match place.value(feature) {
+                    Some(true) => spec.plus(feature),
+                    _ => spec.minus(feature)
+                }));
+                contexts(Rule::new(nasal, place), trigger, across_words)
+            })
+            .collect()
+    }
+}
+
+/// Obstruent clusters take the voicing of their last obstruent, e.g. "vodka" becomes "votka".
+///
+/// Assimilation is regressive: each application voices or devoices an obstruent before another one,
+/// so longer clusters need [`Derivation::apply_repeatedly`].
+///
+/// # Examples
+///
+/// ```
+/// use ipa_sounds::{connected, Ipa};
+///
+/// let assimilation = connected::voicing_assimilation(true);
+/// let ipa = Ipa::try_from("vodka ras ɡorod").unwrap();
+/// assert_eq!(assimilation.apply(&ipa).to_string(), "votka raz ɡorod");
+/// let ipa = Ipa::try_from("ɡvozdʲ ʃtə").unwrap();
+/// assert_eq!(assimilation.apply(&ipa).to_string(), "ɡvoztʲ ʃtə");
+/// assert_eq!(assimilation.apply_repeatedly(&ipa, 10).to_string(), "ɡvostʲ ʃtə");
+/// ```
+pub fn voicing_assimilation(across_words: bool) -> Derivation {
+    let rules = [true, false].into_iter().flat_map(|is_voiced| {
+        let voicing = if is_voiced { FeatureSpec::new().plus(Feature::Voice) } else { FeatureSpec::new().minus(Feature::Voice) };
+        let trigger = Matcher::Class(if is_voiced { OBSTRUENT.plus(Feature::Voice) } else { OBSTRUENT.minus(Feature::Voice) });
+        contexts(Rule::new(OBSTRUENT, voicing), trigger, across_words)
+    });
+    Derivation { rules: rules.collect() }
+}
+
+/// Long consonants and sequences of the same consonant are shortened, e.g. "ʃːal bɪɡ ɡʌn" becomes
+/// "ʃal bɪ ɡʌn" across words.
+///
+/// # Examples
+///
+/// ```
+/// use ipa_sounds::{connected, Ipa};
+///
+/// let ipa = Ipa::try_from("ʃːal bɪɡ ɡʌn").unwrap();
+/// assert_eq!(connected::degemination(false).apply(&ipa).to_string(), "ʃal bɪɡ ɡʌn");
+/// assert_eq!(connected::degemination(true).apply(&ipa).to_string(), "ʃal bɪ ɡʌn");
+/// ```
+pub fn degemination(across_words: bool) -> Derivation {
+    let shortening = Rule::new(FeatureSpec::new().minus(Feature::Syllabic).plus(Feature::Long), FeatureSpec::new().minus(Feature::Long));
+    let doubles = segments().filter(|sound| matches!(sound, Sound::Consonant { .. })).flat_map(|consonant| {
+        contexts(Rule::new(consonant, Change::Delete), Matcher::Sound(consonant), across_words)
+    });
+    Derivation { rules: core::iter::once(shortening).chain(doubles).collect() }
+}
+
+#[cfg(test)]
+mod connected_tests {
+    use super::*;
+
+    #[test]
+    fn test_liaison() {
+        let words = [
+            LexicalWord::parse("pəmi(n)").unwrap(),
+            LexicalWord::parse("pəmi(n)").unwrap(),
+            LexicalWord::parse("ami").unwrap()
+        ];
+        assert_eq!(connect(&words), Ipa::try_from("pəmi pəmin ami").unwrap());
+    }
+
+    #[test]
+    fn test_blocked_linking() {
+        let words = [
+            LexicalWord::parse("nə").unwrap().elides(true),
+            LexicalWord::parse("ɑʊ").unwrap().blocks_linking(true)
+        ];
+        assert_eq!(connect(&words), Ipa::try_from("nə ɑʊ").unwrap());
+    }
+
+    #[test]
+    fn test_sandhi() {
+        let ipa = Ipa::try_from("unf onq amʃ anːɲ").unwrap();
+        assert_eq!(nasal_place_assimilation(false).apply(&ipa).to_string(), "uɱf oɴq amʃ anːɲ");
+        let ipa = Ipa::try_from("ɡvozdʲ ʃt").unwrap();
+        assert_eq!(voicing_assimilation(false).apply(&ipa).to_string(), "ɡvozdʲ ʃt");
+        assert_eq!(voicing_assimilation(true).apply_repeatedly(&ipa, 10).to_string(), "ɡvostʲ ʃt");
+        assert_eq!(degemination(true).apply(&Ipa::try_from("anːna").unwrap()).to_string(), "ana");
+    }
+
+    #[test]
+    fn test_palatalized_sandhi() {
+        let apply = |derivation: Derivation, ipa: &str| derivation.apply(&Ipa::try_from(ipa).unwrap()).to_string();
+        assert_eq!(apply(nasal_place_assimilation(false), "anɡʲe"), "aŋɡʲe");
+        assert_eq!(apply(nasal_place_assimilation(false), "nʲkʲ mʲtʲ"), "ŋʲkʲ nʲtʲ");
+        assert_eq!(apply(voicing_assimilation(false), "akʲba"), "aɡʲba");
+        assert_eq!(apply(voicing_assimilation(false), "ɡʲdʲ sʲtʲ"), "ɡʲdʲ sʲtʲ");
+        assert_eq!(apply(voicing_assimilation(true), "ɡrʲazʲ kʲə dʲ"), "ɡrʲasʲ kʲə dʲ");
+    }
+}
@@ -0,0 +1,76 @@
+//! Exhaustive corpus of everything the parser accepts, for testing downstream coverage.
+//!
+//! Compound sequences (spaces between sounds) are not enumerated, since every sound is parsed
+//! independently of its neighbours.
+
+use alloc::{string::{String, ToString}, vec};
+use crate::{to_superscript, Consonants, Ipa, Release, Sound, Tone, Vowels};
+
+/// Every sound the parser can produce: each vowel and consonant with every legal combination of
+/// length, palatalization (consonants only), reduction (symbols with a superscript form only) and
+/// release (consonants only), followed by every tone of one to three levels.
+pub fn sounds() -> impl Iterator<Item = Sound> {
+    const FLAGS: [bool; 2] = [false, true];
+    let vowels = Vowels::ALL.iter().flat_map(|&phoneme| {
+        FLAGS.into_iter().flat_map(move |is_long| {
+            FLAGS.into_iter()
+                .filter(move |&is_reduced| !is_reduced || to_superscript(char::from(phoneme)).is_some())
+                .map(move |is_reduced| Sound::Vowel { phoneme, is_long, is_reduced })
+        })
+    });
+    let consonants = Consonants::ALL.iter().flat_map(|&phoneme| {
+        FLAGS.into_iter().flat_map(move |is_long| {
+            FLAGS.into_iter().flat_map(move |is_palatalized| {
+                FLAGS.into_iter()
+                    .filter(move |&is_reduced| !is_reduced || to_superscript(char::from(phoneme)).is_some())
+                    .flat_map(move |is_reduced| {
+                        [None].into_iter().chain(Release::ALL.map(Some))
+                            .filter(move |&release| release.is_none_or(|release| release.is_allowed(phoneme)))
+                            .map(move |release| Sound::Consonant { phoneme, is_long, is_palatalized, is_reduced, release })
+                    })
+            })
+        })
+    });
+    // Levels are the digits of the code in base 5.
+    let tones = (1..=3).flat_map(|len| (0..5usize.pow(len as u32)).map(move |code| {
+        let levels: [u8; 3] = core::array::from_fn(|i| (code / 5usize.pow(i as u32) % 5) as u8 + 1);
+        Sound::Tone { contour: Tone::new(&levels[..len]).unwrap() }
+    }));
+    vowels.chain(consonants).chain(tones)
+}
+
+/// Every sound the parser can produce as a one-sound sequence.
+pub fn ipas() -> impl Iterator<Item = Ipa> {
+    sounds().map(|sound| Ipa(vec![sound], None))
+}
+
+/// IPA strings of every sound the parser can produce.
+///
+/// # Examples
+///
+/// ```
+/// assert!(ipa_sounds::corpus::strings().any(|ipa| ipa == "nʲː"));
+/// ```
+pub fn strings() -> impl Iterator<Item = String> {
+    ipas().map(|ipa| ipa.to_string())
+}
+
+#[cfg(test)]
+mod corpus_tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        ipas().zip(strings()).for_each(|(ipa, string)| {
+            assert_eq!(Ipa::try_from(string.as_str()), Ok(ipa));
+        });
+    }
+
+    #[test]
+    fn test_contents() {
+        assert!(Vowels::ALL.contains(&Vowels::MidCentral));
+        assert!(Consonants::ALL.contains(&Consonants::VoicelessBilabialPlosive));
+        assert!(strings().any(|ipa| ipa == "ᵊ"));
+        assert!(!strings().any(|ipa| ipa == "ʲ"));
+    }
+}
@@ -0,0 +1,120 @@
+//! Explanations of how two transcriptions differ.
+
+use alloc::vec::Vec;
+use core::fmt;
+use crate::{AlignmentStep, IpaSlice, Sound};
+
+/// One difference between two transcriptions, as found by [`IpaSlice::diff`].
+///
+/// Positions are indices in the first transcription; added sounds are placed before the sound at
+/// their position.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DiffOp {
+    /// Sound replaced by a different one.
+    Substituted { position: usize, from: Sound, to: Sound },
+    /// Sound that differs only in length.
+    LengthChanged { position: usize, from: Sound, to: Sound },
+    /// Consonant that differs only in palatalization.
+    PalatalizationChanged { position: usize, from: Sound, to: Sound },
+    /// Sound only in the second transcription.
+    Added { position: usize, sound: Sound },
+    /// Sound only in the first transcription.
+    Removed { position: usize, sound: Sound }
+}
+
+/// Explains the difference in words, e.g. "\[a\] at 1 is lengthened to \[aː\]".
+impl fmt::Display for DiffOp {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            DiffOp::Substituted { position, from, to } => {
+                write!(formatter, "[{}] at {} is replaced by [{}]", from, position, to)
+            },
+            DiffOp::LengthChanged { position, from, to } => {
+                let change = if is_long(to) { "lengthened" } else { "shortened" };
+                write!(formatter, "[{}] at {} is {} to [{}]", from, position, change, to)
+            },
+            DiffOp::PalatalizationChanged { position, from, to } => {
+                let change = if is_palatalized(to) { "palatalized" } else { "depalatalized" };
+                write!(formatter, "[{}] at {} is {} to [{}]", from, position, change, to)
+            },
+            DiffOp::Added { position, sound } => write!(formatter, "[{}] is added at {}", sound, position),
+            DiffOp::Removed { position, sound } => write!(formatter, "[{}] at {} is removed", sound, position)
+        }
+    }
+}
+
+fn is_long(sound: Sound) -> bool {
+    matches!(sound, Sound::Vowel { is_long: true, .. } | Sound::Consonant { is_long: true, .. })
+}
+
+fn is_palatalized(sound: Sound) -> bool {
+    matches!(sound, Sound::Consonant { is_palatalized: true, .. })
+}
+
+/// Sound with the given length and palatalization.
+fn with(sound: Sound, length: bool, palatalization: bool) -> Sound {
+    match sound {
+        Sound::Vowel { phoneme, is_reduced, .. } => Sound::Vowel { phoneme, is_long: length, is_reduced },
+        Sound::Consonant { phoneme, is_reduced, release, .. } => {
+            Sound::Consonant { phoneme, is_long: length, is_palatalized: palatalization, is_reduced, release }
+        },
+        sound => sound
+    }
+}
+
+impl IpaSlice {
+    /// Differences from another transcription, in order, using the alignment of [`IpaSlice::align`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ipa_sounds::Ipa;
+    ///
+    /// let expected = Ipa::try_from("ˈmatʲ").unwrap();
+    /// let said = Ipa::try_from("ˈmaːtʲa").unwrap();
+    /// let explanations: Vec<String> = expected.diff(&said).iter().map(ToString::to_string).collect();
+    /// assert_eq!(explanations, ["[a] at 2 is lengthened to [aː]", "[a] is added at 4"]);
+    /// ```
+    pub fn diff(&self, other: &IpaSlice) -> Vec<DiffOp> {
+        let mut position = 0;
+        self.align(other).steps().iter().filter_map(|&step| {
+            let op = match step {
+                AlignmentStep::Match(_) => None,
+                AlignmentStep::Substitution(from, to) if with(from, is_long(to), is_palatalized(from)) == to => {
+                    Some(DiffOp::LengthChanged { position, from, to })
+                },
+                AlignmentStep::Substitution(from, to) if with(from, is_long(from), is_palatalized(to)) == to => {
+                    Some(DiffOp::PalatalizationChanged { position, from, to })
+                },
+                AlignmentStep::Substitution(from, to) => Some(DiffOp::Substituted { position, from, to }),
+                AlignmentStep::Insertion(sound) => return Some(DiffOp::Added { position, sound }),
+                AlignmentStep::Deletion(sound) => Some(DiffOp::Removed { position, sound })
+            };
+            position += 1;
+            op
+        })
+        .collect()
+    }
+}
+
+#[cfg(test)]
+mod diff_tests {
+    use super::*;
+    use crate::Ipa;
+
+    #[test]
+    fn test_diff() {
+        let ipa = |text| Ipa::try_from(text).unwrap();
+        assert_eq!(ipa("nʲæ").diff(&ipa("nʲæ")), []);
+        let from = ipa("tʲonː");
+        let to = ipa("tomn");
+        assert_eq!(from.diff(&to), [
+            DiffOp::PalatalizationChanged { position: 0, from: from[0], to: to[0] },
+            DiffOp::Added { position: 2, sound: to[2] },
+            DiffOp::LengthChanged { position: 2, from: from[2], to: to[3] }
+        ]);
+        assert_eq!(ipa("pa").diff(&ipa("a"))[0].to_string(), "[p] at 0 is removed");
+        assert_eq!(ipa("pa").diff(&ipa("ba"))[0].to_string(), "[p] at 0 is replaced by [b]");
+    }
+}
@@ -0,0 +1,226 @@
+//! Editing and composition of sequences of IPA sounds.
+//!
+//! Plain editing does not validate. `Sound` rules out some invalid combinations, such as palatalized
+//! vowels, but not all: a sequence can still hold a reduced sound without a superscript form, a
+//! release on a consonant other than a plosive, or sounds that are read back differently once
+//! written. [`Ipa::try_push`], [`Ipa::try_insert`] and [`Ipa::try_splice`] reject such edits with
+//! the checks of [`Ipa::from_sounds`], and [`Ipa::canonicalize`] repairs such sequences.
+
+use alloc::vec::Vec;
+use core::ops::{Add, AddAssign, Bound, RangeBounds};
+use crate::{checked, Ipa, Sound, SoundsError};
+
+impl Ipa {
+    /// Creates an empty sequence.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a sound.
+    pub fn push(&mut self, sound: Sound) {
+        self.0.push(sound);
+    }
+
+    /// Inserts a sound at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    pub fn insert(&mut self, index: usize, sound: Sound) {
+        self.0.insert(index, sound);
+    }
+
+    /// Appends a sound unless the sequence would then not be read back, as [`Ipa::from_sounds`] checks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ipa_sounds::{Ipa, SoundsError};
+    ///
+    /// let mut ipa = Ipa::try_from("t").unwrap();
+    /// let reduced_n = Ipa::try_from("ⁿ").unwrap()[0];
+    /// assert_eq!(ipa.try_push(reduced_n), Err(SoundsError::AmbiguousNasal(1)));
+    /// assert_eq!(ipa.to_string(), "t");
+    /// ```
+    pub fn try_push(&mut self, sound: Sound) -> Result<(), SoundsError> {
+        self.try_insert(self.0.len(), sound)
+    }
+
+    /// Inserts a sound at the given index unless the sequence would then not be read back, as
+    /// [`Ipa::from_sounds`] checks.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    pub fn try_insert(&mut self, index: usize, sound: Sound) -> Result<(), SoundsError> {
+        self.0.insert(index, sound);
+        // The sound after the inserted one is checked too, since its previous sound changed.
+        checked::check(&self.0, index..(index + 2).min(self.0.len()), false).inspect_err(|_| {
+            self.0.remove(index);
+        })
+    }
+
+    /// Removes and returns the sound at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= len`.
+    pub fn remove(&mut self, index: usize) -> Sound {
+        self.0.remove(index)
+    }
+
+    /// Keeps only the sounds for which the predicate returns `true`.
+    pub fn retain(&mut self, predicate: impl FnMut(&Sound) -> bool) {
+        self.0.retain(predicate);
+    }
+
+    /// Replaces the given range with other sounds, returning the removed ones.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ipa_sounds::Ipa;
+    ///
+    /// let mut ipa = Ipa::try_from("nʲæ nʲæn").unwrap();
+    /// let removed = ipa.splice(3.., Ipa::try_from("mæ").unwrap());
+    /// assert_eq!(format!("{}", ipa), "nʲæ mæ");
+    /// assert_eq!(format!("{}", removed), "nʲæn");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds.
+    pub fn splice(&mut self, range: impl RangeBounds<usize>, replace_with: impl IntoIterator<Item = Sound>) -> Ipa {
+        Ipa(self.0.splice(range, replace_with).collect(), None)
+    }
+
+    /// Replaces the given range with other sounds unless the sequence would then not be read back, as
+    /// [`Ipa::from_sounds`] checks, returning the removed ones.
+    ///
+    /// On failure the sequence is left unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds.
+    pub fn try_splice(&mut self, range: impl RangeBounds<usize>, replace_with: impl IntoIterator<Item = Sound>) -> Result<Ipa, SoundsError> {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start + 1,
+            Bound::Unbounded => 0
+        };
+        let len = self.0.len();
+        let removed: Vec<Sound> = self.0.splice(range, replace_with).collect();
+        let end = start + self.0.len() + removed.len() - len;
+        // The sound after the replacement is checked too, since its previous sound changed.
+        match checked::check(&self.0, start..(end + 1).min(self.0.len()), false) {
+            Ok(()) => Ok(Ipa(removed, None)),
+            Err(error) => {
+                self.0.splice(start..end, removed);
+                Err(error)
+            }
+        }
+    }
+
+    /// Shortens the sequence to the given length, doing nothing if it is already shorter.
+    pub fn truncate(&mut self, len: usize) {
+        self.0.truncate(len);
+    }
+
+    /// Concatenates sequences without separators.
+    pub fn concat<'a>(ipas: impl IntoIterator<Item = &'a Ipa>) -> Ipa {
+        ipas.into_iter().flatten().copied().collect()
+    }
+
+    /// Joins sequences (e.g. words into an utterance) with spaces.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ipa_sounds::Ipa;
+    ///
+    /// let words = [Ipa::try_from("nʲæ").unwrap(), Ipa::try_from("nʲæn").unwrap()];
+    /// assert_eq!(format!("{}", Ipa::join(&words)), "nʲæ nʲæn");
+    /// assert_eq!(format!("{}", Ipa::concat(&words)), "nʲænʲæn");
+    /// ```
+    pub fn join<'a>(ipas: impl IntoIterator<Item = &'a Ipa>) -> Ipa {
+        let mut joined = Ipa::new();
+        ipas.into_iter().enumerate().for_each(|(i, ipa)| {
+            if i > 0 {
+                joined.push(Sound::Space);
+            }
+            joined.extend(ipa);
+        });
+        joined
+    }
+}
+
+impl Add for Ipa {
+    type Output = Ipa;
+
+    fn add(mut self, other: Ipa) -> Self::Output {
+        self += other;
+        self
+    }
+}
+
+impl Add<&Ipa> for Ipa {
+    type Output = Ipa;
+
+    fn add(mut self, other: &Ipa) -> Self::Output {
+        self += other;
+        self
+    }
+}
+
+impl AddAssign for Ipa {
+    fn add_assign(&mut self, other: Ipa) {
+        self.extend(other);
+    }
+}
+
+impl AddAssign<&Ipa> for Ipa {
+    fn add_assign(&mut self, other: &Ipa) {
+        self.extend(other);
+    }
+}
+
+#[cfg(test)]
+mod edit_tests {
+    use super::*;
+
+    #[test]
+    fn test_edits() {
+        let mut ipa = Ipa::new();
+        ipa.push(Sound::Space);
+        ipa.insert(0, Ipa::try_from("n").unwrap()[0]);
+        ipa.extend(Ipa::try_from("mæpæ").unwrap());
+        assert_eq!(ipa.remove(1), Sound::Space);
+        ipa.retain(|sound| matches!(sound, Sound::Consonant { .. }));
+        ipa.truncate(2);
+        assert_eq!(ipa, Ipa::try_from("nm").unwrap());
+    }
+
+    #[test]
+    fn test_checked_edits() {
+        let sound = |ipa: &str| Ipa::try_from(ipa).unwrap()[0];
+        let mut ipa = Ipa::try_from("ta˥").unwrap();
+        assert_eq!(ipa.try_push(sound("˩")), Err(SoundsError::AdjacentTone(3)));
+        assert_eq!(ipa.try_insert(1, sound("ⁿ")), Err(SoundsError::AmbiguousNasal(1)));
+        assert_eq!(ipa.try_insert(0, sound("s")), Ok(()));
+        assert_eq!(ipa.try_splice(2.., Ipa::try_from("ⁿa").unwrap()), Err(SoundsError::AmbiguousNasal(2)));
+        assert_eq!(ipa.to_string(), "sta˥");
+        assert_eq!(ipa.try_splice(1..2, Ipa::try_from("ⁿ").unwrap()), Ok(Ipa::try_from("t").unwrap()));
+        assert_eq!(ipa.try_splice(..1, [sound("t")]), Err(SoundsError::AmbiguousNasal(1)));
+        assert_eq!(ipa.try_push(Sound::Space), Ok(()));
+        assert_eq!(ipa.to_string(), "sⁿa˥ ");
+    }
+
+    #[test]
+    fn test_add() {
+        let nja = Ipa::try_from("nʲæ").unwrap();
+        let mut ipa = nja.clone() + &nja;
+        ipa += Ipa::try_from(" n").unwrap();
+        assert_eq!(ipa, Ipa::try_from("nʲænʲæ n").unwrap());
+        assert_eq!(Ipa::join([]), Ipa::new());
+    }
+}
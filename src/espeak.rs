@@ -0,0 +1,66 @@
+//! eSpeak NG phoneme mnemonics, for sending transcriptions straight to eSpeak for audio output.
+
+use alloc::{format, string::String};
+use crate::{transliteration::{self, Table}, IpaSlice};
+
+/// eSpeak NG mnemonics of the supported sounds and modifiers.
+///
+/// They mostly follow Kirshenbaum ASCII-IPA, but eSpeak has its own symbols for some vowels, such
+/// as "3" for ɜ and "0" for ɒ, and writes the trilled r and the uvular fricative as "r" and "R".
+const ESPEAK: &Table = &[
+    // Vowels
+    ("i", "i"), ("y", "y"), ("i\"", "ɨ"), ("u\"", "ʉ"), ("u-", "ɯ"), ("u", "u"),
+    ("I", "ɪ"), ("I.", "ʏ"), ("U", "ʊ"),
+    ("e", "e"), ("Y", "ø"), ("@", "ɘ"), ("8", "ɵ"), ("o-", "ɤ"), ("o", "o"),
+    ("@", "ə"),
+    ("E", "ɛ"), ("W", "œ"), ("3", "ɜ"), ("O\"", "ɞ"), ("V", "ʌ"), ("O", "ɔ"),
+    ("&", "æ"), ("a#", "ɐ"),
+    ("a", "a"), ("a.", "ɶ"), ("A", "ɑ"), ("0", "ɒ"),
+    // Plosives
+    ("p", "p"), ("b", "b"), ("t", "t"), ("d", "d"), ("t.", "ʈ"), ("d.", "ɖ"), ("c", "c"), ("J", "ɟ"),
+    ("k", "k"), ("g", "ɡ"), ("q", "q"), ("G", "ɢ"), ("?", "ʔ"),
+    // Nasals
+    ("m", "m"), ("M", "ɱ"), ("n", "n"), ("n.", "ɳ"), ("n^", "ɲ"), ("N", "ŋ"), ("n\"", "ɴ"),
+    // Trills and taps
+    ("r", "r"), ("r\"", "ʀ"), ("*", "ɾ"), ("*.", "ɽ"),
+    // Fricatives
+    ("F", "ɸ"), ("B", "β"), ("f", "f"), ("v", "v"), ("T", "θ"), ("D", "ð"), ("s", "s"), ("z", "z"),
+    ("S", "ʃ"), ("Z", "ʒ"), ("s.", "ʂ"), ("z.", "ʐ"), ("C", "ç"), ("j\"", "ʝ"), ("x", "x"), ("Q", "ɣ"),
+    ("X", "χ"), ("R", "ʁ"), ("H", "ħ"), ("H<vcd>", "ʕ"), ("h", "h"), ("h<?>", "ɦ"), ("l#", "ɬ"), ("lZ", "ɮ"),
+    ("S;", "ɕ"), ("Z;", "ʑ"), ("w#", "ʍ"),
+    // Approximants
+    ("r", "ɹ"), ("v#", "ʋ"), ("r.", "ɻ"), ("j", "j"), ("Q\"", "ɰ"),
+    ("l", "l"), ("l.", "ɭ"), ("l^", "ʎ"), ("L", "ʟ"), ("w", "w"), ("j<rnd>", "ɥ"),
+    // Modifiers
+    (":", "ː"), (";", "ʲ"), ("'", "ˈ"), (",", "ˌ"), (" ", " ")
+];
+
+impl IpaSlice {
+    /// Writes the sounds as eSpeak NG phoneme mnemonics wrapped in "[[...]]", the form eSpeak reads
+    /// as phoneme input.
+    ///
+    /// English \[ɹ\] is written as eSpeak's "r", which it renders as the approximant. Reduced sounds
+    /// are written as full ones, and sounds eSpeak has no mnemonic for are kept as IPA.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let ipa = ipa_sounds::Ipa::try_from("ˈkætʃ ˌbɜːdz").unwrap();
+    /// assert_eq!(ipa.to_espeak(), "[['k&tS ,b3:dz]]");
+    /// ```
+    pub fn to_espeak(&self) -> String {
+        format!("[[{}]]", transliteration::write(self, ESPEAK))
+    }
+}
+
+#[cfg(test)]
+mod espeak_tests {
+    use crate::Ipa;
+
+    #[test]
+    fn test_to_espeak() {
+        assert_eq!(Ipa::try_from("ˈɹɛd ˈpʲɒt").unwrap().to_espeak(), "[['rEd 'p;0t]]");
+        assert_eq!(Ipa::try_from("ʁəˈɕɐ").unwrap().to_espeak(), "[[R@'S;a#]]");
+        assert_eq!(Ipa::try_from("").unwrap().to_espeak(), "[[]]");
+    }
+}
@@ -0,0 +1,273 @@
+//! Binary distinctive features in the tradition of SPE, derived from the articulatory description.
+
+use core::fmt;
+use crate::{Backness, ConsonantFeatures, Height, Manner, Place, Sound, Voicing, VowelFeatures};
+
+/// Binary distinctive feature.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Feature {
+    Syllabic,
+    Consonantal,
+    Sonorant,
+    Continuant,
+    Nasal,
+    Lateral,
+    Strident,
+    Voice,
+    Labial,
+    Round,
+    Labiodental,
+    Coronal,
+    Anterior,
+    Distributed,
+    Dorsal,
+    High,
+    Low,
+    Front,
+    Back,
+    Tense,
+    Long
+}
+
+impl Feature {
+    /// All features, in the order they are written.
+    pub const ALL: &'static [Self] = &[
+        Feature::Syllabic, Feature::Consonantal, Feature::Sonorant, Feature::Continuant, Feature::Nasal,
+        Feature::Lateral, Feature::Strident, Feature::Voice, Feature::Labial, Feature::Round, Feature::Labiodental,
+        Feature::Coronal, Feature::Anterior, Feature::Distributed, Feature::Dorsal, Feature::High, Feature::Low,
+        Feature::Front, Feature::Back, Feature::Tense, Feature::Long
+    ];
+
+    /// Conventional name, e.g. "consonantal".
+    pub const fn name(self) -> &'static str {
+        match self {
+            Feature::Syllabic => "syllabic",
+            Feature::Consonantal => "consonantal",
+            Feature::Sonorant => "sonorant",
+            Feature::Continuant => "continuant",
+            Feature::Nasal => "nasal",
+            Feature::Lateral => "lateral",
+            Feature::Strident => "strident",
+            Feature::Voice => "voice",
+            Feature::Labial => "labial",
+            Feature::Round => "round",
+            Feature::Labiodental => "labiodental",
+            Feature::Coronal => "coronal",
+            Feature::Anterior => "anterior",
+            Feature::Distributed => "distributed",
+            Feature::Dorsal => "dorsal",
+            Feature::High => "high",
+            Feature::Low => "low",
+            Feature::Front => "front",
+            Feature::Back => "back",
+            Feature::Tense => "tense",
+            Feature::Long => "long"
+        }
+    }
+
+    pub(crate) const fn bit(self) -> u32 {
+        1 << self as u32
+    }
+}
+
+/// Bundle of binary distinctive features: every [`Feature`] is either + or -.
+///
+/// Central vowels are [-front, -back], so that every supported sound gets its own bundle, except that the
+/// mid and open-mid central unrounded vowels 'ə' and 'ɜ' are both [-high, -low, -tense]. Consonants are
+/// only [+front] when palatalized.
+///
+/// # Examples
+///
+/// ```
+/// use ipa_sounds::{Feature, Ipa};
+///
+/// let ipa = Ipa::try_from("mæ").unwrap();
+/// let nasal = ipa[0].features().unwrap();
+/// assert!(nasal.has(Feature::Nasal) && nasal.has(Feature::Voice) && !nasal.has(Feature::Syllabic));
+/// assert!(ipa[1].features().unwrap().has(Feature::Low));
+/// ```
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DistinctiveFeatures(pub(crate) u32);
+
+impl DistinctiveFeatures {
+    /// Bundle with every feature set to -.
+    pub const fn new() -> Self {
+        Self(0)
+    }
+
+    /// Whether the feature is +.
+    pub const fn has(self, feature: Feature) -> bool {
+        self.0 & feature.bit() != 0
+    }
+
+    /// Sets the feature to + or -.
+    pub const fn with(self, feature: Feature, value: bool) -> Self {
+        if value {
+            Self(self.0 | feature.bit())
+        } else {
+            Self(self.0 & !feature.bit())
+        }
+    }
+
+    /// Iterator over all features with their values.
+    pub fn iter(self) -> impl Iterator<Item = (Feature, bool)> {
+        Feature::ALL.iter().map(move |&feature| (feature, self.has(feature)))
+    }
+
+    /// Number of features on which the bundles differ.
+    pub const fn differences(self, other: Self) -> u32 {
+        (self.0 ^ other.0).count_ones()
+    }
+
+    /// Features of a consonant; palatalization adds [+front], keeping the place features, so that
+    /// palatalized velars and uvulars stay apart.
+    pub(crate) const fn consonant(features: ConsonantFeatures, is_long: bool, is_palatalized: bool) -> Self {
+        use Place::*;
+        let ConsonantFeatures { place, manner, voicing } = features;
+        let sonorant = matches!(manner, Manner::Nasal | Manner::Trill | Manner::Tap | Manner::Approximant | Manner::LateralApproximant);
+        let lateral = matches!(manner, Manner::LateralFricative | Manner::LateralApproximant);
+        let continuant = lateral || matches!(manner, Manner::Trill | Manner::Fricative | Manner::Approximant);
+        let strident = matches!(manner, Manner::Fricative)
+            && matches!(place, Labiodental | Alveolar | Postalveolar | Retroflex | AlveoloPalatal | Uvular);
+        Self::new()
+            .with(Feature::Consonantal, !matches!(manner, Manner::Approximant) && !matches!(place, Glottal))
+            .with(Feature::Sonorant, sonorant)
+            .with(Feature::Continuant, continuant)
+            .with(Feature::Nasal, matches!(manner, Manner::Nasal))
+            .with(Feature::Lateral, lateral)
+            .with(Feature::Strident, strident)
+            .with(Feature::Voice, matches!(voicing, Voicing::Voiced))
+            .with(Feature::Labial, matches!(place, Bilabial | Labiodental | LabialPalatal | LabialVelar))
+            .with(Feature::Round, matches!(place, LabialPalatal | LabialVelar))
+            .with(Feature::Labiodental, matches!(place, Labiodental))
+            .with(Feature::Coronal, matches!(place, Dental | Alveolar | Postalveolar | Retroflex | AlveoloPalatal | Palatal))
+            .with(Feature::Anterior, matches!(place, Bilabial | Labiodental | Dental | Alveolar))
+            .with(Feature::Distributed, matches!(place, Dental | Postalveolar | AlveoloPalatal | Palatal))
+            .with(Feature::Dorsal, matches!(place, AlveoloPalatal | Palatal | Velar | Uvular | LabialPalatal | LabialVelar))
+            .with(Feature::High, matches!(place, AlveoloPalatal | Palatal | Velar | LabialPalatal | LabialVelar))
+            .with(Feature::Low, matches!(place, Pharyngeal | Epiglottal | Glottal))
+            .with(Feature::Front, is_palatalized)
+            .with(Feature::Back, matches!(place, Velar | Uvular | Pharyngeal | LabialVelar))
+            .with(Feature::Long, is_long)
+    }
+
+    /// Features of a vowel; the near-close, near-open, mid and open-mid vowels are lax.
+    pub(crate) const fn vowel(features: VowelFeatures, is_long: bool) -> Self {
+        let VowelFeatures { height, backness, rounded } = features;
+        Self::new()
+            .with(Feature::Syllabic, true)
+            .with(Feature::Sonorant, true)
+            .with(Feature::Continuant, true)
+            .with(Feature::Voice, true)
+            .with(Feature::Labial, rounded)
+            .with(Feature::Round, rounded)
+            .with(Feature::Dorsal, true)
+            .with(Feature::High, matches!(height, Height::Close | Height::NearClose))
+            .with(Feature::Low, matches!(height, Height::NearOpen | Height::Open))
+            .with(Feature::Front, matches!(backness, Backness::Front | Backness::NearFront))
+            .with(Feature::Back, matches!(backness, Backness::NearBack | Backness::Back))
+            .with(Feature::Tense, matches!(height, Height::Close | Height::CloseMid | Height::Open))
+            .with(Feature::Long, is_long)
+    }
+}
+
+impl Sound {
+    /// Distinctive features of the sound, `None` for a space, a stress mark or a tone.
+    ///
+    /// Reduction is not a distinctive feature, so reduced sounds have the features of their full forms.
+    pub const fn features(&self) -> Option<DistinctiveFeatures> {
+        match *self {
+            Sound::Vowel { phoneme, is_long, .. } => Some(DistinctiveFeatures::vowel(phoneme.features(), is_long)),
+            Sound::Consonant { phoneme, is_long, is_palatalized, .. } =>
+                Some(DistinctiveFeatures::consonant(phoneme.features(), is_long, is_palatalized)),
+            Sound::Space | Sound::Stress { .. } | Sound::Tone { .. } => None
+        }
+    }
+
+    /// Phonetic distance to another sound, from 0.0 for identical features to 1.0 for opposite ones.
+    ///
+    /// Counts the distinctive features on which the sounds differ; spaces and stress marks are at distance 1.0 from
+    /// every other sound.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let ipa = ipa_sounds::Ipa::try_from("pma").unwrap();
+    /// assert!(ipa[0].distance(&ipa[1]) < ipa[0].distance(&ipa[2]));
+    /// assert_eq!(ipa[2].distance(&ipa[2]), 0.0);
+    /// ```
+    pub fn distance(&self, other: &Sound) -> f32 {
+        match (self.features(), other.features()) {
+            (Some(features), Some(other)) => features.differences(other) as f32 / Feature::ALL.len() as f32,
+            (None, None) if self == other => 0.0,
+            _ => 1.0
+        }
+    }
+}
+
+/// Writes the bundle in brackets, e.g. "[+syllabic, -consonantal, …]".
+impl fmt::Display for DistinctiveFeatures {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("[")?;
+        self.iter().enumerate().try_for_each(|(i, (feature, value))| {
+            let separator = if i > 0 { ", " } else { "" };
+            write!(formatter, "{}{}{}", separator, if value { '+' } else { '-' }, feature.name())
+        })?;
+        formatter.write_str("]")
+    }
+}
+
+impl fmt::Debug for DistinctiveFeatures {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "DistinctiveFeatures{}", self)
+    }
+}
+
+#[cfg(test)]
+mod features_tests {
+    use super::*;
+    use crate::{natural_class::segments, Ipa, Vowels};
+
+    #[test]
+    fn test_bundles_are_distinct() {
+        let sounds: Vec<_> = segments().collect();
+        let central = [Vowels::MidCentral, Vowels::OpenMidCentralUnrounded];
+        sounds.iter().enumerate().for_each(|(i, sound)| {
+            let same: Vec<_> = sounds[..i].iter().filter(|other| other.features() == sound.features()).collect();
+            match (same.as_slice(), sound) {
+                ([], _) => {},
+                ([&Sound::Vowel { phoneme: other, is_long: other_long, .. }], &Sound::Vowel { phoneme, is_long, .. })
+                    if central.contains(&other) && central.contains(&phoneme) && other_long == is_long => {},
+                _ => panic!("{} has the features of {:?}", sound, same)
+            }
+        });
+        let [k, q] = ["kʲ", "qʲ"].map(|ipa| Ipa::try_from(ipa).unwrap()[0]);
+        assert!(k.distance(&q) > 0.0);
+    }
+
+    #[test]
+    fn test_modifiers() {
+        let plain = Ipa::try_from("nnʲnːᵊ").unwrap();
+        let [n, palatalized, long, reduced] = [0, 1, 2, 3].map(|i| plain[i].features().unwrap());
+        assert_eq!(n.differences(palatalized), 1);
+        assert!(palatalized.has(Feature::Front) && !n.has(Feature::Front));
+        assert_eq!(long, n.with(Feature::Long, true));
+        assert!(reduced.has(Feature::Syllabic));
+        assert_eq!(Sound::Space.features(), None);
+        assert!(n.to_string().starts_with("[-syllabic, +consonantal, +sonorant, -continuant, +nasal"));
+    }
+
+    #[test]
+    fn test_distance() {
+        let ipa = Ipa::try_from("nmpiy ").unwrap();
+        ipa.iter().for_each(|sound| {
+            ipa.iter().for_each(|other| assert_eq!(sound.distance(other), other.distance(sound)));
+            assert!((0.0..=1.0).contains(&sound.distance(&Sound::Space)));
+        });
+        assert!(ipa[0].distance(&ipa[1]) < ipa[0].distance(&ipa[2]));
+        assert!(ipa[3].distance(&ipa[4]) < ipa[0].distance(&ipa[3]));
+        assert_eq!(ipa[5].distance(&Sound::Space), 0.0);
+    }
+}
@@ -0,0 +1,187 @@
+//! C-compatible interface for embedding the parser in C, C++ and Swift, suitable for cbindgen.
+//!
+//! Transcriptions are opaque pointers owned by the caller from [`ipa_parse`] until [`ipa_free`],
+//! and strings from [`ipa_to_string`] until [`ipa_string_free`]. Building the crate with the `ffi`
+//! feature produces a shared library to link against; a static one is built with
+//! `cargo rustc --lib --features ffi --crate-type staticlib`.
+
+use alloc::{boxed::Box, ffi::CString, string::ToString};
+use core::ffi::{c_char, CStr};
+use crate::{Ipa, Sound};
+
+/// Kind of an [`IpaSound`].
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IpaSoundKind {
+    Vowel,
+    Consonant,
+    Space,
+    Stress,
+    Tone
+}
+
+/// Sound of a transcription with its phoneme as a Unicode code point, 0 for spaces, stress marks and tones.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IpaSound {
+    pub kind: IpaSoundKind,
+    pub phoneme: u32,
+    pub is_long: bool,
+    pub is_palatalized: bool,
+    pub is_reduced: bool,
+    /// Whether a stress mark is primary.
+    pub is_primary: bool,
+    /// Levels of a tone as decimal digits, e.g. 35, otherwise 0.
+    pub tone: u32,
+    /// Release diacritic of a consonant as a Unicode code point, 0 for none.
+    pub release: u32
+}
+
+impl From<Sound> for IpaSound {
+    fn from(sound: Sound) -> Self {
+        let empty = IpaSound {
+            kind: IpaSoundKind::Space,
+            phoneme: 0,
+            is_long: false,
+            is_palatalized: false,
+            is_reduced: false,
+            is_primary: false,
+            tone: 0,
+            release: 0
+        };
+        match sound {
+            Sound::Vowel { phoneme, is_long, is_reduced } => IpaSound {
+                kind: IpaSoundKind::Vowel,
+                phoneme: char::from(phoneme).into(),
+                is_long,
+                is_reduced,
+                ..empty
+            },
+            Sound::Consonant { phoneme, is_long, is_palatalized, is_reduced, release } => IpaSound {
+                kind: IpaSoundKind::Consonant,
+                phoneme: char::from(phoneme).into(),
+                is_long,
+                is_palatalized,
+                is_reduced,
+                release: release.map_or(0, |release| release.symbol().into()),
+                ..empty
+            },
+            Sound::Space => empty,
+            Sound::Stress { is_primary } => IpaSound { kind: IpaSoundKind::Stress, is_primary, ..empty },
+            Sound::Tone { contour } => IpaSound {
+                kind: IpaSoundKind::Tone,
+                tone: contour.levels().iter().fold(0, |tone, &level| tone * 10 + level as u32),
+                ..empty
+            }
+        }
+    }
+}
+
+/// Parses a NUL-terminated UTF-8 string, returning null if it is not valid UTF-8 or valid IPA.
+///
+/// # Safety
+///
+/// `text` must be null or point to a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn ipa_parse(text: *const c_char) -> *mut Ipa {
+    if text.is_null() {
+        return core::ptr::null_mut();
+    }
+    // SAFETY: the caller guarantees a NUL-terminated string.
+    let text = unsafe { CStr::from_ptr(text) };
+    match text.to_str().ok().and_then(|text| Ipa::try_from(text).ok()) {
+        Some(ipa) => Box::into_raw(Box::new(ipa)),
+        None => core::ptr::null_mut()
+    }
+}
+
+/// Writes the transcription as a NUL-terminated UTF-8 string, null for a null transcription.
+///
+/// # Safety
+///
+/// `ipa` must be null or come from [`ipa_parse`] and not be freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn ipa_to_string(ipa: *const Ipa) -> *mut c_char {
+    // SAFETY: the caller guarantees a live transcription if it is not null.
+    match unsafe { ipa.as_ref() } {
+        Some(ipa) => CString::new(ipa.to_string()).expect("IPA has no NUL").into_raw(),
+        None => core::ptr::null_mut()
+    }
+}
+
+/// Number of sounds of the transcription, 0 for a null transcription.
+///
+/// # Safety
+///
+/// `ipa` must be null or come from [`ipa_parse`] and not be freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn ipa_len(ipa: *const Ipa) -> usize {
+    // SAFETY: the caller guarantees a live transcription if it is not null.
+    unsafe { ipa.as_ref() }.map_or(0, |ipa| ipa.len())
+}
+
+/// Writes the sound at the index to `sound`, returning false if there is none.
+///
+/// # Safety
+///
+/// `ipa` must be null or come from [`ipa_parse`] and not be freed yet, and `sound` must be null or
+/// point to writable memory for an [`IpaSound`].
+#[no_mangle]
+pub unsafe extern "C" fn ipa_get(ipa: *const Ipa, index: usize, sound: *mut IpaSound) -> bool {
+    // SAFETY: the caller guarantees valid pointers if they are not null.
+    match (unsafe { ipa.as_ref() }.and_then(|ipa| ipa.get(index)), unsafe { sound.as_mut() }) {
+        (Some(&found), Some(sound)) => {
+            *sound = IpaSound::from(found);
+            true
+        },
+        _ => false
+    }
+}
+
+/// Frees a transcription; null is ignored.
+///
+/// # Safety
+///
+/// `ipa` must be null or come from [`ipa_parse`] and not be freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn ipa_free(ipa: *mut Ipa) {
+    if !ipa.is_null() {
+        // SAFETY: the caller guarantees the transcription came from `ipa_parse`.
+        drop(unsafe { Box::from_raw(ipa) });
+    }
+}
+
+/// Frees a string from [`ipa_to_string`]; null is ignored.
+///
+/// # Safety
+///
+/// `string` must be null or come from [`ipa_to_string`] and not be freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn ipa_string_free(string: *mut c_char) {
+    if !string.is_null() {
+        // SAFETY: the caller guarantees the string came from `ipa_to_string`.
+        drop(unsafe { CString::from_raw(string) });
+    }
+}
+
+#[cfg(test)]
+mod ffi_tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        unsafe {
+            let ipa = ipa_parse(c"ˈnʲæn".as_ptr());
+            assert_eq!(ipa_len(ipa), 4);
+            let mut sound = IpaSound::from(Sound::Space);
+            assert!(ipa_get(ipa, 1, &mut sound));
+            assert_eq!((sound.kind, sound.phoneme, sound.is_palatalized), (IpaSoundKind::Consonant, 'n' as u32, true));
+            assert!(!ipa_get(ipa, 4, &mut sound));
+            let string = ipa_to_string(ipa);
+            assert_eq!(CStr::from_ptr(string).to_str(), Ok("ˈnʲæn"));
+            ipa_string_free(string);
+            ipa_free(ipa);
+            assert!(ipa_parse(c"x!".as_ptr()).is_null());
+        }
+    }
+}
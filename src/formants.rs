@@ -0,0 +1,102 @@
+//! Typical formant frequencies of vowels, for acoustic phonetics and vowel-space plots.
+
+use crate::{Sound, Vowels};
+
+/// Frequencies of the first three formants in Hz.
+///
+/// F1 falls with the height of the tongue, F2 with its backness, and rounding lowers F2 and F3.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Formants {
+    pub f1: f64,
+    pub f2: f64,
+    pub f3: f64
+}
+
+impl Formants {
+    /// Formants of a neutral vocal tract, the mid central vowel \[ə\].
+    pub const NEUTRAL: Formants = Formants { f1: 500.0, f2: 1500.0, f3: 2500.0 };
+
+    /// Formants a share of the way from these to the other ones: 0.0 gives these, 1.0 the other ones.
+    ///
+    /// Partial rounding, for example, lies between a vowel and its rounded counterpart.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ipa_sounds::{Formants, Vowels};
+    ///
+    /// let (i, y) = (Vowels::CloseFrontUnrounded.formants(), Vowels::CloseFrontRounded.formants());
+    /// let half_rounded = i.interpolate(&y, 0.5);
+    /// assert!(y.f2 < half_rounded.f2 && half_rounded.f2 < i.f2);
+    /// assert_eq!(i.interpolate(&y, 1.0), y);
+    /// ```
+    pub fn interpolate(&self, other: &Formants, share: f64) -> Formants {
+        let mix = |from: f64, to: f64| from + (to - from) * share;
+        Formants { f1: mix(self.f1, other.f1), f2: mix(self.f2, other.f2), f3: mix(self.f3, other.f3) }
+    }
+}
+
+impl Vowels {
+    /// Typical formants of an adult male speaker, estimated from the position of the vowel on the chart.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ipa_sounds::{Formants, Vowels};
+    ///
+    /// assert_eq!(Vowels::CloseFrontUnrounded.formants(), Formants { f1: 280.0, f2: 2300.0, f3: 3000.0 });
+    /// assert!(Vowels::CloseBackRounded.formants().f2 < 900.0);
+    /// ```
+    pub fn formants(self) -> Formants {
+        let height = self.height() as usize as f64 / 6.0;
+        let backness = self.backness() as usize as f64 / 4.0;
+        let f1 = [280.0, 340.0, 400.0, 500.0, 600.0, 720.0, 850.0][self.height() as usize];
+        let front = 2300.0 - 600.0 * height;
+        let back = 1350.0 - 240.0 * height;
+        let mut f2 = front + (back - front) * backness;
+        let mut f3 = 2500.0 + 500.0 * (1.0 - backness) * (1.0 - height);
+        if self.is_rounded() {
+            // Rounding lengthens the vocal tract most for close back vowels.
+            f2 *= 1.0 - (0.17 + 0.2 * backness) * (1.0 - height / 2.0);
+            f3 -= 250.0 * (1.0 - height / 2.0);
+        }
+        Formants { f1, f2, f3 }
+    }
+}
+
+impl Sound {
+    /// Typical formants of a vowel, `None` for other sounds.
+    ///
+    /// Long vowels are a tenth more peripheral than their short counterparts, moving away from the
+    /// neutral formants, and reduced ones lie halfway towards them.
+    pub fn formants(&self) -> Option<Formants> {
+        let Sound::Vowel { phoneme, is_long, is_reduced } = *self else {
+            return None;
+        };
+        let formants = phoneme.formants();
+        Some(match (is_long, is_reduced) {
+            (_, true) => formants.interpolate(&Formants::NEUTRAL, 0.5),
+            (true, false) => formants.interpolate(&Formants::NEUTRAL, -0.1),
+            (false, false) => formants
+        })
+    }
+}
+
+#[cfg(test)]
+mod formants_tests {
+    use super::*;
+    use crate::Ipa;
+
+    #[test]
+    fn test_formants() {
+        for vowel in Vowels::ALL {
+            let formants = vowel.formants();
+            assert!(formants.f1 < formants.f2 && formants.f2 < formants.f3, "{:?}", vowel);
+        }
+        let ipa = Ipa::try_from("aaːᵊa").unwrap();
+        let [short, long, reduced] = [ipa[0], ipa[1], ipa[2]].map(|sound| sound.formants().unwrap());
+        assert!(long.f1 > short.f1 && reduced.f1 < short.f1);
+        assert_eq!(Ipa::try_from("p").unwrap()[0].formants(), None);
+    }
+}
@@ -0,0 +1,226 @@
+//! Writing IPA sounds as text.
+
+use core::fmt::{self, Write};
+use crate::{names::write_name, tone::{TONE_LETTERS, TONE_NUMBERS}, Consonants, IpaSlice, Sound, Tone, Vowels};
+
+/// Options for writing IPA sounds, mirroring the options of [`IpaParser`](crate::IpaParser).
+///
+/// The default options write the same text as `Display`.
+///
+/// # Examples
+///
+/// ```
+/// use ipa_sounds::{FormatOptions, Ipa};
+///
+/// let ipa = Ipa::try_from("nʲaː").unwrap();
+/// let options = FormatOptions::new().ascii_substitutes(true);
+/// assert_eq!(ipa.to_string_with(&options), "n'a:");
+/// assert_eq!(ipa.to_string_with(&options.doubled_as_long(true)), "n'aa");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FormatOptions {
+    ascii_substitutes: bool,
+    doubled_as_long: bool,
+    decomposed: bool,
+    strip_suprasegmentals: bool,
+    tone_numbers: bool
+}
+
+impl FormatOptions {
+    /// Creates options that write standard IPA.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Writes ASCII substitutes: ':' for 'ː', '\'' for 'ʲ' and digits for tones.
+    pub fn ascii_substitutes(mut self, ascii_substitutes: bool) -> Self {
+        self.ascii_substitutes = ascii_substitutes;
+        self
+    }
+
+    /// Writes long sounds as two identical symbols ("nː" as "nn").
+    ///
+    /// Two adjacent identical short sounds are then indistinguishable from one long sound.
+    pub fn doubled_as_long(mut self, doubled_as_long: bool) -> Self {
+        self.doubled_as_long = doubled_as_long;
+        self
+    }
+
+    /// Writes precomposed letters as a base letter and combining diacritics ("ç" as "c\u{327}").
+    ///
+    /// The parser reads only the precomposed letters.
+    pub fn decomposed(mut self, decomposed: bool) -> Self {
+        self.decomposed = decomposed;
+        self
+    }
+
+    /// Writes tones as superscript tone numbers ("³⁵") rather than tone letters ("˧˥").
+    pub fn tone_numbers(mut self, tone_numbers: bool) -> Self {
+        self.tone_numbers = tone_numbers;
+        self
+    }
+
+    /// Leaves out stress marks, tones and length, writing only the segments.
+    pub fn strip_suprasegmentals(mut self, strip_suprasegmentals: bool) -> Self {
+        self.strip_suprasegmentals = strip_suprasegmentals;
+        self
+    }
+}
+
+/// Canonical decompositions of the precomposed letters among the symbols.
+const DECOMPOSITIONS: &[(char, &str)] = &[('ç', "c\u{327}")];
+
+/// Sounds displayed with the given options, returned by [`IpaSlice::format_with`].
+#[derive(Clone, Copy, Debug)]
+pub struct IpaFormatter<'a> {
+    ipa: &'a IpaSlice,
+    options: FormatOptions
+}
+
+impl IpaSlice {
+    /// Wraps the sounds to be displayed with the given options, e.g. in `format!` or `println!`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ipa_sounds::{FormatOptions, Ipa};
+    ///
+    /// let ipa = Ipa::try_from("ˈçaːl").unwrap();
+    /// let options = FormatOptions::new().strip_suprasegmentals(true);
+    /// assert_eq!(format!("[{}]", ipa.format_with(&options)), "[çal]");
+    /// assert_eq!(ipa.format_with(&options.decomposed(true)).to_string(), "c\u{327}al");
+    /// ```
+    pub fn format_with(&self, options: &FormatOptions) -> IpaFormatter<'_> {
+        IpaFormatter { ipa: self, options: *options }
+    }
+}
+
+impl fmt::Display for IpaFormatter<'_> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.ipa.write_with(formatter, &self.options)
+    }
+}
+
+/// Writes a single sound char by char.
+pub(crate) fn write_sound(writer: &mut impl fmt::Write, sound: Sound, options: &FormatOptions) -> fmt::Result {
+    let (is_long, is_palatalized, release) = match sound {
+        Sound::Vowel { is_long, .. } => (is_long, false, None),
+        Sound::Consonant { is_long, is_palatalized, release, .. } => (is_long, is_palatalized, release),
+        Sound::Stress { .. } | Sound::Tone { .. } if options.strip_suprasegmentals => return Ok(()),
+        Sound::Stress { .. } => (false, false, None),
+        Sound::Tone { contour } => return write_tone(writer, contour, options),
+        Sound::Space => return writer.write_char(' ')
+    };
+    let is_long = is_long && !options.strip_suprasegmentals;
+    let symbol = sound.symbol().unwrap();
+    let decomposition = DECOMPOSITIONS.iter()
+        .find(|&&(precomposed, _)| options.decomposed && precomposed == symbol)
+        .map(|&(_, decomposition)| decomposition);
+    let repeats = if is_long && options.doubled_as_long { 2 } else { 1 };
+    for _ in 0..repeats {
+        match decomposition {
+            Some(decomposition) => writer.write_str(decomposition)?,
+            None => writer.write_char(symbol)?
+        }
+        if let Some(release) = release {
+            writer.write_char(release.symbol())?;
+        }
+        if is_palatalized {
+            writer.write_char(if options.ascii_substitutes { '\'' } else { 'ʲ' })?;
+        }
+    }
+    if is_long && !options.doubled_as_long {
+        writer.write_char(if options.ascii_substitutes { ':' } else { 'ː' })?;
+    }
+    Ok(())
+}
+
+/// Writes the levels of a tone as tone letters, tone numbers or, with ASCII substitutes, digits.
+fn write_tone(writer: &mut impl fmt::Write, tone: Tone, options: &FormatOptions) -> fmt::Result {
+    tone.levels().iter().try_for_each(|&level| {
+        let i = level as usize - 1;
+        writer.write_char(match (options.ascii_substitutes, options.tone_numbers) {
+            (true, _) => char::from(b'0' + level),
+            (false, true) => TONE_NUMBERS[i],
+            (false, false) => TONE_LETTERS[i]
+        })
+    })
+}
+
+/// Writes the tone letters, e.g. "˧˥".
+impl fmt::Display for Tone {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_tone(formatter, *self, &FormatOptions::default())
+    }
+}
+
+/// Writes the symbol, or the descriptive name with `{:#}`.
+impl fmt::Display for Vowels {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if formatter.alternate() {
+            formatter.write_str(self.name())
+        } else {
+            formatter.write_char(char::from(*self))
+        }
+    }
+}
+
+/// Writes the symbol, or the descriptive name with `{:#}`.
+impl fmt::Display for Consonants {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if formatter.alternate() {
+            formatter.write_str(self.name())
+        } else {
+            formatter.write_char(char::from(*self))
+        }
+    }
+}
+
+/// Writes the sound as in `Ipa`, e.g. "nʲː" or "ᵊ", or its descriptive name with `{:#}`.
+impl fmt::Display for Sound {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if formatter.alternate() {
+            write_name(formatter, *self)
+        } else {
+            write_sound(formatter, *self, &FormatOptions::default())
+        }
+    }
+}
+
+#[cfg(test)]
+mod format_tests {
+    use super::*;
+    use crate::{Ipa, IpaParser};
+
+    #[test]
+    fn test_default_matches_display() {
+        let ipa = Ipa::try_from("nʲæː ᵊpʲː").unwrap();
+        assert_eq!(ipa.to_string_with(&FormatOptions::new()), ipa.to_string());
+    }
+
+    #[test]
+    fn test_single_sounds() {
+        let ipa = Ipa::try_from("nʲːᵊ").unwrap();
+        assert_eq!(ipa[0].to_string(), "nʲː");
+        assert_eq!(ipa[1].to_string(), "ᵊ");
+        assert_eq!(Vowels::MidCentral.to_string(), "ə");
+        assert_eq!(Consonants::VoicedBilabialNasal.to_string(), "m");
+    }
+
+    #[test]
+    fn test_round_trip_with_parser() {
+        let ipa = Ipa::try_from("nʲːaː mi").unwrap();
+        let written = ipa.to_string_with(&FormatOptions::new().ascii_substitutes(true).doubled_as_long(true));
+        assert_eq!(written, "n'n'aa mi");
+        assert_eq!(IpaParser::new().ascii_substitutes(true).doubled_as_long(true).parse(&written), Ok(ipa));
+    }
+
+    #[test]
+    fn test_format_with() {
+        let ipa = Ipa::try_from("ˌçːaˈnʲa").unwrap();
+        let options = FormatOptions::new().decomposed(true);
+        assert_eq!(ipa.format_with(&options).to_string(), "ˌc\u{327}ːaˈnʲa");
+        assert_eq!(ipa.format_with(&options.doubled_as_long(true)).to_string(), "ˌc\u{327}c\u{327}aˈnʲa");
+        assert_eq!(ipa.format_with(&FormatOptions::new().strip_suprasegmentals(true)).to_string(), "çanʲa");
+    }
+}
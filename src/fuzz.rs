@@ -0,0 +1,93 @@
+//! `Arbitrary` implementations for fuzzing.
+//!
+//! Only sounds the parser can produce are generated, so `Ipa::try_from(ipa.to_string()) == Ok(ipa)`
+//! holds for every generated value.
+
+use alloc::vec::Vec;
+use arbitrary::{Arbitrary, Result, Unstructured};
+use crate::{to_superscript, Consonants, Ipa, Release, Sound, Tone, Vowels};
+
+/// Generates the reduction flag, which is only valid for symbols with a superscript form.
+fn is_reduced(u: &mut Unstructured<'_>, symbol: char) -> Result<bool> {
+    Ok(to_superscript(symbol).is_some() && bool::arbitrary(u)?)
+}
+
+impl<'a> Arbitrary<'a> for Sound {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(match u.int_in_range(0..=4)? {
+            0 => {
+                let phoneme = *u.choose(Vowels::ALL)?;
+                Sound::Vowel {
+                    phoneme,
+                    is_long: bool::arbitrary(u)?,
+                    is_reduced: is_reduced(u, char::from(phoneme))?
+                }
+            },
+            1 => {
+                let phoneme = *u.choose(Consonants::ALL)?;
+                Sound::Consonant {
+                    phoneme,
+                    is_long: bool::arbitrary(u)?,
+                    is_palatalized: bool::arbitrary(u)?,
+                    is_reduced: is_reduced(u, char::from(phoneme))?,
+                    release: match u.choose_index(Release::ALL.len() + 1)? {
+                        0 => None,
+                        i => Some(Release::ALL[i - 1]).filter(|release| release.is_allowed(phoneme))
+                    }
+                }
+            },
+            2 => Sound::Stress { is_primary: bool::arbitrary(u)? },
+            3 => {
+                let mut levels = [0; 3];
+                let levels = &mut levels[..u.int_in_range(1..=3)?];
+                for level in levels.iter_mut() {
+                    *level = u.int_in_range(1..=5)?;
+                }
+                Sound::Tone { contour: Tone::new(levels).expect("levels are 1 to 5") }
+            },
+            _ => Sound::Space
+        })
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (1, Some(5))
+    }
+}
+
+impl<'a> Arbitrary<'a> for Ipa {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        u.arbitrary_iter()?.collect::<Result<Vec<_>>>().map(canonical)
+    }
+
+    fn arbitrary_take_rest(u: Unstructured<'a>) -> Result<Self> {
+        u.arbitrary_take_rest_iter()?.collect::<Result<Vec<_>>>().map(canonical)
+    }
+}
+
+/// Sounds in the form the parser reads them, e.g. a plosive and a reduced [ⁿ] as a nasally released plosive.
+fn canonical(sounds: Vec<Sound>) -> Ipa {
+    let mut ipa = Ipa(sounds, None);
+    ipa.canonicalize();
+    ipa
+}
+
+#[cfg(test)]
+mod fuzz_tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        let bytes: Vec<u8> = (0..4096).map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state as u8
+        }).collect();
+        let mut u = Unstructured::new(&bytes);
+        while !u.is_empty() {
+            let ipa = Ipa::arbitrary(&mut u).unwrap();
+            assert_eq!(Ipa::try_from(ipa.to_string()), Ok(ipa));
+        }
+    }
+}
@@ -0,0 +1,324 @@
+//! Grapheme-to-phoneme conversion: transcribing the spelling of a language in IPA.
+//!
+//! [`Orthography`] reads spelling by ordered context-sensitive rules, which can be written in a simple
+//! text format; [`spanish`] bundles a set of them. Languages whose spelling does not fit the rules,
+//! such as Russian with its vowel reduction, have modules of their own.
+
+pub mod russian;
+pub mod spanish;
+
+use alloc::{string::String, vec::Vec};
+use core::{fmt, str::FromStr};
+use crate::{transliteration::TransliterationError, Error, Ipa, IpaSlice, Sound, Stress, Utterance, Word};
+
+/// Grapheme of the context of a spelling rule.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Context {
+    Letter(char),
+    Set(String),
+    Boundary
+}
+
+impl Context {
+    /// Whether the context matches the letter, `None` at the edge of the word.
+    fn matches(&self, letter: Option<char>) -> bool {
+        match (self, letter) {
+            (Context::Letter(expected), Some(letter)) => *expected == letter,
+            (Context::Set(letters), Some(letter)) => letters.contains(letter),
+            (Context::Boundary, None) => true,
+            _ => false
+        }
+    }
+
+    /// Parses contexts written as letters, sets of letters in brackets and "#" for the word boundary.
+    fn parse_all(text: &str) -> Result<Vec<Context>, SpellingRuleError> {
+        let mut contexts = Vec::new();
+        let mut symbols = text.chars().flat_map(char::to_lowercase).filter(|symbol| !symbol.is_whitespace());
+        while let Some(symbol) = symbols.next() {
+            contexts.push(match symbol {
+                '#' => Context::Boundary,
+                '[' => {
+                    let mut letters = String::new();
+                    loop {
+                        match symbols.next() {
+                            Some(']') if !letters.is_empty() => break,
+                            Some(']') | None => return Err(SpellingRuleError::InvalidContext),
+                            Some(letter) => letters.push(letter)
+                        }
+                    }
+                    Context::Set(letters)
+                },
+                ']' | '_' => return Err(SpellingRuleError::InvalidContext),
+                letter => Context::Letter(letter)
+            });
+        }
+        Ok(contexts)
+    }
+}
+
+impl fmt::Display for Context {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Context::Letter(letter) => write!(formatter, "{}", letter),
+            Context::Set(letters) => write!(formatter, "[{}]", letters),
+            Context::Boundary => formatter.write_str("#")
+        }
+    }
+}
+
+/// Enum for possible errors when parsing a spelling rule.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SpellingRuleError {
+    /// Rule has no "->" between the graphemes and the IPA.
+    MissingArrow,
+    /// Rule reads no graphemes.
+    EmptyGraphemes,
+    /// Context is not a left and a right side around a single "_", or has an empty or unclosed set.
+    InvalidContext,
+    Ipa(Error)
+}
+
+impl fmt::Display for SpellingRuleError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SpellingRuleError::MissingArrow => formatter.write_str("Spelling rule has no \"->\""),
+            SpellingRuleError::EmptyGraphemes => formatter.write_str("Spelling rule reads no graphemes"),
+            SpellingRuleError::InvalidContext => formatter.write_str("Context of the spelling rule is invalid"),
+            SpellingRuleError::Ipa(error) => fmt::Display::fmt(error, formatter)
+        }
+    }
+}
+
+impl core::error::Error for SpellingRuleError {}
+
+impl From<Error> for SpellingRuleError {
+    fn from(error: Error) -> Self {
+        SpellingRuleError::Ipa(error)
+    }
+}
+
+/// Spelling rule: graphemes and the IPA they are read as, optionally only between a left and a right
+/// context.
+///
+/// Rules are written as `graphemes -> ipa / left _ right`, where the context is optional, "∅" reads
+/// silent graphemes, and the contexts consist of letters, sets of letters such as "\[ei\]" and "#" for
+/// the word boundary. Stress marks in the IPA belong to the vowel after them.
+///
+/// # Examples
+///
+/// ```
+/// use ipa_sounds::g2p::SpellingRule;
+///
+/// let rule: SpellingRule = "c -> θ / _ [ei]".parse().unwrap();
+/// assert!(rule.matches_at(&['c', 'e'], 0));
+/// assert!(!rule.matches_at(&['c', 'a'], 0));
+/// assert_eq!(rule.to_string(), "c -> θ / _ [ei]");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SpellingRule {
+    graphemes: String,
+    ipa: Ipa,
+    left: Vec<Context>,
+    right: Vec<Context>
+}
+
+impl SpellingRule {
+    /// Graphemes the rule reads.
+    pub fn graphemes(&self) -> &str {
+        &self.graphemes
+    }
+
+    /// IPA the graphemes are read as.
+    pub fn ipa(&self) -> &IpaSlice {
+        &self.ipa
+    }
+
+    /// Whether the rule reads the letters of a word starting at the index.
+    pub fn matches_at(&self, letters: &[char], index: usize) -> bool {
+        let end = index + self.graphemes.chars().count();
+        letters.get(index..end).is_some_and(|letters| letters.iter().copied().eq(self.graphemes.chars()))
+            && self.left.iter().rev().enumerate()
+                .all(|(i, context)| context.matches(index.checked_sub(i + 1).map(|i| letters[i])))
+            && self.right.iter().enumerate().all(|(i, context)| context.matches(letters.get(end + i).copied()))
+    }
+}
+
+impl FromStr for SpellingRule {
+    type Err = SpellingRuleError;
+
+    fn from_str(rule: &str) -> Result<Self, Self::Err> {
+        let (graphemes, rest) = rule.split_once("->").ok_or(SpellingRuleError::MissingArrow)?;
+        let graphemes: String = graphemes.trim().chars().flat_map(char::to_lowercase).collect();
+        if graphemes.is_empty() {
+            return Err(SpellingRuleError::EmptyGraphemes);
+        }
+        let (ipa, context) = rest.split_once('/').unwrap_or((rest, "_"));
+        let ipa = match ipa.trim() {
+            "∅" => Ipa::default(),
+            ipa => Ipa::try_from(ipa)?
+        };
+        let (left, right) = context.split_once('_').ok_or(SpellingRuleError::InvalidContext)?;
+        Ok(SpellingRule { graphemes, ipa, left: Context::parse_all(left)?, right: Context::parse_all(right)? })
+    }
+}
+
+/// Writes the rule in the text format, e.g. "c -> θ / _ \[ei\]".
+impl fmt::Display for SpellingRule {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "{} -> ", self.graphemes)?;
+        if self.ipa.is_empty() {
+            formatter.write_str("∅")?;
+        } else {
+            write!(formatter, "{}", self.ipa)?;
+        }
+        if !self.left.is_empty() || !self.right.is_empty() {
+            formatter.write_str(" /")?;
+            self.left.iter().try_for_each(|context| write!(formatter, " {}", context))?;
+            formatter.write_str(" _")?;
+            self.right.iter().try_for_each(|context| write!(formatter, " {}", context))?;
+        }
+        Ok(())
+    }
+}
+
+/// Error in a line of the text format of an orthography.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OrthographyError {
+    /// Number of the line, starting from 1.
+    pub line: usize,
+    pub error: SpellingRuleError
+}
+
+impl fmt::Display for OrthographyError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "Line {}: {}", self.line, self.error)
+    }
+}
+
+impl core::error::Error for OrthographyError {}
+
+/// Ordered spelling rules: at every position of a word, the first rule that matches reads the
+/// graphemes.
+///
+/// The text format has a rule per line; blank lines and lines starting with "//" are skipped.
+///
+/// # Examples
+///
+/// ```
+/// use ipa_sounds::g2p::Orthography;
+///
+/// let orthography: Orthography = "
+///     // Finnish-like long vowels
+///     aa -> aː
+///     a -> a
+///     k -> k
+///     t -> t
+///     i -> i
+/// ".parse().unwrap();
+/// assert_eq!(orthography.transcribe("Kaatit").unwrap().to_string(), "kaːtit");
+/// assert!(orthography.transcribe("katu").is_err());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Orthography {
+    rules: Vec<SpellingRule>
+}
+
+impl Orthography {
+    /// Creates an orthography without rules.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a rule after the existing ones.
+    pub fn rule(mut self, rule: SpellingRule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    /// Rules in the order they are tried.
+    pub fn rules(&self) -> &[SpellingRule] {
+        &self.rules
+    }
+
+    /// Transcribes text of words separated by whitespace, ignoring case.
+    ///
+    /// Stress marks read from the graphemes are moved to the start of the syllable of their vowel.
+    /// Letters no rule reads are reported.
+    pub fn transcribe(&self, text: &str) -> Result<Ipa, TransliterationError> {
+        let words = text.split_whitespace()
+            .map(|word| self.transcribe_word(word))
+            .collect::<Result<_, _>>()?;
+        Ok(Utterance::new(words).to_ipa())
+    }
+
+    fn transcribe_word(&self, word: &str) -> Result<Word, TransliterationError> {
+        let letters: Vec<char> = word.chars().flat_map(char::to_lowercase).collect();
+        let mut segments = Vec::new();
+        let mut stresses = Vec::new();
+        let mut stress = Stress::Unstressed;
+        let mut i = 0;
+        while i < letters.len() {
+            let rule = self.rules.iter()
+                .find(|rule| rule.matches_at(&letters, i))
+                .ok_or(TransliterationError::UnknownSymbol(letters[i]))?;
+            for &sound in rule.ipa.iter() {
+                match sound {
+                    Sound::Stress { is_primary } => {
+                        stress = stress.max(if is_primary { Stress::Primary } else { Stress::Secondary });
+                    },
+                    Sound::Vowel { .. } => {
+                        stresses.push(stress);
+                        stress = Stress::Unstressed;
+                        segments.push(sound);
+                    },
+                    sound => segments.push(sound)
+                }
+            }
+            i += rule.graphemes.chars().count();
+        }
+        let syllables = IpaSlice::new(&segments).syllabify().into_iter().flatten().enumerate()
+            .map(|(i, mut syllable)| {
+                syllable.stress = stresses.get(i).copied().unwrap_or_default();
+                syllable
+            })
+            .collect();
+        Ok(Word::new(syllables))
+    }
+}
+
+impl FromStr for Orthography {
+    type Err = OrthographyError;
+
+    fn from_str(rules: &str) -> Result<Self, Self::Err> {
+        let mut orthography = Orthography::new();
+        for (i, line) in rules.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with("//") {
+                continue;
+            }
+            let rule = line.parse().map_err(|error| OrthographyError { line: i + 1, error })?;
+            orthography = orthography.rule(rule);
+        }
+        Ok(orthography)
+    }
+}
+
+#[cfg(test)]
+mod g2p_tests {
+    use super::*;
+
+    #[test]
+    fn test_spelling_rule() {
+        let rule: SpellingRule = "R -> r / [lns] _ #".parse().unwrap();
+        assert_eq!(rule.to_string(), "r -> r / [lns] _ #");
+        assert!(rule.matches_at(&['n', 'r'], 1));
+        assert!(!rule.matches_at(&['r'], 0) && !rule.matches_at(&['n', 'r', 'a'], 1));
+        assert_eq!("h -> ∅".parse::<SpellingRule>().unwrap().to_string(), "h -> ∅");
+        assert_eq!("h - x".parse::<SpellingRule>(), Err(SpellingRuleError::MissingArrow));
+        assert_eq!("c -> k / _ [ei".parse::<SpellingRule>(), Err(SpellingRuleError::InvalidContext));
+        assert_eq!("c -> k / a".parse::<SpellingRule>(), Err(SpellingRuleError::InvalidContext));
+        assert_eq!("a\n -> a".parse::<Orthography>(), Err(OrthographyError { line: 1, error: SpellingRuleError::MissingArrow }));
+    }
+}
@@ -0,0 +1,171 @@
+//! Russian Cyrillic spelling.
+//!
+//! Stress is not written in Russian, so it is read from an acute accent (U+0301) after the stressed
+//! vowel, as in dictionaries; "ё" and the vowel of single-vowel words are always stressed. Words with
+//! no known stress keep their vowels unreduced.
+//!
+//! # Examples
+//!
+//! ```
+//! use ipa_sounds::g2p::russian;
+//!
+//! assert_eq!(russian::to_ipa("молоко\u{301}").unwrap().to_string(), "məlɐˈko");
+//! assert_eq!(russian::to_ipa("Я\u{301}блоко").unwrap().to_string(), "ˈjabləkə");
+//! assert_eq!(russian::to_ipa("хлеб и соль").unwrap().to_string(), "ˈxlʲep ˈi ˈsolʲ");
+//! ```
+
+use alloc::{string::String, vec::Vec};
+use crate::{transliteration::TransliterationError, Ipa, Stress, Utterance, Word};
+
+const VOWELS: &str = "аеёиоуыэюя";
+
+/// Vowels that palatalize the consonant before them, or stand for [j] and a vowel after a vowel or a sign.
+const SOFT_VOWELS: &str = "еёиюя";
+
+/// Consonants that are always hard (ж, ш, ц) or always soft (ч, щ, й) and take no palatalization.
+const UNPAIRED: &str = "жшцчщй";
+
+const STRESS_MARK: char = '\u{301}';
+
+/// IPA of a consonant letter.
+fn consonant(letter: char) -> Option<&'static str> {
+    Some(match letter {
+        'б' => "b", 'в' => "v", 'г' => "ɡ", 'д' => "d", 'ж' => "ʐ", 'з' => "z", 'й' => "j", 'к' => "k",
+        'л' => "l", 'м' => "m", 'н' => "n", 'п' => "p", 'р' => "r", 'с' => "s", 'т' => "t", 'ф' => "f",
+        'х' => "x", 'ц' => "ts", 'ч' => "tɕ", 'ш' => "ʂ", 'щ' => "ɕː",
+        _ => return None
+    })
+}
+
+/// IPA of a vowel letter, without the [j] of a vowel starting a syllable.
+///
+/// Unstressed [a], [o] and [e] are reduced as in the Moscow standard: to [ɐ] in the syllable before the
+/// stress and at the start of the word, to [ə] elsewhere, and to [ɪ] after soft consonants, or [ɨ]
+/// for [e] after hard ж, ш and ц.
+fn vowel(letter: char, is_soft: bool, is_hushing: bool, reduction: Option<Reduction>) -> &'static str {
+    let full = match letter {
+        'а' | 'я' => "a",
+        'о' | 'ё' => "o",
+        'э' | 'е' => "e",
+        'у' | 'ю' => "u",
+        'ы' => "ɨ",
+        _ if is_hushing => "ɨ",
+        _ => "i"
+    };
+    match (full, reduction) {
+        (_, None) | ("u" | "ɨ" | "i", _) => full,
+        ("a", Some(Reduction::Final)) if is_soft => "ə",
+        (_, Some(_)) if is_soft => "ɪ",
+        ("e", Some(_)) if is_hushing => "ɨ",
+        ("e", Some(_)) => "ɪ",
+        (_, Some(Reduction::Strong)) | (_, Some(Reduction::Final)) => "ə",
+        (_, Some(Reduction::Weak)) => "ɐ"
+    }
+}
+
+/// Position of an unstressed vowel that determines its reduction.
+#[derive(Clone, Copy)]
+enum Reduction {
+    /// Directly before the stressed syllable or at the start of the word.
+    Weak,
+    /// Elsewhere before the end of the word.
+    Strong,
+    /// At the end of the word.
+    Final
+}
+
+/// Transcribes a word, giving the index of its stressed vowel.
+fn word(word: &str) -> Result<(String, Option<usize>), TransliterationError> {
+    let mut letters = Vec::new();
+    let mut stressed = None;
+    for symbol in word.chars().flat_map(char::to_lowercase) {
+        match symbol {
+            STRESS_MARK => stressed = Some(letters.iter().filter(|letter| VOWELS.contains(**letter)).count().saturating_sub(1)),
+            symbol if VOWELS.contains(symbol) || "ъь".contains(symbol) || consonant(symbol).is_some() => letters.push(symbol),
+            symbol => return Err(TransliterationError::UnknownSymbol(symbol))
+        }
+    }
+    let vowels = letters.iter().filter(|letter| VOWELS.contains(**letter)).count();
+    let stressed = stressed
+        .or_else(|| letters.iter().filter(|letter| VOWELS.contains(**letter)).position(|&letter| letter == 'ё'))
+        .or((vowels == 1).then_some(0));
+    let mut ipa = String::new();
+    let mut count = 0;
+    for (i, &letter) in letters.iter().enumerate() {
+        let previous = i.checked_sub(1).map(|previous| letters[previous]);
+        if let Some(sound) = consonant(letter) {
+            ipa.push_str(sound);
+            let next = letters.get(i + 1).copied();
+            if next.is_some_and(|next| SOFT_VOWELS.contains(next) || next == 'ь') && !UNPAIRED.contains(letter) {
+                ipa.push('ʲ');
+            }
+            continue;
+        }
+        if !VOWELS.contains(letter) {
+            continue;
+        }
+        let is_iotated = SOFT_VOWELS.contains(letter)
+            && previous.is_none_or(|previous| VOWELS.contains(previous) || "ъь".contains(previous))
+            && !(letter == 'и' && previous.is_none_or(|previous| VOWELS.contains(previous)));
+        if is_iotated {
+            ipa.push('j');
+        }
+        let is_soft = is_iotated
+            || previous.is_some_and(|previous| "чщй".contains(previous))
+            || (SOFT_VOWELS.contains(letter) && previous.is_some_and(|previous| consonant(previous).is_some() && !UNPAIRED.contains(previous)));
+        let is_hushing = previous.is_some_and(|previous| "жшц".contains(previous));
+        let reduction = match stressed {
+            Some(stressed) if stressed != count => Some(if count + 1 == stressed || i == 0 {
+                Reduction::Weak
+            } else if count + 1 == vowels {
+                Reduction::Final
+            } else {
+                Reduction::Strong
+            }),
+            _ => None
+        };
+        ipa.push_str(vowel(letter, is_soft, is_hushing, reduction));
+        count += 1;
+    }
+    Ok((ipa, stressed))
+}
+
+/// Transcribes Cyrillic text of words separated by whitespace.
+///
+/// Consonants are palatalized before soft vowels and ь, unstressed vowels are reduced, and obstruents
+/// are devoiced at the end of words. Letters outside the Russian alphabet are reported.
+pub fn to_ipa(text: &str) -> Result<Ipa, TransliterationError> {
+    let mut words = Vec::new();
+    for text in text.split_whitespace() {
+        let (ipa, stressed) = word(text)?;
+        let syllables = Ipa::try_from(ipa.as_str())?.syllabify().into_iter().flatten().enumerate()
+            .map(|(i, mut syllable)| {
+                if stressed == Some(i) {
+                    syllable.stress = Stress::Primary;
+                }
+                syllable
+            })
+            .collect();
+        words.push(Word::new(syllables));
+    }
+    let mut ipa = Utterance::new(words).to_ipa();
+    ipa.devoice_finals();
+    Ok(ipa)
+}
+
+#[cfg(test)]
+mod russian_tests {
+    use super::*;
+
+    fn ipa(text: &str) -> String {
+        to_ipa(text).unwrap().to_string()
+    }
+
+    #[test]
+    fn test_to_ipa() {
+        assert_eq!(ipa("язы\u{301}к"), "jɪˈzɨk");
+        assert_eq!(ipa("жена\u{301} часы\u{301}"), "ʐɨˈna tɕɪˈsɨ");
+        assert_eq!(ipa("ёж день пла\u{301}тье"), "ˈjoʂ ˈdʲenʲ ˈplatʲjɪ");
+        assert_eq!(to_ipa("молоко!"), Err(TransliterationError::UnknownSymbol('!')));
+    }
+}
@@ -0,0 +1,114 @@
+//! Spanish spelling, as pronounced in Spain.
+//!
+//! Written accents mark stress; other words are stressed by the default rule, on the second-to-last
+//! syllable if they end in a vowel, "n" or "s" and on the last one otherwise. Sounds are phonemic, so
+//! the approximant allophones of /b d ɡ/ are not written.
+//!
+//! # Examples
+//!
+//! ```
+//! use ipa_sounds::g2p::spanish;
+//!
+//! assert_eq!(spanish::to_ipa("La canción").unwrap().to_string(), "ˈla kanˈθjon");
+//! assert_eq!(spanish::to_ipa("ciudad").unwrap().to_string(), "θjuˈdad");
+//! ```
+
+use alloc::vec::Vec;
+use crate::{transliteration::TransliterationError, Ipa, Stress, Utterance, Word};
+use super::Orthography;
+
+/// Spelling rules in the text format of [`Orthography`].
+pub const RULES: &str = "
+// Digraphs
+ch -> tʃ
+ll -> ʝ
+rr -> r
+qu -> k
+gu -> ɡ / _ [eiéí]
+gü -> ɡw
+// Consonants read by their context
+c -> θ / _ [eiéí]
+c -> k
+g -> x / _ [eiéí]
+g -> ɡ
+r -> r / # _
+r -> r / [lns] _
+r -> ɾ
+y -> i / # _ #
+y -> j / [aeiou] _ #
+y -> ʝ
+// Unstressed i and u next to other vowels are glides
+i -> j / _ [aeouáéóú]
+i -> j / [aeoáéó] _
+u -> w / _ [aeioáéíó]
+u -> w / [aeoáéó] _
+// Stressed vowels
+á -> ˈa
+é -> ˈe
+í -> ˈi
+ó -> ˈo
+ú -> ˈu
+// Other letters
+a -> a
+e -> e
+i -> i
+o -> o
+u -> u
+b -> b
+d -> d
+f -> f
+h -> ∅
+j -> x
+k -> k
+l -> l
+m -> m
+n -> n
+ñ -> ɲ
+p -> p
+s -> s
+t -> t
+v -> b
+w -> w
+x -> ks
+z -> θ
+";
+
+/// Orthography of the bundled rules.
+pub fn orthography() -> Orthography {
+    RULES.parse().expect("bundled rules are valid")
+}
+
+/// Transcribes Spanish text of words separated by whitespace, marking the stress of every word.
+pub fn to_ipa(text: &str) -> Result<Ipa, TransliterationError> {
+    let orthography = orthography();
+    let mut words = Vec::new();
+    for word in text.split_whitespace() {
+        let mut syllables: Vec<_> = orthography.transcribe(word)?.syllabify().into_iter().flatten().collect();
+        if syllables.iter().all(|syllable| syllable.stress == Stress::Unstressed) {
+            let is_penultimate = word.ends_with(|letter: char| "aeiouns".contains(letter.to_ascii_lowercase()));
+            let stressed = syllables.len().saturating_sub(if is_penultimate { 2 } else { 1 });
+            if let Some(syllable) = syllables.get_mut(stressed) {
+                syllable.stress = Stress::Primary;
+            }
+        }
+        words.push(Word::new(syllables));
+    }
+    Ok(Utterance::new(words).to_ipa())
+}
+
+#[cfg(test)]
+mod spanish_tests {
+    use super::*;
+
+    fn ipa(text: &str) -> String {
+        to_ipa(text).unwrap().to_string()
+    }
+
+    #[test]
+    fn test_to_ipa() {
+        assert_eq!(ipa("perro pero guitarra pingüino"), "ˈpero ˈpeɾo ɡiˈtara pinˈɡwino");
+        assert_eq!(ipa("hoy muy y jamón"), "ˈoj ˈmuj ˈi xaˈmon");
+        assert_eq!(ipa("quiero cenar"), "ˈkjeɾo θeˈnaɾ");
+        assert_eq!(to_ipa("año."), Err(TransliterationError::UnknownSymbol('.')));
+    }
+}
@@ -0,0 +1,185 @@
+//! Random words obeying phonotactics, for conlang tooling and test data.
+
+use alloc::vec::Vec;
+use core::ops::RangeInclusive;
+use crate::{FrequencyTable, Inventory, Ipa, Phonotactics, Sound, SyllableShape};
+
+/// Attempts at a word before [`Generator::generate`] gives up.
+const ATTEMPTS: usize = 100;
+
+/// Generator of random words from the sounds of an inventory, syllable by syllable.
+///
+/// Syllable shapes are chosen uniformly from the allowed ones (CV, CVC and V if the phonotactics does
+/// not restrict them), clusters from the allowed ones, and sounds by their weights. Words that break
+/// the phonotactics when divided into syllables are generated again. The generator is deterministic
+/// for a seed.
+///
+/// # Examples
+///
+/// ```
+/// use ipa_sounds::{Generator, Inventory, Ipa, Phonotactics};
+///
+/// let inventory: Inventory = Ipa::try_from("ptkmnaiu").unwrap().iter().copied().collect();
+/// let phonotactics = Phonotactics::new()
+///     .shapes(["CV", "CVC"].map(|shape| shape.parse().unwrap()))
+///     .codas([Ipa::try_from("n").unwrap()]);
+/// let mut generator = Generator::new(&inventory, phonotactics.clone()).syllables(2..=3).seed(7);
+/// for word in generator.by_ref().take(20) {
+///     assert_eq!(phonotactics.check(&word), Ok(()));
+///     assert!(word.iter().all(|sound| inventory.contains(sound)));
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Generator {
+    weights: Vec<(Sound, f64)>,
+    phonotactics: Phonotactics,
+    syllables: RangeInclusive<usize>,
+    state: u64
+}
+
+impl Generator {
+    /// Creates a generator of words of one to three syllables with equally weighted sounds.
+    pub fn new(inventory: &Inventory, phonotactics: Phonotactics) -> Self {
+        Generator {
+            weights: inventory.iter().map(|&sound| (sound, 1.0)).collect(),
+            phonotactics,
+            syllables: 1..=3,
+            state: 0x9E37_79B9_7F4A_7C15
+        }
+    }
+
+    /// Weights the sounds by their counts in the table; sounds of the inventory missing from it are
+    /// never chosen.
+    pub fn weights(mut self, weights: &FrequencyTable<Sound>) -> Self {
+        for (sound, weight) in &mut self.weights {
+            *weight = weights.count(sound) as f64;
+        }
+        self
+    }
+
+    /// Sets the range of the number of syllables of a word.
+    pub fn syllables(mut self, syllables: RangeInclusive<usize>) -> Self {
+        self.syllables = syllables;
+        self
+    }
+
+    /// Sets the seed of the random numbers.
+    pub fn seed(mut self, seed: u64) -> Self {
+        // Xorshift never leaves a zero state.
+        self.state = seed.max(1);
+        self
+    }
+
+    /// Generates a word, `None` if no word obeying the phonotactics was found after a hundred attempts.
+    pub fn generate(&mut self) -> Option<Ipa> {
+        (0..ATTEMPTS).find_map(|_| {
+            let (least, most) = (*self.syllables.start(), *self.syllables.end());
+            let count = least + self.below(most.saturating_sub(least) + 1);
+            let mut word = Vec::new();
+            for _ in 0..count {
+                word.extend(self.syllable()?);
+            }
+            let word: Ipa = word.into_iter().collect();
+            (!word.is_empty() && self.phonotactics.check(&word).is_ok()).then_some(word)
+        })
+    }
+
+    /// Sounds of a random syllable, `None` if a part of its shape cannot be filled.
+    fn syllable(&mut self) -> Option<Vec<Sound>> {
+        let shapes = self.phonotactics.shapes.clone()
+            .unwrap_or_else(|| Vec::from([SyllableShape::new(1, 1, 0), SyllableShape::new(1, 1, 1), SyllableShape::new(0, 1, 0)]));
+        let shape = shapes[self.below(shapes.len())];
+        let mut sounds = self.cluster(self.phonotactics.onsets.clone(), shape.onset)?;
+        for _ in 0..shape.nucleus {
+            sounds.push(self.sound(|sound| matches!(sound, Sound::Vowel { .. }))?);
+        }
+        sounds.extend(self.cluster(self.phonotactics.codas.clone(), shape.coda)?);
+        Some(sounds)
+    }
+
+    /// Random cluster of consonants of the length, from the allowed clusters if they are restricted.
+    fn cluster(&mut self, allowed: Option<Vec<Ipa>>, length: usize) -> Option<Vec<Sound>> {
+        match allowed {
+            _ if length == 0 => Some(Vec::new()),
+            Some(allowed) => {
+                let candidates: Vec<(Vec<Sound>, f64)> = allowed.into_iter()
+                    .filter(|cluster| cluster.len() == length)
+                    .map(|cluster| {
+                        let weight = cluster.iter().map(|sound| self.weight(sound)).product();
+                        (cluster.iter().copied().collect(), weight)
+                    })
+                    .collect();
+                self.choose(&candidates).cloned()
+            },
+            None => (0..length).map(|_| self.sound(|sound| matches!(sound, Sound::Consonant { .. }))).collect()
+        }
+    }
+
+    /// Random sound of the inventory satisfying the predicate.
+    fn sound(&mut self, predicate: impl Fn(&Sound) -> bool) -> Option<Sound> {
+        let candidates: Vec<(Sound, f64)> = self.weights.iter().copied().filter(|(sound, _)| predicate(sound)).collect();
+        self.choose(&candidates).copied()
+    }
+
+    /// Weight of a sound, 0.0 outside the inventory.
+    fn weight(&self, sound: &Sound) -> f64 {
+        self.weights.iter().find(|(known, _)| known == sound).map_or(0.0, |&(_, weight)| weight)
+    }
+
+    /// Random item chosen with a probability proportional to its weight, `None` if all weigh nothing.
+    fn choose<'a, T>(&mut self, candidates: &'a [(T, f64)]) -> Option<&'a T> {
+        let total: f64 = candidates.iter().map(|(_, weight)| weight).sum();
+        if total <= 0.0 {
+            return None;
+        }
+        let mut target = self.next_unit() * total;
+        for (item, weight) in candidates {
+            if target < *weight {
+                return Some(item);
+            }
+            target -= weight;
+        }
+        candidates.iter().rev().find(|(_, weight)| *weight > 0.0).map(|(item, _)| item)
+    }
+
+    /// Random number below the bound.
+    fn below(&mut self, bound: usize) -> usize {
+        ((self.next_unit() * bound as f64) as usize).min(bound.saturating_sub(1))
+    }
+
+    /// Random number from 0.0 to 1.0 by xorshift64*.
+    fn next_unit(&mut self) -> f64 {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+        (self.state.wrapping_mul(0x2545_F491_4F6C_DD1D) >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+/// Infinite words, ending early only if [`Generator::generate`] gives up.
+impl Iterator for Generator {
+    type Item = Ipa;
+
+    fn next(&mut self) -> Option<Ipa> {
+        self.generate()
+    }
+}
+
+#[cfg(test)]
+mod generate_tests {
+    use super::*;
+
+    #[test]
+    fn test_generate() {
+        let inventory: Inventory = Ipa::try_from("pai").unwrap().iter().copied().collect();
+        let weights: FrequencyTable<Sound> = Ipa::try_from("pa").unwrap().iter().copied().collect();
+        let mut generator = Generator::new(&inventory, Phonotactics::new()).weights(&weights).syllables(2..=2);
+        let words: Vec<Ipa> = generator.by_ref().take(10).collect();
+        assert_eq!(words.len(), 10);
+        assert!(words.iter().all(|word| !word.to_string().contains('i') && word.syllabify()[0].len() == 2));
+        let seeded = |seed| Generator::new(&inventory, Phonotactics::new()).seed(seed).take(5).collect::<Vec<_>>();
+        assert_eq!(seeded(3), seeded(3));
+        let impossible = Phonotactics::new().shapes(["CCV".parse().unwrap()]).onsets([Ipa::try_from("pt").unwrap()]);
+        assert_eq!(Generator::new(&inventory, impossible).generate(), None);
+    }
+}
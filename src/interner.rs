@@ -0,0 +1,134 @@
+//! Interning of transcriptions: each distinct sequence of sounds is stored once.
+
+use alloc::vec::Vec;
+use core::hash::{BuildHasher, Hasher};
+use hashbrown::{DefaultHashBuilder, HashTable};
+use crate::{IpaSlice, Sound};
+
+/// Handle of a transcription stored in an [`IpaInterner`], four bytes instead of an [`Ipa`](crate::Ipa).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InternedIpa(u32);
+
+impl InternedIpa {
+    /// Index of the transcription in the order of interning.
+    pub fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+/// Store of distinct transcriptions, all kept in one buffer of sounds.
+///
+/// Interning the same sounds again gives the same handle, so a dictionary with millions of
+/// entries holds each pronunciation once and makes no allocation per entry.
+///
+/// # Examples
+///
+/// ```
+/// use ipa_sounds::{Ipa, IpaInterner};
+///
+/// let mut interner = IpaInterner::new();
+/// let first = interner.intern(&Ipa::try_from("ˈnʲæn").unwrap());
+/// let second = interner.intern(&Ipa::try_from("ˈnʲæ").unwrap());
+/// assert_eq!(interner.intern(&Ipa::try_from("ˈnʲæn").unwrap()), first);
+/// assert_ne!(first, second);
+/// assert_eq!(interner.len(), 2);
+/// assert_eq!(interner.resolve(first).to_string(), "ˈnʲæn");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct IpaInterner {
+    sounds: Vec<Sound>,
+    /// Start and end of every transcription in `sounds`, by handle.
+    spans: Vec<(u32, u32)>,
+    /// Handles by the hash of their sounds.
+    table: HashTable<u32>,
+    hasher: DefaultHashBuilder
+}
+
+impl IpaInterner {
+    /// Creates an empty interner.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Handle of the sounds, storing them if they are new.
+    ///
+    /// # Panics
+    ///
+    /// Panics if more than `u32::MAX` sounds or transcriptions are stored.
+    pub fn intern(&mut self, ipa: &IpaSlice) -> InternedIpa {
+        if let Some(handle) = self.get(ipa) {
+            return handle;
+        }
+        let start = u32::try_from(self.sounds.len()).expect("too many sounds to intern");
+        self.sounds.extend_from_slice(ipa.as_sounds());
+        let end = u32::try_from(self.sounds.len()).expect("too many sounds to intern");
+        let handle = u32::try_from(self.spans.len()).expect("too many transcriptions to intern");
+        self.spans.push((start, end));
+        let Self { sounds, spans, table, hasher } = self;
+        let hash = hash(hasher, ipa);
+        table.insert_unique(hash, handle, |&other| {
+            let (start, end) = spans[other as usize];
+            self::hash(hasher, IpaSlice::new(&sounds[start as usize..end as usize]))
+        });
+        InternedIpa(handle)
+    }
+
+    /// Handle of the sounds if they were interned.
+    pub fn get(&self, ipa: &IpaSlice) -> Option<InternedIpa> {
+        self.table.find(hash(&self.hasher, ipa), |&handle| *self.resolve(InternedIpa(handle)) == *ipa)
+            .map(|&handle| InternedIpa(handle))
+    }
+
+    /// Sounds of the handle.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the handle comes from another interner with more transcriptions.
+    pub fn resolve(&self, handle: InternedIpa) -> &IpaSlice {
+        let (start, end) = self.spans[handle.index()];
+        IpaSlice::new(&self.sounds[start as usize..end as usize])
+    }
+
+    /// Number of distinct transcriptions.
+    pub fn len(&self) -> usize {
+        self.spans.len()
+    }
+
+    /// Whether nothing was interned.
+    pub fn is_empty(&self) -> bool {
+        self.spans.is_empty()
+    }
+
+    /// Number of sounds stored for all transcriptions together.
+    pub fn sound_count(&self) -> usize {
+        self.sounds.len()
+    }
+}
+
+/// Hashes the packed codes of the sounds, since `Sound` itself is not `Hash`.
+fn hash(hasher: &DefaultHashBuilder, ipa: &IpaSlice) -> u64 {
+    let mut state = hasher.build_hasher();
+    state.write_usize(ipa.len());
+    ipa.iter().for_each(|sound| state.write_u16(sound.pack()));
+    state.finish()
+}
+
+#[cfg(test)]
+mod interner_tests {
+    use super::*;
+    use crate::{corpus, Ipa};
+
+    #[test]
+    fn test_interning() {
+        let mut interner = IpaInterner::new();
+        let entries: Vec<Ipa> = corpus::strings().map(|entry| Ipa::try_from(entry).unwrap()).collect();
+        let handles: Vec<_> = entries.iter().map(|entry| interner.intern(entry)).collect();
+        assert!(entries.iter().zip(&handles).all(|(entry, &handle)| *interner.resolve(handle) == **entry));
+        assert!(entries.iter().zip(&handles).all(|(entry, &handle)| interner.intern(entry) == handle));
+        assert!(interner.len() <= entries.len());
+        assert_eq!(interner.get(&Ipa::try_from("ʡʢʡʢʡʢ").unwrap()), None);
+        let empty = interner.intern(IpaSlice::new(&[]));
+        assert!(interner.resolve(empty).is_empty());
+    }
+}
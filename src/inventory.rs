@@ -0,0 +1,234 @@
+//! Phoneme inventories: sets of sounds.
+
+use alloc::vec::Vec;
+use core::fmt;
+use crate::{IpaSlice, Sound};
+
+/// Set of vowels and consonants, e.g. the phoneme inventory of a language, iterated in chart order
+/// (the order of [`Sound`]).
+///
+/// Spaces, stress marks and tones are not phonemes and are never added.
+///
+/// # Examples
+///
+/// ```
+/// use ipa_sounds::{Inventory, Ipa};
+///
+/// let first: Inventory = Ipa::try_from("pami").unwrap().iter().copied().collect();
+/// let second: Inventory = Ipa::try_from("nʲæ mi").unwrap().iter().copied().collect();
+/// assert_eq!(first.intersection(&second).to_string(), "m, i");
+/// assert_eq!(first.union(&second).len(), 6);
+/// assert_eq!(first.difference(&second).to_string(), "p, a");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(from = "Vec<Sound>", into = "Vec<Sound>"))]
+pub struct Inventory {
+    /// Sounds sorted in chart order, without duplicates.
+    sounds: Vec<Sound>
+}
+
+impl Inventory {
+    /// Creates an empty inventory.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a sound, returning whether it was not present; spaces, stress marks and tones are
+    /// ignored.
+    pub fn insert(&mut self, sound: Sound) -> bool {
+        if !matches!(sound, Sound::Vowel { .. } | Sound::Consonant { .. }) {
+            return false;
+        }
+        match self.position(&sound) {
+            Ok(_) => false,
+            Err(i) => {
+                self.sounds.insert(i, sound);
+                true
+            }
+        }
+    }
+
+    /// Removes a sound, returning whether it was present.
+    pub fn remove(&mut self, sound: &Sound) -> bool {
+        self.position(sound).map(|i| self.sounds.remove(i)).is_ok()
+    }
+
+    /// Whether the sound is in the inventory.
+    pub fn contains(&self, sound: &Sound) -> bool {
+        self.position(sound).is_ok()
+    }
+
+    /// Number of sounds.
+    pub fn len(&self) -> usize {
+        self.sounds.len()
+    }
+
+    /// Whether the inventory has no sounds.
+    pub fn is_empty(&self) -> bool {
+        self.sounds.is_empty()
+    }
+
+    /// Iterator over the sounds in chart order.
+    pub fn iter(&self) -> core::slice::Iter<'_, Sound> {
+        self.sounds.iter()
+    }
+
+    /// Sounds in either inventory.
+    pub fn union(&self, other: &Inventory) -> Inventory {
+        self.iter().chain(other).copied().collect()
+    }
+
+    /// Sounds in both inventories.
+    pub fn intersection(&self, other: &Inventory) -> Inventory {
+        self.filtered(|sound| other.contains(sound))
+    }
+
+    /// Sounds in this inventory but not in the other.
+    pub fn difference(&self, other: &Inventory) -> Inventory {
+        self.filtered(|sound| !other.contains(sound))
+    }
+
+    /// Whether every sound of this inventory is in the other.
+    pub fn is_subset(&self, other: &Inventory) -> bool {
+        self.iter().all(|sound| other.contains(sound))
+    }
+
+    fn filtered(&self, predicate: impl Fn(&Sound) -> bool) -> Inventory {
+        Inventory { sounds: self.iter().copied().filter(predicate).collect() }
+    }
+
+    fn position(&self, sound: &Sound) -> Result<usize, usize> {
+        self.sounds.binary_search(sound)
+    }
+}
+
+impl FromIterator<Sound> for Inventory {
+    fn from_iter<I: IntoIterator<Item = Sound>>(sounds: I) -> Self {
+        let mut inventory = Inventory::new();
+        inventory.extend(sounds);
+        inventory
+    }
+}
+
+impl Extend<Sound> for Inventory {
+    fn extend<I: IntoIterator<Item = Sound>>(&mut self, sounds: I) {
+        sounds.into_iter().for_each(|sound| {
+            self.insert(sound);
+        });
+    }
+}
+
+impl<'a> IntoIterator for &'a Inventory {
+    type Item = &'a Sound;
+    type IntoIter = core::slice::Iter<'a, Sound>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl From<Vec<Sound>> for Inventory {
+    fn from(sounds: Vec<Sound>) -> Self {
+        sounds.into_iter().collect()
+    }
+}
+
+impl From<Inventory> for Vec<Sound> {
+    fn from(inventory: Inventory) -> Self {
+        inventory.sounds
+    }
+}
+
+/// Writes the sounds in chart order separated by commas, e.g. "p, m, n, a".
+impl fmt::Display for Inventory {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.iter().enumerate().try_for_each(|(i, sound)| {
+            if i > 0 {
+                formatter.write_str(", ")?;
+            }
+            fmt::Display::fmt(sound, formatter)
+        })
+    }
+}
+
+/// Sound of a transcription missing from the inventory it was validated against.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InventoryError {
+    /// Index of the sound in the transcription.
+    pub position: usize,
+    pub sound: Sound
+}
+
+impl fmt::Display for InventoryError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "Sound ({}) at {} is not in the inventory", self.sound, self.position)
+    }
+}
+
+impl core::error::Error for InventoryError {}
+
+impl IpaSlice {
+    /// Checks that every vowel and consonant is in the inventory, reporting all that are not.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ipa_sounds::{Inventory, Ipa};
+    ///
+    /// let inventory: Inventory = Ipa::try_from("mas").unwrap().into_iter().collect();
+    /// assert_eq!(Ipa::try_from("ˈsam mas").unwrap().validate(&inventory), Ok(()));
+    /// let errors = Ipa::try_from("θam maθ").unwrap().validate(&inventory).unwrap_err();
+    /// assert_eq!(errors.iter().map(|error| error.position).collect::<Vec<_>>(), [0, 6]);
+    /// ```
+    pub fn validate(&self, inventory: &Inventory) -> Result<(), Vec<InventoryError>> {
+        let errors: Vec<_> = self.iter().enumerate()
+            .filter(|(_, sound)| matches!(sound, Sound::Vowel { .. } | Sound::Consonant { .. }))
+            .filter(|(_, sound)| !inventory.contains(sound))
+            .map(|(position, &sound)| InventoryError { position, sound })
+            .collect();
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+#[cfg(test)]
+mod inventory_tests {
+    use super::*;
+    use crate::{corpus, Ipa};
+
+    #[test]
+    fn test_set_operations() {
+        let mut inventory: Inventory = Ipa::try_from("ma ˈpa").unwrap().iter().copied().collect();
+        assert_eq!(inventory.to_string(), "p, m, a");
+        assert!(!inventory.insert(Sound::Space));
+        assert!(inventory.remove(&Ipa::try_from("m").unwrap()[0]));
+        assert!(!inventory.remove(&Ipa::try_from("m").unwrap()[0]));
+        assert!(inventory.is_subset(&corpus::sounds().collect()));
+        assert!(inventory.difference(&inventory).is_empty());
+    }
+
+    #[test]
+    fn test_chart_order() {
+        let inventory: Inventory = corpus::sounds().collect();
+        assert_eq!(inventory.len(), corpus::sounds().filter(|sound| !matches!(sound, Sound::Tone { .. })).count());
+        let sounds: Vec<_> = inventory.iter().copied().collect();
+        assert!(sounds.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(Inventory::from(sounds.clone()), inventory);
+        assert!(sounds.iter().all(|sound| inventory.contains(sound)));
+    }
+
+    #[test]
+    fn test_validate() {
+        let ipa = Ipa::try_from("nʲam").unwrap();
+        let inventory: Inventory = Ipa::try_from("n a m").unwrap().into_iter().collect();
+        let errors = ipa.validate(&inventory).unwrap_err();
+        assert_eq!(errors, [InventoryError { position: 0, sound: ipa[0] }]);
+        assert_eq!(errors[0].to_string(), "Sound (nʲ) at 0 is not in the inventory");
+        assert_eq!(Ipa::new().validate(&Inventory::new()), Ok(()));
+    }
+}
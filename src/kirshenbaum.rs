@@ -0,0 +1,81 @@
+//! Kirshenbaum ASCII-IPA, the notation of Usenet-era resources and of eSpeak NG's phoneme input.
+
+use alloc::string::String;
+use crate::{transliteration::{self, Table, TransliterationError}, Ipa, IpaSlice};
+
+/// Kirshenbaum symbols of the supported sounds and modifiers.
+///
+/// Modifier characters follow the notation: '"' centralizes, '.' rounds front vowels or marks retroflex
+/// consonants, '^' marks palatal ones, and extensions are named in angle brackets. The notation has no
+/// epiglottals, which are kept as IPA.
+const KIRSHENBAUM: &Table = &[
+    // Vowels
+    ("i", "i"), ("y", "y"), ("i\"", "ɨ"), ("u\"", "ʉ"), ("u-", "ɯ"), ("u", "u"),
+    ("I", "ɪ"), ("I.", "ʏ"), ("U", "ʊ"),
+    ("e", "e"), ("Y", "ø"), ("e\"", "ɘ"), ("o\"", "ɵ"), ("o-", "ɤ"), ("o", "o"),
+    ("@", "ə"),
+    ("E", "ɛ"), ("W", "œ"), ("V\"", "ɜ"), ("O\"", "ɞ"), ("V", "ʌ"), ("O", "ɔ"),
+    ("&", "æ"), ("a\"", "ɐ"),
+    ("a", "a"), ("a.", "ɶ"), ("A", "ɑ"), ("A.", "ɒ"),
+    // Plosives
+    ("p", "p"), ("b", "b"), ("t", "t"), ("d", "d"), ("t.", "ʈ"), ("d.", "ɖ"), ("c", "c"), ("J", "ɟ"),
+    ("k", "k"), ("g", "ɡ"), ("q", "q"), ("G", "ɢ"), ("?", "ʔ"),
+    // Nasals
+    ("m", "m"), ("M", "ɱ"), ("n", "n"), ("n.", "ɳ"), ("n^", "ɲ"), ("N", "ŋ"), ("n\"", "ɴ"),
+    // Trills and taps
+    ("b<trl>", "ʙ"), ("r<trl>", "r"), ("r\"", "ʀ"), ("*", "ɾ"), ("*.", "ɽ"), ("*<lbd>", "ⱱ"),
+    // Fricatives
+    ("P", "ɸ"), ("B", "β"), ("f", "f"), ("v", "v"), ("T", "θ"), ("D", "ð"), ("s", "s"), ("z", "z"),
+    ("S", "ʃ"), ("Z", "ʒ"), ("s.", "ʂ"), ("z.", "ʐ"), ("C", "ç"), ("C<vcd>", "ʝ"), ("x", "x"), ("Q", "ɣ"),
+    ("X", "χ"), ("g\"", "ʁ"), ("H", "ħ"), ("H<vcd>", "ʕ"), ("h", "h"), ("h<?>", "ɦ"), ("s<lat>", "ɬ"), ("z<lat>", "ɮ"),
+    ("s^", "ɕ"), ("z^", "ʑ"), ("w<vls>", "ʍ"),
+    // Approximants
+    ("r<lbd>", "ʋ"), ("r", "ɹ"), ("r.", "ɻ"), ("j", "j"), ("j<vel>", "ɰ"),
+    ("l", "l"), ("l.", "ɭ"), ("l^", "ʎ"), ("L", "ʟ"), ("w", "w"), ("w.", "ɥ"),
+    // Modifiers
+    (":", "ː"), (";", "ʲ"), ("'", "ˈ"), (",", "ˌ"), (" ", " ")
+];
+
+impl Ipa {
+    /// Reads Kirshenbaum ASCII-IPA, e.g. "'n;&n" for "ˈnʲæn".
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ipa_sounds::Ipa;
+    ///
+    /// let ipa = Ipa::from_kirshenbaum("'n;&n r<trl>a:t.").unwrap();
+    /// assert_eq!(ipa.to_string(), "ˈnʲæn raːʈ");
+    /// assert_eq!(ipa.to_kirshenbaum(), "'n;&n r<trl>a:t.");
+    /// assert!(Ipa::from_kirshenbaum("t<asp>").is_err());
+    /// ```
+    pub fn from_kirshenbaum(kirshenbaum: &str) -> Result<Ipa, TransliterationError> {
+        transliteration::parse(kirshenbaum, KIRSHENBAUM)
+    }
+}
+
+impl IpaSlice {
+    /// Writes the sounds in Kirshenbaum ASCII-IPA; reduced sounds are written as full ones, and releases and
+    /// tone letters, which have no Kirshenbaum symbols, are kept as IPA.
+    pub fn to_kirshenbaum(&self) -> String {
+        transliteration::write(self, KIRSHENBAUM)
+    }
+}
+
+#[cfg(test)]
+mod kirshenbaum_tests {
+    use super::*;
+    use crate::{corpus, Place, Sound};
+
+    #[test]
+    fn test_round_trip() {
+        let ipa: Ipa = corpus::sounds()
+            .filter(|sound| !matches!(sound, Sound::Consonant { is_reduced: true, .. } | Sound::Vowel { is_reduced: true, .. }))
+            .filter(|sound| !matches!(sound, Sound::Consonant { release: Some(_), .. } | Sound::Tone { .. }))
+            .filter(|sound| !matches!(sound, Sound::Consonant { phoneme, .. } if phoneme.place() == Place::Epiglottal))
+            .collect();
+        let written = ipa.to_kirshenbaum();
+        assert!(written.is_ascii());
+        assert_eq!(Ipa::from_kirshenbaum(&written), Ok(ipa));
+    }
+}
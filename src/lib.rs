@@ -1,14 +1,171 @@
-#![feature(iterator_try_collect)]
-
 //! Crate with IPA sounds. Use it to parse and process IPA.
+//!
+//! The crate is `no_std` compatible (it only needs `alloc`) when the default `std` feature is disabled.
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
 
-use std::{fmt, ops::Deref};
+use alloc::{string::String, vec::Vec};
+use core::{fmt, ops::Deref, str::FromStr};
 use alt_enum::alt_val_enum;
 
-alt_val_enum!(
-#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd)]
+mod align;
+mod arpabet;
+mod articulation;
+mod braille;
+mod broad;
+pub mod chart;
+mod checked;
+mod compact;
+pub mod connected;
+pub mod corpus;
+mod diff;
+mod edit;
+mod espeak;
+mod features;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+mod format;
+mod formants;
+pub mod g2p;
+#[cfg(feature = "arbitrary")]
+mod fuzz;
+mod generate;
+#[cfg(feature = "interner")]
+mod interner;
+mod inventory;
+mod kirshenbaum;
+mod mbrola;
+mod minimal_pairs;
+mod names;
+mod natural_class;
+mod notation;
+mod order;
+#[cfg(feature = "rayon")]
+mod parallel;
+mod parser;
+mod pattern;
+mod phonotactics;
+pub mod prelude;
+#[cfg(feature = "python")]
+mod python;
+mod release;
+mod respelling;
+mod rhyme;
+mod rule;
+pub mod sampa;
+mod search;
+mod slice;
+#[cfg(feature = "smallvec")]
+mod small;
+mod sonority;
+mod ssml;
+mod statistics;
+mod suggestion;
+mod syllable;
+mod tipa;
+mod tone;
+mod tracked;
+mod transform;
+mod transliteration;
+mod utterance;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+mod xsampa;
+#[cfg(feature = "audio")]
+mod audio;
+#[cfg(feature = "persistent")]
+mod persistent;
+#[cfg(feature = "presets")]
+pub mod presets;
+#[cfg(feature = "serde")]
+mod serialization;
+#[cfg(feature = "proptest")]
+pub mod strategies;
+
+pub use align::{Alignment, AlignmentStep};
+pub use arpabet::ArpabetOptions;
+pub use articulation::{Backness, ConsonantFeatures, Height, Manner, Place, Voicing, VowelFeatures};
+pub use broad::BroadOptions;
+pub use checked::SoundsError;
+pub use compact::CompactIpa;
+pub use diff::DiffOp;
+pub use features::{DistinctiveFeatures, Feature};
+pub use format::{FormatOptions, IpaFormatter};
+pub use formants::Formants;
+pub use generate::Generator;
+#[cfg(feature = "interner")]
+pub use interner::{InternedIpa, IpaInterner};
+pub use inventory::{Inventory, InventoryError};
+pub use mbrola::{MbrolaOptions, Prosody};
+pub use minimal_pairs::{minimal_pairs, MinimalPair};
+pub use natural_class::{FeatureSpec, NaturalClass};
+pub use notation::Notation;
+#[cfg(feature = "rayon")]
+pub use parallel::parse_lines_parallel;
+pub use parser::{iter_sounds, IpaParser, Sounds};
+pub use pattern::Pattern;
+pub use phonotactics::{Phonotactics, ShapeError, SyllableShape, Violation, ViolationKind};
+pub use release::Release;
+pub use rhyme::RhymeScheme;
+pub use rule::{Change, Derivation, Matcher, Rule};
+pub use search::Needle;
+pub use slice::IpaSlice;
+#[cfg(feature = "smallvec")]
+pub use small::SmallIpa;
+#[cfg(feature = "serde")]
+pub use serialization::structured;
+pub use statistics::{FrequencyTable, Statistics};
+pub use suggestion::Suggestion;
+pub use syllable::{Stress, Syllable, SyllableError};
+pub use tone::{Tone, ToneCategories};
+pub use transliteration::TransliterationError;
+pub use utterance::{Utterance, Word};
+#[cfg(feature = "audio")]
+pub use audio::Synthesizer;
+#[cfg(feature = "persistent")]
+pub use persistent::ImIpa;
+pub use tracked::{SegmentId, TrackedIpa};
+
+/// Declares an enum with `alt_val_enum!` along with an `ALL` constant listing its variants.
+macro_rules! ipa_enum {
+    (
+        $(#[$attr:meta])*
+        $vis:vis $name:ident -> $type:ty:
+            $($($variant:ident $(-)?)+: $value:literal),*
+            $(,)?
+    ) => {
+        alt_val_enum!(
+            $(#[$attr])*
+            $vis $name -> $type:
+                $($($variant)+: $value),*
+        );
+
+        alt_enum::paste::paste! {
+            impl [<$name:camel>] {
+                /// All variants in declaration order.
+                pub const ALL: &'static [Self] = &[$(Self::[<$($variant:camel)+>]),*];
+
+                /// Same as `From`, but usable in const contexts.
+                pub(crate) const fn value(self) -> $type {
+                    match self {
+                        $(Self::[<$($variant:camel)+>] => $value),*
+                    }
+                }
+            }
+        }
+    };
+}
+
+ipa_enum!(
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+#[repr(u8)]
 /// Enum for IPA vowels.
 pub vowels -> char:
+    // New variants are appended rather than sorted in, so existing discriminants stay stable.
     Close back rounded: 'u',
     Close back unrounded: 'ɯ',
     Close central rounded: 'ʉ',
@@ -28,29 +185,212 @@ pub vowels -> char:
     NearOpen front urounded: 'æ',
     Open back unrounded: 'ɑ',
     Open front unrounded: 'a',
-    Open mid back unrounded: 'ʌ'
+    Open mid back unrounded: 'ʌ',
+    Near-open central: 'ɐ',
+    Open back rounded: 'ɒ',
+    Open front rounded: 'ɶ',
+    Open-mid back rounded: 'ɔ',
+    Open-mid central rounded: 'ɞ',
+    Open-mid central unrounded: 'ɜ',
+    Open-mid front rounded: 'œ',
+    Open-mid front unrounded: 'ɛ'
 );
 
-alt_val_enum!(
-#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd)]
+ipa_enum!(
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+#[repr(u8)]
 /// Enum for IPA consonants.
 pub consonants -> char:
+    // New variants are appended rather than sorted in, so existing discriminants stay stable.
     Voiced alveolar nasal: 'n',
     Voiced bilabial nasal: 'm',
     Voiced palatal approximant: 'j',
     Voiceless bilabial plosive: 'p',
+    Voiced alveolar approximant: 'ɹ',
+    Voiced alveolar fricative: 'z',
+    Voiced alveolar lateral approximant: 'l',
+    Voiced alveolar lateral fricative: 'ɮ',
+    Voiced alveolar plosive: 'd',
+    Voiced alveolar tap: 'ɾ',
+    Voiced alveolar trill: 'r',
+    Voiced alveolo-palatal fricative: 'ʑ',
+    Voiced bilabial fricative: 'β',
+    Voiced bilabial plosive: 'b',
+    Voiced bilabial trill: 'ʙ',
+    Voiced dental fricative: 'ð',
+    Voiced labial-palatal approximant: 'ɥ',
+    Voiced labial-velar approximant: 'w',
+    Voiced labiodental approximant: 'ʋ',
+    Voiced labiodental fricative: 'v',
+    Voiced labiodental nasal: 'ɱ',
+    Voiced labiodental tap: 'ⱱ',
+    Voiced palatal fricative: 'ʝ',
+    Voiced palatal lateral approximant: 'ʎ',
+    Voiced palatal nasal: 'ɲ',
+    Voiced palatal plosive: 'ɟ',
+    Voiced pharyngeal fricative: 'ʕ',
+    Voiced postalveolar fricative: 'ʒ',
+    Voiced retroflex approximant: 'ɻ',
+    Voiced retroflex fricative: 'ʐ',
+    Voiced retroflex lateral approximant: 'ɭ',
+    Voiced retroflex nasal: 'ɳ',
+    Voiced retroflex plosive: 'ɖ',
+    Voiced retroflex tap: 'ɽ',
+    Voiced uvular fricative: 'ʁ',
+    Voiced uvular nasal: 'ɴ',
+    Voiced uvular plosive: 'ɢ',
+    Voiced uvular trill: 'ʀ',
+    Voiced velar approximant: 'ɰ',
+    Voiced velar fricative: 'ɣ',
+    Voiced velar lateral approximant: 'ʟ',
+    Voiced velar nasal: 'ŋ',
+    Voiced velar plosive: 'ɡ',
+    Voiceless alveolar fricative: 's',
+    Voiceless alveolar lateral fricative: 'ɬ',
+    Voiceless alveolar plosive: 't',
+    Voiceless alveolo-palatal fricative: 'ɕ',
+    Voiceless bilabial fricative: 'ɸ',
+    Voiceless dental fricative: 'θ',
+    Voiceless labial-velar fricative: 'ʍ',
+    Voiceless labiodental fricative: 'f',
+    Voiceless palatal fricative: 'ç',
+    Voiceless palatal plosive: 'c',
+    Voiceless pharyngeal fricative: 'ħ',
+    Voiceless postalveolar fricative: 'ʃ',
+    Voiceless retroflex fricative: 'ʂ',
+    Voiceless retroflex plosive: 'ʈ',
+    Voiceless uvular fricative: 'χ',
+    Voiceless uvular plosive: 'q',
+    Voiceless velar fricative: 'x',
+    Voiceless velar plosive: 'k',
+    Voiceless glottal plosive: 'ʔ',
+    Voiceless glottal fricative: 'h',
+    Voiced glottal fricative: 'ɦ',
+    Voiceless epiglottal plosive: 'ʡ',
+    Voiceless epiglottal fricative: 'ʜ',
+    Voiced epiglottal fricative: 'ʢ'
 );
 
+/// Superscript forms of base symbols, used in narrow transcription for reduced sounds.
+const SUPERSCRIPTS: &[(char, char)] = &[
+    ('a', 'ᵃ'),
+    ('b', 'ᵇ'),
+    ('c', 'ᶜ'),
+    ('d', 'ᵈ'),
+    ('e', 'ᵉ'),
+    ('f', 'ᶠ'),
+    ('i', 'ⁱ'),
+    ('k', 'ᵏ'),
+    ('m', 'ᵐ'),
+    ('n', 'ⁿ'),
+    ('o', 'ᵒ'),
+    ('p', 'ᵖ'),
+    ('r', 'ʳ'),
+    ('s', 'ˢ'),
+    ('t', 'ᵗ'),
+    ('u', 'ᵘ'),
+    ('v', 'ᵛ'),
+    ('x', 'ˣ'),
+    ('y', 'ʸ'),
+    ('z', 'ᶻ'),
+    ('ð', 'ᶞ'),
+    ('ŋ', 'ᵑ'),
+    ('œ', 'ꟹ'),
+    ('ɐ', 'ᵄ'),
+    ('ɑ', 'ᵅ'),
+    ('ɒ', 'ᶛ'),
+    ('ɔ', 'ᵓ'),
+    ('ɕ', 'ᶝ'),
+    ('ə', 'ᵊ'),
+    ('ɛ', 'ᵋ'),
+    ('ɜ', 'ᶟ'),
+    ('ɡ', 'ᶢ'),
+    ('ɥ', 'ᶣ'),
+    ('ɨ', 'ᶤ'),
+    ('ɪ', 'ᶦ'),
+    ('ɭ', 'ᶩ'),
+    ('ɯ', 'ᵚ'),
+    ('ɰ', 'ᶭ'),
+    ('ɱ', 'ᶬ'),
+    ('ɲ', 'ᶮ'),
+    ('ɳ', 'ᶯ'),
+    ('ɴ', 'ᶰ'),
+    ('ɵ', 'ᶱ'),
+    ('ɸ', 'ᶲ'),
+    ('ɹ', 'ʴ'),
+    ('ɻ', 'ʵ'),
+    ('ʁ', 'ʶ'),
+    ('ʂ', 'ᶳ'),
+    ('ʃ', 'ᶴ'),
+    ('ʉ', 'ᶶ'),
+    ('ʊ', 'ᶷ'),
+    ('ʋ', 'ᶹ'),
+    ('ʌ', 'ᶺ'),
+    ('ʐ', 'ᶼ'),
+    ('ʑ', 'ᶽ'),
+    ('ʒ', 'ᶾ'),
+    ('ʝ', 'ᶨ'),
+    ('ʟ', 'ᶫ'),
+    ('β', 'ᵝ'),
+    ('θ', 'ᶿ'),
+    ('χ', 'ᵡ'),
+];
+
+/// Superscript form of a base symbol, if there is one.
+fn to_superscript(base: char) -> Option<char> {
+    SUPERSCRIPTS.iter()
+        .find(|&&(symbol, _)| symbol == base)
+        .map(|&(_, sup)| sup)
+}
+
 /// Enum for IPA sounds.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd)]
+///
+/// Reduced (weakened or epenthetic) sounds are written with superscript symbols, e.g. 'ᵊ'.
+///
+/// Stress marks, 'ˈ' for primary and 'ˌ' for secondary stress, are sounds of their own, like spaces,
+/// and so are tones such as '˧˥'. Consonants can carry a [`Release`] diacritic, e.g. 't̚'.
+///
+/// The layout is fixed with `#[repr(u8)]` so archived sounds stay compatible between builds.
+///
+/// Sounds are ordered by their position on the IPA chart, see the [`Ord`] implementation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+#[repr(u8)]
 pub enum Sound {
-    Vowel { phoneme: Vowels, is_long: bool },
-    Consonant { phoneme: Consonants, is_long: bool, is_palatalized: bool },
-    Space
+    Vowel { phoneme: Vowels, is_long: bool, is_reduced: bool },
+    Consonant { phoneme: Consonants, is_long: bool, is_palatalized: bool, is_reduced: bool, release: Option<Release> },
+    Space,
+    Stress { is_primary: bool },
+    Tone { contour: Tone }
+}
+
+impl Sound {
+    /// Symbol of the sound's phoneme, in superscript if the sound is reduced and there is a superscript form,
+    /// or the stress mark.
+    fn symbol(self) -> Option<char> {
+        let (symbol, is_reduced) = match self {
+            Sound::Vowel { phoneme, is_reduced, .. } => (char::from(phoneme), is_reduced),
+            Sound::Consonant { phoneme, is_reduced, .. } => (char::from(phoneme), is_reduced),
+            Sound::Space | Sound::Tone { .. } => return None,
+            Sound::Stress { is_primary } => return Some(if is_primary { 'ˈ' } else { 'ˌ' })
+        };
+        Some(if is_reduced {
+            to_superscript(symbol).unwrap_or(symbol)
+        } else {
+            symbol
+        })
+    }
 }
 
 /// Struct containing a sequence of IPA sounds.
 ///
+/// A sequence parsed from text in `/slashes/` or `[brackets]` records its [`Notation`] and is written
+/// with the same delimiters; slicing it gives the bare sounds. Equality only compares the sounds, so
+/// "/ma/" and "ma" parse to equal sequences.
+///
 /// # Examples
 ///
 /// ```
@@ -59,25 +399,65 @@ pub enum Sound {
 ///     "nʲæ nʲæn"
 /// )
 /// ```
-#[derive(Clone, Debug, PartialEq, Eq)]
-pub struct Ipa(Vec<Sound>);
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+pub struct Ipa(Vec<Sound>, Option<Notation>);
+
+impl PartialEq for Ipa {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl Eq for Ipa {}
 
 /// Enum for possible errors when constructing a sequence of IPA sounds from &str.
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Error {
     PalatalizedVowel(char),
-    NotYetImplemented(char)
+    NotYetImplemented(char),
+    NotAllowed(char)
 }
 
-impl fmt::Debug for Error {
+impl fmt::Display for Error {
     fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Error::PalatalizedVowel(vowel) => {
                 write!(formatter, "Vowel ({}) cannot be palatalized", vowel)
             },
             Error::NotYetImplemented(symbol) => {
-                write!(formatter, "'{}' is not yet implemented", symbol)
+                match symbol {
+                    '1'..='5' => write!(formatter, "'{}' is a tone number", symbol)?,
+                    _ => write!(formatter, "'{}' is not yet implemented", symbol)?
+                }
+                match self.suggestion() {
+                    Some(suggestion) => write!(formatter, "; {}", suggestion),
+                    None => Ok(())
+                }
             },
+            Error::NotAllowed(symbol) => {
+                write!(formatter, "'{}' is not in the allowed symbol set", symbol)
+            },
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+impl Error {
+    /// Suggests a supported symbol that was probably meant instead of the unrecognized one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let error = ipa_sounds::Ipa::try_from("n:").unwrap_err();
+    /// assert_eq!(error.suggestion().unwrap().replacement, "ː");
+    /// ```
+    pub fn suggestion(&self) -> Option<Suggestion> {
+        match self {
+            Error::NotYetImplemented(symbol) => suggestion::suggest(*symbol),
+            Error::PalatalizedVowel(_) | Error::NotAllowed(_) => None
         }
     }
 }
@@ -86,50 +466,27 @@ impl TryFrom<&str> for Ipa {
     type Error = Error;
 
     fn try_from(ipa: &str) -> Result<Self, Self::Error> {
+        IpaParser::new().parse(ipa)
+    }
+}
 
-        let ipa: Vec<_> = ipa.chars().collect();
-        (0..ipa.len()).filter_map(|i| {
-            let is_palatalized = if i == ipa.len() - 1 {
-                false
-            } else {
-                matches!(ipa[i + 1], 'ʲ')
-            };
-            let is_long = if i == ipa.len() - 1 {
-                false
-            } else if i < ipa.len() - 2 && is_palatalized {
-                matches!(ipa[i + 2], 'ː')
-            } else {
-                matches!(ipa[i + 1], 'ː')
-            };
-            match ipa[i] {
-                'ʲ' | 'ː' => None,
-
-                ' ' => Some(Ok(Sound::Space)),
-
-                ch => {
-                    Some(match (Consonants::try_from(ch), Vowels::try_from(ch)) {
-                        (Ok(consonant), _) => Ok(
-                            Sound::Consonant {
-                                phoneme: consonant,
-                                is_long,
-                                is_palatalized
-                            }
-                        ),
-                        (_, Ok(vowel)) => if is_palatalized {
-                                Err(Error::PalatalizedVowel(ch))
-                            } else {
-                                Ok(Sound::Vowel {
-                                    phoneme: vowel,
-                                    is_long
-                                })
-                            },
-                        _ => Err(Error::NotYetImplemented(ch))
-                    })
-                }
-            }
-        })
-        .try_collect()
-        .map(Ipa)
+impl Ipa {
+    /// Constructs a sequence of IPA sounds from &str without failing on errors.
+    ///
+    /// Unrecognized symbols are skipped and palatalization of vowels is dropped;
+    /// all encountered errors are returned along with the partial result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ipa_sounds::{Error, Ipa};
+    ///
+    /// let (ipa, errors) = Ipa::from_str_lossy("nʲæþ æʲ");
+    /// assert_eq!(format!("{}", ipa), "nʲæ æ");
+    /// assert_eq!(errors, vec![Error::NotYetImplemented('þ'), Error::PalatalizedVowel('æ')]);
+    /// ```
+    pub fn from_str_lossy(ipa: &str) -> (Ipa, Vec<Error>) {
+        IpaParser::new().parse_with_errors(ipa)
     }
 }
 
@@ -141,34 +498,94 @@ impl TryFrom<String> for Ipa {
     }
 }
 
+impl FromStr for Ipa {
+    type Err = Error;
+
+    fn from_str(ipa: &str) -> Result<Self, Self::Err> {
+        Self::try_from(ipa)
+    }
+}
+
+/// Writes the sounds, enclosed in the delimiters of the notation if there is one.
 impl fmt::Display for Ipa {
     fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
-        self.0.iter().try_for_each(|&sound|
-            write!(formatter, "{}", match sound {
-                Sound::Vowel { phoneme, is_long } => {
-                    format!("{}{}",
-                        char::from(phoneme),
-                        if is_long {"ː"} else {""}
-                    )
-                },
-                Sound::Consonant { phoneme, is_long, is_palatalized } => {
-                    format!("{}{}{}",
-                        char::from(phoneme),
-                        if is_palatalized {"ʲ"} else {""},
-                        if is_long {"ː"} else {""}
-                    )
-                },
-                Sound::Space => " ".to_owned()
-            })
-        )
+        match self.1.map(Notation::delimiters) {
+            Some((open, close)) => {
+                write!(formatter, "{}", open)?;
+                fmt::Display::fmt(&**self, formatter)?;
+                write!(formatter, "{}", close)
+            },
+            None => fmt::Display::fmt(&**self, formatter)
+        }
+    }
+}
+
+mod sealed {
+    /// Supertrait preventing extension traits from being implemented outside the crate.
+    pub trait Sealed {}
+
+    impl Sealed for [super::Sound] {}
+    impl Sealed for super::Sound {}
+    impl Sealed for super::IpaSlice {}
+    impl Sealed for super::Ipa {}
+    impl<const N: usize> Sealed for [super::Sound; N] {}
+}
+
+/// Extension methods for slices of IPA sounds.
+///
+/// This trait is sealed, so new methods can be added without breaking users.
+pub trait SoundsExt: sealed::Sealed {
+    /// Copies the sounds into a new sequence.
+    fn to_ipa(&self) -> Ipa;
+}
+
+impl SoundsExt for [Sound] {
+    fn to_ipa(&self) -> Ipa {
+        Ipa(self.to_vec(), None)
     }
 }
 
 impl Deref for Ipa {
-    type Target = [Sound];
+    type Target = IpaSlice;
 
     fn deref(&self) -> &Self::Target {
-        &self.0
+        IpaSlice::new(&self.0)
+    }
+}
+
+impl IntoIterator for Ipa {
+    type Item = Sound;
+    type IntoIter = alloc::vec::IntoIter<Sound>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Ipa {
+    type Item = &'a Sound;
+    type IntoIter = core::slice::Iter<'a, Sound>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl FromIterator<Sound> for Ipa {
+    fn from_iter<I: IntoIterator<Item = Sound>>(sounds: I) -> Self {
+        Ipa(sounds.into_iter().collect(), None)
+    }
+}
+
+impl Extend<Sound> for Ipa {
+    fn extend<I: IntoIterator<Item = Sound>>(&mut self, sounds: I) {
+        self.0.extend(sounds)
+    }
+}
+
+impl<'a> Extend<&'a Sound> for Ipa {
+    fn extend<I: IntoIterator<Item = &'a Sound>>(&mut self, sounds: I) {
+        self.0.extend(sounds)
     }
 }
 
@@ -180,15 +597,18 @@ mod ipa_build_tests {
     fn test_nja() {
         assert_eq!(
             Ipa::try_from("nʲæ"),
-            Ok(Ipa(vec![
+            Ok(Ipa::from_sounds_unchecked(vec![
                 Sound::Consonant {
                     phoneme: Consonants::VoicedAlveolarNasal,
                     is_long: false,
-                    is_palatalized: true
+                    is_palatalized: true,
+                    is_reduced: false,
+                    release: None
                 },
                 Sound::Vowel {
                     phoneme: Vowels::NearOpenFrontUrounded,
-                    is_long: false
+                    is_long: false,
+                    is_reduced: false
                 }
             ]))
         );
@@ -212,6 +632,143 @@ mod ipa_build_tests {
         );
         
     }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("nʲæ".parse::<Ipa>(), Ipa::try_from("nʲæ"));
+        assert_eq!("æʲ".parse::<Ipa>(), Err(Error::PalatalizedVowel('æ')));
+    }
+
+    #[test]
+    fn test_lossy() {
+        assert_eq!(
+            Ipa::from_str_lossy("þæʲː"),
+            (
+                Ipa::from_sounds_unchecked(vec![
+                    Sound::Vowel {
+                        phoneme: Vowels::NearOpenFrontUrounded,
+                        is_long: true,
+                        is_reduced: false
+                    }
+                ]),
+                vec![Error::NotYetImplemented('þ'), Error::PalatalizedVowel('æ')]
+            )
+        );
+    }
+}
+
+#[cfg(test)]
+mod reduced_tests {
+    use super::*;
+
+    #[test]
+    fn test_reduced() {
+        assert_eq!(
+            Ipa::try_from("pᵊⁿʲ"),
+            Ok(Ipa::from_sounds_unchecked(vec![
+                Sound::Consonant {
+                    phoneme: Consonants::VoicelessBilabialPlosive,
+                    is_long: false,
+                    is_palatalized: false,
+                    is_reduced: false,
+                    release: None
+                },
+                Sound::Vowel {
+                    phoneme: Vowels::MidCentral,
+                    is_long: false,
+                    is_reduced: true
+                },
+                Sound::Consonant {
+                    phoneme: Consonants::VoicedAlveolarNasal,
+                    is_long: false,
+                    is_palatalized: true,
+                    is_reduced: true,
+                    release: None
+                }
+            ]))
+        );
+        assert_eq!(
+            Ipa::try_from("pᵊⁿʲ").map(|ipa| format!("{}", ipa)),
+            Ok("pᵊⁿʲ".to_owned())
+        );
+    }
+}
+
+#[cfg(test)]
+mod error_tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            Error::PalatalizedVowel('æ').to_string(),
+            "Vowel (æ) cannot be palatalized"
+        );
+        assert_eq!(
+            Error::NotYetImplemented('þ').to_string(),
+            "'þ' is not yet implemented"
+        );
+    }
+
+    #[test]
+    fn test_boxed() {
+        let error: Box<dyn std::error::Error> = Box::new(Error::NotYetImplemented(':'));
+        assert_eq!(
+            error.to_string(),
+            "':' is not yet implemented; did you mean 'ː'? (the length mark is 'ː', not a colon)"
+        );
+    }
+}
+
+#[cfg(all(test, feature = "rkyv"))]
+mod rkyv_tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let ipa = Ipa::try_from("nʲæ pᵊmː").unwrap();
+        let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&ipa).unwrap();
+        let archived = rkyv::access::<ArchivedIpa, rkyv::rancor::Error>(&bytes).unwrap();
+        assert_eq!(archived.0.len(), 6);
+        assert_eq!(rkyv::deserialize::<Ipa, rkyv::rancor::Error>(archived).unwrap(), ipa);
+    }
+}
+
+#[cfg(test)]
+mod ipa_iter_tests {
+    use super::*;
+
+    #[test]
+    fn test_collect() {
+        let ipa = Ipa::try_from("nʲæ nʲæn").unwrap();
+        let vowels: Ipa = ipa.into_iter()
+            .filter(|sound| !matches!(sound, Sound::Consonant { .. }))
+            .collect();
+        assert_eq!(vowels, Ipa::try_from("æ æ").unwrap());
+    }
+
+    #[test]
+    fn test_extend() {
+        let mut ipa = Ipa::try_from("nʲæ").unwrap();
+        let tail = Ipa::try_from(" n").unwrap();
+        ipa.extend(&tail);
+        ipa.extend(tail);
+        assert_eq!(ipa, Ipa::try_from("nʲæ n n").unwrap());
+    }
+}
+
+#[cfg(test)]
+mod words_tests {
+    use super::*;
+
+    #[test]
+    fn test_words() {
+        let ipa = Ipa::try_from("nʲæ nʲæn ").unwrap();
+        let words: Vec<_> = ipa.words().map(IpaSlice::to_owned).collect();
+        assert_eq!(words, [Ipa::try_from("nʲæ").unwrap(), Ipa::try_from("nʲæn").unwrap()]);
+        assert_eq!(ipa.word_count(), 2);
+        assert_eq!(Ipa::try_from("  ").unwrap().word_count(), 0);
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,107 @@
+//! MBROLA .pho files, the input of the MBROLA diphone synthesizer.
+
+use alloc::{format, string::String, vec::Vec};
+use crate::{transliteration, xsampa::XSAMPA, IpaSlice, Sound};
+
+/// Duration and pitch of one sound in a .pho file.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Prosody {
+    /// Duration in milliseconds, the default of the options if `None`.
+    pub duration: Option<u32>,
+    /// Pitch targets: positions in percent of the duration with frequencies in Hz.
+    pub pitch: Vec<(u8, u32)>
+}
+
+/// Default durations of a .pho file.
+///
+/// # Examples
+///
+/// ```
+/// use ipa_sounds::{Ipa, MbrolaOptions, Prosody};
+///
+/// let ipa = Ipa::try_from("ˈnoː ʃa").unwrap();
+/// let stressed = Prosody { duration: Some(120), pitch: Vec::from([(0, 130), (100, 110)]) };
+/// let pho = ipa.to_mbrola_with(&MbrolaOptions::new().duration(60), &[Prosody::default(), Prosody::default(), stressed]);
+/// assert_eq!(pho, "n 60\no 240 0 130 100 110\n_ 200\nS 60\na 60\n");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MbrolaOptions {
+    duration: u32,
+    pause: u32
+}
+
+impl Default for MbrolaOptions {
+    fn default() -> Self {
+        Self { duration: 80, pause: 200 }
+    }
+}
+
+impl MbrolaOptions {
+    /// Creates options with sounds of 80 ms and pauses between words of 200 ms.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the default duration of a sound in milliseconds.
+    pub fn duration(mut self, duration: u32) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Sets the default duration of the pause written for a space in milliseconds.
+    pub fn pause(mut self, pause: u32) -> Self {
+        self.pause = pause;
+        self
+    }
+}
+
+impl IpaSlice {
+    /// Writes the sounds as an MBROLA .pho file with the default options and no pitch targets.
+    pub fn to_mbrola(&self) -> String {
+        self.to_mbrola_with(&MbrolaOptions::default(), &[])
+    }
+
+    /// Writes the sounds as an MBROLA .pho file, one phoneme per line.
+    ///
+    /// The prosody of a sound is at its index, and sounds past the end of `prosody` get the defaults.
+    /// Phonemes are named in X-SAMPA, as most MBROLA voices do, and long sounds get twice their
+    /// duration instead of a length mark. Spaces become the pause phoneme "_", and stress marks are
+    /// left to the pitch targets.
+    pub fn to_mbrola_with(&self, options: &MbrolaOptions, prosody: &[Prosody]) -> String {
+        let mut pho = String::new();
+        for (i, &sound) in self.iter().enumerate() {
+            let hint = prosody.get(i);
+            let (phoneme, default, is_long) = match sound {
+                Sound::Stress { .. } | Sound::Tone { .. } => continue,
+                Sound::Space => (String::from("_"), options.pause, false),
+                Sound::Vowel { phoneme, is_long, is_reduced } => {
+                    let short = [Sound::Vowel { phoneme, is_long: false, is_reduced }];
+                    (transliteration::write(IpaSlice::new(&short), XSAMPA), options.duration, is_long)
+                },
+                Sound::Consonant { phoneme, is_long, is_palatalized, is_reduced, release } => {
+                    let short = [Sound::Consonant { phoneme, is_long: false, is_palatalized, is_reduced, release }];
+                    (transliteration::write(IpaSlice::new(&short), XSAMPA), options.duration, is_long)
+                }
+            };
+            let duration = hint.and_then(|hint| hint.duration).unwrap_or(default) * if is_long { 2 } else { 1 };
+            pho.push_str(&format!("{} {}", phoneme, duration));
+            for (position, frequency) in hint.into_iter().flat_map(|hint| &hint.pitch) {
+                pho.push_str(&format!(" {} {}", position, frequency));
+            }
+            pho.push('\n');
+        }
+        pho
+    }
+}
+
+#[cfg(test)]
+mod mbrola_tests {
+    use crate::Ipa;
+
+    #[test]
+    fn test_to_mbrola() {
+        assert_eq!(Ipa::try_from("tʲaː").unwrap().to_mbrola(), "t' 80\na 160\n");
+        assert_eq!(Ipa::try_from("").unwrap().to_mbrola(), "");
+    }
+}
@@ -0,0 +1,71 @@
+//! Minimal pairs: words differing in exactly one sound.
+
+use alloc::vec::Vec;
+use crate::{Ipa, Sound};
+
+/// Two words of a collection differing only in the sound at one position.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MinimalPair {
+    /// Indices of the words in the collection, the first one smaller.
+    pub words: (usize, usize),
+    /// Index of the differing sound in both words.
+    pub position: usize,
+    /// Differing sounds, in the order of the words.
+    pub sounds: (Sound, Sound)
+}
+
+/// Finds all minimal pairs in a collection of words, optionally only those contrasting the two sounds.
+///
+/// Words are compared sound by sound, so stress marks and length count as differences. Pairs are
+/// ordered by their first and then second word.
+///
+/// # Examples
+///
+/// ```
+/// use ipa_sounds::{minimal_pairs, Ipa};
+///
+/// let words = ["lajt", "rajt", "lajk", "bajk"].map(|word| Ipa::try_from(word).unwrap());
+/// let pairs = minimal_pairs(&words, None);
+/// assert_eq!(pairs.iter().map(|pair| pair.words).collect::<Vec<_>>(), [(0, 1), (0, 2), (2, 3)]);
+/// let [l, r] = [words[0][0], words[1][0]];
+/// let pairs = minimal_pairs(&words, Some([r, l]));
+/// assert_eq!(pairs.len(), 1);
+/// assert_eq!((pairs[0].position, pairs[0].sounds), (0, (l, r)));
+/// ```
+pub fn minimal_pairs(words: &[Ipa], contrast: Option<[Sound; 2]>) -> Vec<MinimalPair> {
+    let mut pairs = Vec::new();
+    for (i, first) in words.iter().enumerate() {
+        for (j, second) in words.iter().enumerate().skip(i + 1) {
+            if first.len() != second.len() {
+                continue;
+            }
+            let mut differences = (0..first.len()).filter(|&k| first[k] != second[k]);
+            let (Some(position), None) = (differences.next(), differences.next()) else {
+                continue;
+            };
+            let sounds = (first[position], second[position]);
+            if contrast.is_none_or(|[a, b]| sounds == (a, b) || sounds == (b, a)) {
+                pairs.push(MinimalPair { words: (i, j), position, sounds });
+            }
+        }
+    }
+    pairs
+}
+
+#[cfg(test)]
+mod minimal_pairs_tests {
+    use super::*;
+
+    #[test]
+    fn test_minimal_pairs() {
+        let words = ["ʃip", "ʃiːp", "ʃip", "ˈʃip", "ʃiːps"].map(|word| Ipa::try_from(word).unwrap());
+        let pairs = minimal_pairs(&words, None);
+        assert_eq!(pairs, [
+            MinimalPair { words: (0, 1), position: 1, sounds: (words[0][1], words[1][1]) },
+            MinimalPair { words: (1, 2), position: 1, sounds: (words[1][1], words[2][1]) }
+        ]);
+        assert!(minimal_pairs(&words, Some([words[0][0], words[0][2]])).is_empty());
+        assert!(minimal_pairs(&[], None).is_empty());
+    }
+}
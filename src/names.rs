@@ -0,0 +1,210 @@
+//! Descriptive names of sounds, as used on the IPA chart.
+
+use core::fmt;
+use crate::{Consonants, Sound, Vowels};
+
+impl Vowels {
+    /// Descriptive name, e.g. "near-open front unrounded vowel".
+    pub const fn name(self) -> &'static str {
+        match self {
+            Vowels::CloseBackRounded => "close back rounded vowel",
+            Vowels::CloseBackUnrounded => "close back unrounded vowel",
+            Vowels::CloseCentralRounded => "close central rounded vowel",
+            Vowels::CloseCentralUnrounded => "close central unrounded vowel",
+            Vowels::CloseFrontRounded => "close front rounded vowel",
+            Vowels::CloseFrontUnrounded => "close front unrounded vowel",
+            Vowels::CloseMidBackRounded => "close-mid back rounded vowel",
+            Vowels::CloseMidBackUnrounded => "close-mid back unrounded vowel",
+            Vowels::CloseMidCentralRounded => "close-mid central rounded vowel",
+            Vowels::CloseMidCentralUnrounded => "close-mid central unrounded vowel",
+            Vowels::CloseMidFrontRounded => "close-mid front rounded vowel",
+            Vowels::CloseMidFrontUnrounded => "close-mid front unrounded vowel",
+            Vowels::MidCentral => "mid central vowel",
+            Vowels::NearCloseNearBackRounded => "near-close near-back rounded vowel",
+            Vowels::NearCloseNearFrontRounded => "near-close near-front rounded vowel",
+            Vowels::NearCloseNearFrontUnrounded => "near-close near-front unrounded vowel",
+            Vowels::NearOpenFrontUrounded => "near-open front unrounded vowel",
+            Vowels::OpenBackUnrounded => "open back unrounded vowel",
+            Vowels::OpenFrontUnrounded => "open front unrounded vowel",
+            Vowels::OpenMidBackUnrounded => "open-mid back unrounded vowel",
+            Vowels::NearOpenCentral => "near-open central vowel",
+            Vowels::OpenBackRounded => "open back rounded vowel",
+            Vowels::OpenFrontRounded => "open front rounded vowel",
+            Vowels::OpenMidBackRounded => "open-mid back rounded vowel",
+            Vowels::OpenMidCentralRounded => "open-mid central rounded vowel",
+            Vowels::OpenMidCentralUnrounded => "open-mid central unrounded vowel",
+            Vowels::OpenMidFrontRounded => "open-mid front rounded vowel",
+            Vowels::OpenMidFrontUnrounded => "open-mid front unrounded vowel"
+        }
+    }
+
+    /// Looks up a vowel by its descriptive name, ignoring case and punctuation; the final "vowel" may be omitted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ipa_sounds::Vowels;
+    ///
+    /// assert_eq!(Vowels::from_description("Near open front unrounded"), Some(Vowels::NearOpenFrontUrounded));
+    /// assert_eq!(Vowels::from_description("close-mid front unrounded vowel"), Some(Vowels::CloseMidFrontUnrounded));
+    /// ```
+    pub fn from_description(description: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|vowel| {
+            let name = vowel.name();
+            describes(description, name) || name.strip_suffix(" vowel").is_some_and(|name| describes(description, name))
+        })
+    }
+}
+
+impl Consonants {
+    /// Descriptive name, e.g. "voiced alveolar nasal".
+    pub const fn name(self) -> &'static str {
+        match self {
+            Consonants::VoicedAlveolarNasal => "voiced alveolar nasal",
+            Consonants::VoicedBilabialNasal => "voiced bilabial nasal",
+            Consonants::VoicedPalatalApproximant => "voiced palatal approximant",
+            Consonants::VoicelessBilabialPlosive => "voiceless bilabial plosive",
+            Consonants::VoicedAlveolarApproximant => "voiced alveolar approximant",
+            Consonants::VoicedAlveolarFricative => "voiced alveolar fricative",
+            Consonants::VoicedAlveolarLateralApproximant => "voiced alveolar lateral approximant",
+            Consonants::VoicedAlveolarLateralFricative => "voiced alveolar lateral fricative",
+            Consonants::VoicedAlveolarPlosive => "voiced alveolar plosive",
+            Consonants::VoicedAlveolarTap => "voiced alveolar tap",
+            Consonants::VoicedAlveolarTrill => "voiced alveolar trill",
+            Consonants::VoicedAlveoloPalatalFricative => "voiced alveolo-palatal fricative",
+            Consonants::VoicedBilabialFricative => "voiced bilabial fricative",
+            Consonants::VoicedBilabialPlosive => "voiced bilabial plosive",
+            Consonants::VoicedBilabialTrill => "voiced bilabial trill",
+            Consonants::VoicedDentalFricative => "voiced dental fricative",
+            Consonants::VoicedLabialPalatalApproximant => "voiced labial-palatal approximant",
+            Consonants::VoicedLabialVelarApproximant => "voiced labial-velar approximant",
+            Consonants::VoicedLabiodentalApproximant => "voiced labiodental approximant",
+            Consonants::VoicedLabiodentalFricative => "voiced labiodental fricative",
+            Consonants::VoicedLabiodentalNasal => "voiced labiodental nasal",
+            Consonants::VoicedLabiodentalTap => "voiced labiodental tap",
+            Consonants::VoicedPalatalFricative => "voiced palatal fricative",
+            Consonants::VoicedPalatalLateralApproximant => "voiced palatal lateral approximant",
+            Consonants::VoicedPalatalNasal => "voiced palatal nasal",
+            Consonants::VoicedPalatalPlosive => "voiced palatal plosive",
+            Consonants::VoicedPharyngealFricative => "voiced pharyngeal fricative",
+            Consonants::VoicedPostalveolarFricative => "voiced postalveolar fricative",
+            Consonants::VoicedRetroflexApproximant => "voiced retroflex approximant",
+            Consonants::VoicedRetroflexFricative => "voiced retroflex fricative",
+            Consonants::VoicedRetroflexLateralApproximant => "voiced retroflex lateral approximant",
+            Consonants::VoicedRetroflexNasal => "voiced retroflex nasal",
+            Consonants::VoicedRetroflexPlosive => "voiced retroflex plosive",
+            Consonants::VoicedRetroflexTap => "voiced retroflex tap",
+            Consonants::VoicedUvularFricative => "voiced uvular fricative",
+            Consonants::VoicedUvularNasal => "voiced uvular nasal",
+            Consonants::VoicedUvularPlosive => "voiced uvular plosive",
+            Consonants::VoicedUvularTrill => "voiced uvular trill",
+            Consonants::VoicedVelarApproximant => "voiced velar approximant",
+            Consonants::VoicedVelarFricative => "voiced velar fricative",
+            Consonants::VoicedVelarLateralApproximant => "voiced velar lateral approximant",
+            Consonants::VoicedVelarNasal => "voiced velar nasal",
+            Consonants::VoicedVelarPlosive => "voiced velar plosive",
+            Consonants::VoicelessAlveolarFricative => "voiceless alveolar fricative",
+            Consonants::VoicelessAlveolarLateralFricative => "voiceless alveolar lateral fricative",
+            Consonants::VoicelessAlveolarPlosive => "voiceless alveolar plosive",
+            Consonants::VoicelessAlveoloPalatalFricative => "voiceless alveolo-palatal fricative",
+            Consonants::VoicelessBilabialFricative => "voiceless bilabial fricative",
+            Consonants::VoicelessDentalFricative => "voiceless dental fricative",
+            Consonants::VoicelessLabialVelarFricative => "voiceless labial-velar fricative",
+            Consonants::VoicelessLabiodentalFricative => "voiceless labiodental fricative",
+            Consonants::VoicelessPalatalFricative => "voiceless palatal fricative",
+            Consonants::VoicelessPalatalPlosive => "voiceless palatal plosive",
+            Consonants::VoicelessPharyngealFricative => "voiceless pharyngeal fricative",
+            Consonants::VoicelessPostalveolarFricative => "voiceless postalveolar fricative",
+            Consonants::VoicelessRetroflexFricative => "voiceless retroflex fricative",
+            Consonants::VoicelessRetroflexPlosive => "voiceless retroflex plosive",
+            Consonants::VoicelessUvularFricative => "voiceless uvular fricative",
+            Consonants::VoicelessUvularPlosive => "voiceless uvular plosive",
+            Consonants::VoicelessVelarFricative => "voiceless velar fricative",
+            Consonants::VoicelessVelarPlosive => "voiceless velar plosive",
+            Consonants::VoicelessGlottalPlosive => "voiceless glottal plosive",
+            Consonants::VoicelessGlottalFricative => "voiceless glottal fricative",
+            Consonants::VoicedGlottalFricative => "voiced glottal fricative",
+            Consonants::VoicelessEpiglottalPlosive => "voiceless epiglottal plosive",
+            Consonants::VoicelessEpiglottalFricative => "voiceless epiglottal fricative",
+            Consonants::VoicedEpiglottalFricative => "voiced epiglottal fricative"
+        }
+    }
+
+    /// Looks up a consonant by its descriptive name, ignoring case and punctuation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ipa_sounds::Consonants;
+    ///
+    /// assert_eq!(Consonants::from_description("Voiced alveolar nasal"), Some(Consonants::VoicedAlveolarNasal));
+    /// assert_eq!(Consonants::from_description("voiced pharyngeal nasal"), None);
+    /// ```
+    pub fn from_description(description: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|consonant| describes(description, consonant.name()))
+    }
+}
+
+/// Words of a text, ignoring punctuation.
+fn words(text: &str) -> impl Iterator<Item = &str> {
+    text.split(|symbol: char| !symbol.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+}
+
+/// Whether the description consists of the same words as the name, ignoring case and punctuation.
+fn describes(description: &str, name: &str) -> bool {
+    let mut description = words(description);
+    words(name).all(|word| description.next().is_some_and(|described| described.eq_ignore_ascii_case(word)))
+        && description.next().is_none()
+}
+
+/// Writes the descriptive name of a sound, e.g. "long palatalized voiced alveolar nasal" or "primary stress".
+pub(crate) fn write_name(writer: &mut impl fmt::Write, sound: Sound) -> fmt::Result {
+    let (name, is_long, is_palatalized, is_reduced, release) = match sound {
+        Sound::Vowel { phoneme, is_long, is_reduced } => (phoneme.name(), is_long, false, is_reduced, None),
+        Sound::Consonant { phoneme, is_long, is_palatalized, is_reduced, release } =>
+            (phoneme.name(), is_long, is_palatalized, is_reduced, release),
+        Sound::Space => return writer.write_str("space"),
+        Sound::Stress { is_primary } => return writer.write_str(if is_primary { "primary stress" } else { "secondary stress" }),
+        Sound::Tone { contour } => {
+            writer.write_str("tone ")?;
+            return contour.levels().iter().try_for_each(|level| write!(writer, "{}", level));
+        }
+    };
+    [(is_reduced, "reduced "), (is_long, "long "), (is_palatalized, "palatalized ")].into_iter()
+        .filter(|&(is_set, _)| is_set)
+        .try_for_each(|(_, modifier)| writer.write_str(modifier))?;
+    if let Some(release) = release {
+        write!(writer, "{} ", release.name())?;
+    }
+    writer.write_str(name)
+}
+
+#[cfg(test)]
+mod names_tests {
+    use crate::{Consonants, Ipa, Vowels};
+
+    #[test]
+    fn test_alternate_display() {
+        let ipa = Ipa::try_from("nʲæ ᵊpː").unwrap();
+        assert_eq!(
+            format!("{:#}", ipa),
+            "palatalized voiced alveolar nasal, near-open front unrounded vowel, space, \
+             reduced mid central vowel, long voiceless bilabial plosive"
+        );
+        assert_eq!(format!("{:#}", ipa[1]), "near-open front unrounded vowel");
+    }
+
+    #[test]
+    fn test_descriptions_round_trip() {
+        Vowels::ALL.iter().for_each(|&vowel| {
+            assert_eq!(Vowels::from_description(&vowel.name().to_uppercase()), Some(vowel));
+        });
+        Consonants::ALL.iter().for_each(|&consonant| {
+            assert_eq!(Consonants::from_description(consonant.name()), Some(consonant));
+        });
+        assert_eq!(Vowels::from_description("mid, central"), Some(Vowels::MidCentral));
+        assert_eq!(Vowels::from_description("mid central vowel vowel"), None);
+        assert_eq!(Consonants::from_description("voiced alveolar"), None);
+    }
+}
@@ -0,0 +1,245 @@
+//! Natural classes: sets of sounds sharing a feature specification.
+
+use core::fmt;
+use crate::{corpus, to_superscript, Consonants, DistinctiveFeatures, Feature, Sound, Vowels};
+
+/// Partial feature specification, e.g. [+voice, -continuant]; unspecified features match either value.
+///
+/// # Examples
+///
+/// ```
+/// use ipa_sounds::{Feature, FeatureSpec, Ipa};
+///
+/// let voiced_stops = FeatureSpec::new().plus(Feature::Voice).minus(Feature::Continuant);
+/// let ipa = Ipa::try_from("mpa").unwrap();
+/// assert_eq!(ipa.iter().map(|sound| sound.matches(&voiced_stops)).collect::<Vec<_>>(), [true, false, false]);
+/// assert_eq!(voiced_stops.to_string(), "[-continuant, +voice]");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FeatureSpec {
+    plus: u32,
+    minus: u32
+}
+
+impl FeatureSpec {
+    /// Creates a specification matching every sound.
+    pub const fn new() -> Self {
+        Self { plus: 0, minus: 0 }
+    }
+
+    /// Requires the feature to be +.
+    pub const fn plus(mut self, feature: Feature) -> Self {
+        self.plus |= feature.bit();
+        self.minus &= !feature.bit();
+        self
+    }
+
+    /// Requires the feature to be -.
+    pub const fn minus(mut self, feature: Feature) -> Self {
+        self.minus |= feature.bit();
+        self.plus &= !feature.bit();
+        self
+    }
+
+    /// Required value of the feature, `None` if it is unspecified.
+    pub const fn value(&self, feature: Feature) -> Option<bool> {
+        if self.plus & feature.bit() != 0 {
+            Some(true)
+        } else if self.minus & feature.bit() != 0 {
+            Some(false)
+        } else {
+            None
+        }
+    }
+
+    /// Whether the bundle has every specified value.
+    pub const fn matches(&self, features: DistinctiveFeatures) -> bool {
+        features.0 & self.plus == self.plus && features.0 & self.minus == 0
+    }
+
+    /// Bundle with the specified values set and the others unchanged.
+    pub const fn apply(&self, features: DistinctiveFeatures) -> DistinctiveFeatures {
+        DistinctiveFeatures(features.0 & !self.minus | self.plus)
+    }
+}
+
+/// Writes the specified values in brackets, e.g. "[-continuant, +voice]".
+impl fmt::Display for FeatureSpec {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("[")?;
+        Feature::ALL.iter()
+            .filter_map(|&feature| self.value(feature).map(|value| (feature, value)))
+            .enumerate()
+            .try_for_each(|(i, (feature, value))| {
+                let separator = if i > 0 { ", " } else { "" };
+                write!(formatter, "{}{}{}", separator, if value { '+' } else { '-' }, feature.name())
+            })?;
+        formatter.write_str("]")
+    }
+}
+
+impl Sound {
+    /// Whether the sound matches the specification; a space matches nothing.
+    pub const fn matches(&self, spec: &FeatureSpec) -> bool {
+        match self.features() {
+            Some(features) => spec.matches(features),
+            None => false
+        }
+    }
+
+    /// Supported sound with the specified values changed, `None` for a space, a stress mark or a tone.
+    ///
+    /// If no supported sound has the changed features, the sound is returned unchanged, e.g. devoicing
+    /// keeps \[m\] and vowels as they are. A specification that changes no feature keeps the sound. Length
+    /// and palatalization follow [+long] and, for consonants, [+front], while reduction and release are
+    /// kept where the result allows them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ipa_sounds::{Feature, FeatureSpec, Ipa};
+    ///
+    /// let devoice = FeatureSpec::new().minus(Feature::Voice);
+    /// let devoiced: Ipa = Ipa::try_from("bzʲma").unwrap().iter().map(|sound| sound.with_features(&devoice).unwrap()).collect();
+    /// assert_eq!(devoiced.to_string(), "psʲma");
+    /// ```
+    pub fn with_features(&self, spec: &FeatureSpec) -> Option<Sound> {
+        let features = self.features()?;
+        let changed = spec.apply(features);
+        if changed == features {
+            return Some(*self);
+        }
+        // The phoneme is found from the features without length and palatalization, which are put back,
+        // preferring the current phoneme among those sharing a bundle.
+        let is_long = changed.has(Feature::Long);
+        let full = if changed.has(Feature::Syllabic) {
+            let current = if let Sound::Vowel { phoneme, .. } = *self { Some(phoneme) } else { None };
+            Vowels::ALL.iter().copied()
+                .filter(|phoneme| DistinctiveFeatures::vowel(phoneme.features(), is_long) == changed)
+                .min_by_key(|&phoneme| Some(phoneme) != current)
+                .map(|phoneme| Sound::Vowel { phoneme, is_long, is_reduced: false })
+        } else {
+            let current = if let Sound::Consonant { phoneme, .. } = *self { Some(phoneme) } else { None };
+            let is_palatalized = changed.has(Feature::Front);
+            Consonants::ALL.iter().copied()
+                .filter(|phoneme| DistinctiveFeatures::consonant(phoneme.features(), is_long, is_palatalized) == changed)
+                .min_by_key(|&phoneme| Some(phoneme) != current)
+                .map(|phoneme| Sound::Consonant { phoneme, is_long, is_palatalized, is_reduced: false, release: None })
+        };
+        let Some(full) = full else {
+            return Some(*self);
+        };
+        // Reduction and release are not features, so they are kept where the changed sound allows them.
+        let (is_reduced, release) = match *self {
+            Sound::Vowel { is_reduced, .. } => (is_reduced, None),
+            Sound::Consonant { is_reduced, release, .. } => (is_reduced, release),
+            _ => (false, None)
+        };
+        Some(match full {
+            Sound::Vowel { phoneme, is_long, .. } => Sound::Vowel {
+                phoneme,
+                is_long,
+                is_reduced: is_reduced && to_superscript(char::from(phoneme)).is_some()
+            },
+            Sound::Consonant { phoneme, is_long, is_palatalized, .. } => Sound::Consonant {
+                phoneme,
+                is_long,
+                is_palatalized,
+                is_reduced: is_reduced && to_superscript(char::from(phoneme)).is_some(),
+                release: release.filter(|release| release.is_allowed(phoneme))
+            },
+            sound => sound
+        })
+    }
+}
+
+/// Natural class of the supported sounds described by a [`FeatureSpec`].
+///
+/// # Examples
+///
+/// ```
+/// use ipa_sounds::{Feature, FeatureSpec, NaturalClass};
+///
+/// let front_rounded = NaturalClass::new(FeatureSpec::new().plus(Feature::Syllabic).plus(Feature::Front).plus(Feature::Round).minus(Feature::Long));
+/// let members: String = front_rounded.members().map(|sound| sound.to_string()).collect();
+/// assert_eq!(members, "yøʏɶœ");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NaturalClass {
+    spec: FeatureSpec
+}
+
+impl NaturalClass {
+    /// Creates the class of sounds matching the specification.
+    pub const fn new(spec: FeatureSpec) -> Self {
+        Self { spec }
+    }
+
+    /// Specification of the class.
+    pub const fn spec(&self) -> &FeatureSpec {
+        &self.spec
+    }
+
+    /// Whether the sound belongs to the class.
+    pub const fn contains(&self, sound: &Sound) -> bool {
+        sound.matches(&self.spec)
+    }
+
+    /// Iterator over all supported full (not reduced) sounds in the class, vowels first, in declaration order.
+    pub fn members(&self) -> impl Iterator<Item = Sound> + '_ {
+        segments().filter(move |sound| self.contains(sound))
+    }
+}
+
+impl From<FeatureSpec> for NaturalClass {
+    fn from(spec: FeatureSpec) -> Self {
+        Self::new(spec)
+    }
+}
+
+/// Every full sound the parser can produce: each phoneme short and long, consonants also palatalized,
+/// without reduced sounds, releases and tones.
+pub(crate) fn segments() -> impl Iterator<Item = Sound> {
+    corpus::sounds().filter(|sound| !matches!(sound,
+        Sound::Vowel { is_reduced: true, .. } | Sound::Consonant { is_reduced: true, .. }
+            | Sound::Consonant { release: Some(_), .. } | Sound::Tone { .. }
+    ))
+}
+
+#[cfg(test)]
+mod natural_class_tests {
+    use super::*;
+    use crate::Ipa;
+
+    #[test]
+    fn test_members() {
+        let nasals = NaturalClass::new(FeatureSpec::new().plus(Feature::Nasal));
+        assert_eq!(nasals.members().count(), 7 * 4);
+        assert!(nasals.members().all(|sound| matches!(sound, Sound::Consonant { .. })));
+        assert_eq!(NaturalClass::default().members().count(), segments().count());
+        assert!(!NaturalClass::default().contains(&Sound::Space));
+    }
+
+    #[test]
+    fn test_with_features() {
+        assert!(corpus::sounds().all(|sound| sound.with_features(&FeatureSpec::new()) == sound.features().map(|_| sound)));
+        let change = |ipa: &str, spec: FeatureSpec| Ipa::try_from(ipa).unwrap()[0].with_features(&spec).unwrap().to_string();
+        let devoice = FeatureSpec::new().minus(Feature::Voice);
+        assert_eq!(change("ɡʲ", devoice), "kʲ");
+        assert_eq!(change("ɢʲː", devoice), "qʲː");
+        assert_eq!(change("ɜ", FeatureSpec::new().plus(Feature::Long)), "ɜː");
+        assert_eq!(change("ᶢⁿʲ", devoice), "ᵏⁿʲ");
+        assert_eq!(change("dⁿ", FeatureSpec::new().plus(Feature::Continuant).plus(Feature::Strident)), "z");
+        assert_eq!(change("nʲ", FeatureSpec::new().minus(Feature::Front)), "n");
+    }
+
+    #[test]
+    fn test_later_value_wins() {
+        let spec = FeatureSpec::new().plus(Feature::Voice).minus(Feature::Voice);
+        assert_eq!(spec.value(Feature::Voice), Some(false));
+        assert_eq!(spec.value(Feature::Nasal), None);
+        assert_eq!(spec, FeatureSpec::new().minus(Feature::Voice));
+    }
+}
@@ -0,0 +1,83 @@
+//! Phonemic /slashes/ and phonetic [brackets] around transcriptions.
+
+use crate::Ipa;
+
+/// Kind of transcription, told by the delimiters enclosing it.
+///
+/// The parser strips one pair of delimiters around the whole input and records them, and writing
+/// the transcription puts them back, so dictionary entries such as "/kæt/" round-trip.
+///
+/// # Examples
+///
+/// ```
+/// use ipa_sounds::{Ipa, Notation};
+///
+/// let ipa = Ipa::try_from("[ˈkæt̚]").unwrap();
+/// assert_eq!(ipa.notation(), Some(Notation::Phonetic));
+/// assert_eq!(ipa.to_string(), "[ˈkæt̚]");
+/// assert_eq!(Ipa::try_from("/kæt/").unwrap().notation(), Some(Notation::Phonemic));
+/// assert_eq!(Ipa::try_from("kæt").unwrap().notation(), None);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+pub enum Notation {
+    /// Broad transcription of phonemes between slashes, e.g. "/kæt/".
+    Phonemic,
+    /// Narrow transcription of phones between square brackets, e.g. "[kʰæt̚]".
+    Phonetic
+}
+
+impl Notation {
+    /// Opening and closing delimiters.
+    pub const fn delimiters(self) -> (char, char) {
+        match self {
+            Notation::Phonemic => ('/', '/'),
+            Notation::Phonetic => ('[', ']')
+        }
+    }
+}
+
+/// Splits the enclosing delimiters off the text, if it has a matching pair.
+pub(crate) fn strip(text: &str) -> (Option<Notation>, &str) {
+    [Notation::Phonemic, Notation::Phonetic].into_iter()
+        .find_map(|notation| {
+            let (open, close) = notation.delimiters();
+            let inner = text.strip_prefix(open)?.strip_suffix(close)?;
+            Some((Some(notation), inner))
+        })
+        .unwrap_or((None, text))
+}
+
+impl Ipa {
+    /// Notation recorded from the delimiters of the parsed text, `None` without them.
+    pub fn notation(&self) -> Option<Notation> {
+        self.1
+    }
+
+    /// Sets the notation written around the sounds, or removes it with `None`.
+    pub fn set_notation(&mut self, notation: Option<Notation>) {
+        self.1 = notation;
+    }
+}
+
+#[cfg(test)]
+mod notation_tests {
+    use super::*;
+    use crate::{Error, IpaParser};
+
+    #[test]
+    fn test_delimiters() {
+        let ipa = Ipa::try_from("/ˈnʲæn/").unwrap();
+        assert_eq!(ipa.notation(), Some(Notation::Phonemic));
+        assert_eq!(Ipa::try_from(ipa.to_string()).unwrap().notation(), ipa.notation());
+        assert_eq!(ipa, Ipa::try_from("ˈnʲæn").unwrap());
+        assert_eq!(IpaParser::new().ignore_notation(true).parse("[ma]"), Ipa::try_from("ma"));
+        assert_eq!(Ipa::try_from("/ma]"), Err(Error::NotYetImplemented('/')));
+        assert_eq!(Ipa::try_from("/ma/ /pa/"), Err(Error::NotYetImplemented('/')));
+        let mut ipa = Ipa::try_from("ma").unwrap();
+        ipa.set_notation(Some(Notation::Phonetic));
+        assert_eq!(ipa.to_string(), "[ma]");
+        assert_eq!(format!("{:#}", ipa), format!("[{:#}]", Ipa::try_from("ma").unwrap()));
+    }
+}
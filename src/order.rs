@@ -0,0 +1,126 @@
+//! Ordering of phonemes and sounds by their position on the IPA chart.
+//!
+//! The order is part of the stable API: it does not depend on declaration order, which only grows
+//! by appending, so sorted collections and map keys keep their order between versions.
+
+use core::cmp::Ordering;
+use crate::{Consonants, Sound, Vowels};
+
+/// Orders consonants by place of articulation (chart columns), then manner (rows), voiceless before voiced.
+///
+/// # Examples
+///
+/// ```
+/// use ipa_sounds::Consonants;
+///
+/// assert!(Consonants::VoicelessBilabialPlosive < Consonants::VoicedBilabialNasal);
+/// assert!(Consonants::VoicedBilabialNasal < Consonants::VoicelessAlveolarPlosive);
+/// ```
+impl Ord for Consonants {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let key = |consonant: &Self| (consonant.place(), consonant.manner(), consonant.voicing());
+        key(self).cmp(&key(other))
+    }
+}
+
+impl PartialOrd for Consonants {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Orders vowels by height from close to open, then backness from front to back, unrounded before rounded.
+///
+/// # Examples
+///
+/// ```
+/// use ipa_sounds::Vowels;
+///
+/// assert!(Vowels::CloseFrontUnrounded < Vowels::CloseFrontRounded);
+/// assert!(Vowels::CloseBackRounded < Vowels::CloseMidFrontUnrounded);
+/// ```
+impl Ord for Vowels {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let key = |vowel: &Self| (vowel.height(), vowel.backness(), vowel.is_rounded());
+        key(self).cmp(&key(other))
+    }
+}
+
+impl PartialOrd for Vowels {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Orders consonants before vowels, each by phoneme and then plain before long, palatalized, reduced and
+/// released forms, followed by the space, the primary and secondary stress marks and tones by their levels.
+///
+/// # Examples
+///
+/// ```
+/// let mut ipa: Vec<_> = ipa_sounds::Ipa::try_from("ˈaː mʲa ˈpi").unwrap().into_iter().collect();
+/// ipa.sort();
+/// assert_eq!(ipa.into_iter().collect::<ipa_sounds::Ipa>().to_string(), "pmʲiaaː  ˈˈ");
+/// ```
+impl Ord for Sound {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.rank().cmp(&other.rank()).then_with(|| match (self, other) {
+            (
+                Sound::Consonant { phoneme, is_long, is_palatalized, is_reduced, release },
+                Sound::Consonant {
+                    phoneme: other,
+                    is_long: other_long,
+                    is_palatalized: other_palatalized,
+                    is_reduced: other_reduced,
+                    release: other_release
+                }
+            ) => (phoneme, is_long, is_palatalized, is_reduced, release)
+                .cmp(&(other, other_long, other_palatalized, other_reduced, other_release)),
+            (
+                Sound::Vowel { phoneme, is_long, is_reduced },
+                Sound::Vowel { phoneme: other, is_long: other_long, is_reduced: other_reduced }
+            ) => (phoneme, is_long, is_reduced).cmp(&(other, other_long, other_reduced)),
+            (Sound::Stress { is_primary }, Sound::Stress { is_primary: other }) => other.cmp(is_primary),
+            (Sound::Tone { contour }, Sound::Tone { contour: other }) => contour.cmp(other),
+            _ => Ordering::Equal
+        })
+    }
+}
+
+impl PartialOrd for Sound {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Sound {
+    /// Position of the kind of sound in the order.
+    const fn rank(&self) -> u8 {
+        match self {
+            Sound::Consonant { .. } => 0,
+            Sound::Vowel { .. } => 1,
+            Sound::Space => 2,
+            Sound::Stress { .. } => 3,
+            Sound::Tone { .. } => 4
+        }
+    }
+}
+
+#[cfg(test)]
+mod order_tests {
+    use super::*;
+    use crate::corpus;
+
+    #[test]
+    fn test_total_order() {
+        assert!(Vowels::ALL.iter().all(|vowel| Vowels::ALL.iter().filter(|other| vowel.cmp(other).is_eq()).count() == 1));
+        assert!(Consonants::ALL.iter().all(|consonant| {
+            Consonants::ALL.iter().filter(|other| consonant.cmp(other).is_eq()).count() == 1
+        }));
+        let mut sounds: alloc::vec::Vec<_> = corpus::sounds().collect();
+        sounds.sort();
+        assert!(sounds.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(Sound::Space < Sound::Stress { is_primary: true });
+        assert!(Sound::Stress { is_primary: true } < Sound::Stress { is_primary: false });
+    }
+}
@@ -0,0 +1,44 @@
+//! Parsing large corpora of transcriptions on all cores.
+
+use rayon::prelude::*;
+use crate::{Error, Ipa, IpaParser};
+
+impl IpaParser {
+    /// Parses every line in parallel, returning the results in the order of the lines.
+    pub fn parse_lines_parallel<'a>(&self, lines: impl Iterator<Item = &'a str>) -> Vec<Result<Ipa, Error>> {
+        let lines: Vec<&str> = lines.collect();
+        lines.into_par_iter().map(|line| self.parse(line)).collect()
+    }
+}
+
+/// Parses every line in parallel with the default options, e.g. the pronunciations of a dictionary.
+///
+/// # Examples
+///
+/// ```
+/// use ipa_sounds::{parse_lines_parallel, Ipa};
+///
+/// let results = parse_lines_parallel("nʲæ\nþ\nˈmama".lines());
+/// assert_eq!(results[0], Ipa::try_from("nʲæ"));
+/// assert!(results[1].is_err());
+/// assert_eq!(results.len(), 3);
+/// ```
+pub fn parse_lines_parallel<'a>(lines: impl Iterator<Item = &'a str>) -> Vec<Result<Ipa, Error>> {
+    IpaParser::new().parse_lines_parallel(lines)
+}
+
+#[cfg(test)]
+mod parallel_tests {
+    use super::*;
+    use crate::corpus;
+
+    #[test]
+    fn test_order() {
+        let corpus: Vec<String> = corpus::strings().chain(["þ".into()]).collect();
+        let lines: Vec<&str> = corpus.iter().map(String::as_str).cycle().take(10_000).collect();
+        let sequential: Vec<_> = lines.iter().map(|&line| Ipa::try_from(line)).collect();
+        assert_eq!(parse_lines_parallel(lines.iter().copied()), sequential);
+        let parser = IpaParser::new().lossy(true);
+        assert!(parser.parse_lines_parallel(["þa"].into_iter()).iter().all(Result::is_ok));
+    }
+}
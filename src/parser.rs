@@ -0,0 +1,516 @@
+//! Configurable parsing of IPA strings.
+
+use alloc::vec::Vec;
+use crate::{notation, tone::{TONE_LETTERS, TONE_NUMBERS}, Consonants, Error, Ipa, Notation, Release, Sound, Tone, ToneCategories, Vowels, SUPERSCRIPTS};
+
+/// Meaning of a single symbol.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Symbol {
+    Unknown,
+    Space,
+    Palatalization,
+    Length,
+    Stress { is_primary: bool },
+    Tone { level: u8 },
+    Vowel { phoneme: Vowels, is_reduced: bool },
+    Consonant { phoneme: Consonants, is_reduced: bool }
+}
+
+const SYMBOL_COUNT: usize = 5 + 2 * TONE_LETTERS.len() + Vowels::ALL.len() + Consonants::ALL.len() + SUPERSCRIPTS.len();
+
+/// Every known symbol, sorted by code point.
+static SYMBOLS: [(char, Symbol); SYMBOL_COUNT] = known_symbols();
+
+/// Code points below this are looked up directly; it covers ASCII, Latin-1, IPA Extensions
+/// and Spacing Modifier Letters, so only superscripts, Greek letters, 'ⱱ' and the tone numbers
+/// '⁴' and '⁵' need a search.
+const DIRECT_LIMIT: usize = 0x300;
+
+/// Meaning of every symbol below [`DIRECT_LIMIT`], indexed by code point.
+static DIRECT_SYMBOLS: [Symbol; DIRECT_LIMIT] = direct_symbols();
+
+const fn known_symbols() -> [(char, Symbol); SYMBOL_COUNT] {
+    let mut symbols = [(' ', Symbol::Space); SYMBOL_COUNT];
+    symbols[1] = ('ʲ', Symbol::Palatalization);
+    symbols[2] = ('ː', Symbol::Length);
+    symbols[3] = ('ˈ', Symbol::Stress { is_primary: true });
+    symbols[4] = ('ˌ', Symbol::Stress { is_primary: false });
+    let mut len = 5;
+    let mut i = 0;
+    while i < TONE_LETTERS.len() {
+        let level = i as u8 + 1;
+        symbols[len] = (TONE_LETTERS[i], Symbol::Tone { level });
+        symbols[len + 1] = (TONE_NUMBERS[i], Symbol::Tone { level });
+        len += 2;
+        i += 1;
+    }
+    i = 0;
+    while i < Vowels::ALL.len() {
+        let phoneme = Vowels::ALL[i];
+        symbols[len] = (phoneme.value(), Symbol::Vowel { phoneme, is_reduced: false });
+        len += 1;
+        i += 1;
+    }
+    i = 0;
+    while i < Consonants::ALL.len() {
+        let phoneme = Consonants::ALL[i];
+        symbols[len] = (phoneme.value(), Symbol::Consonant { phoneme, is_reduced: false });
+        len += 1;
+        i += 1;
+    }
+    let base_len = len;
+    i = 0;
+    while i < SUPERSCRIPTS.len() {
+        let (base, superscript) = SUPERSCRIPTS[i];
+        let mut j = 0;
+        while symbols[j].0 != base {
+            j += 1;
+            assert!(j < base_len, "superscript of an unknown symbol");
+        }
+        symbols[len] = (superscript, match symbols[j].1 {
+            Symbol::Vowel { phoneme, .. } => Symbol::Vowel { phoneme, is_reduced: true },
+            Symbol::Consonant { phoneme, .. } => Symbol::Consonant { phoneme, is_reduced: true },
+            _ => panic!("superscript of a modifier")
+        });
+        len += 1;
+        i += 1;
+    }
+    // Insertion sort, since sorting is not available in const contexts.
+    i = 1;
+    while i < SYMBOL_COUNT {
+        let mut j = i;
+        while j > 0 && symbols[j - 1].0 as u32 > symbols[j].0 as u32 {
+            let swapped = symbols[j - 1];
+            symbols[j - 1] = symbols[j];
+            symbols[j] = swapped;
+            j -= 1;
+        }
+        i += 1;
+    }
+    symbols
+}
+
+const fn direct_symbols() -> [Symbol; DIRECT_LIMIT] {
+    let mut direct = [Symbol::Unknown; DIRECT_LIMIT];
+    let mut i = 0;
+    while i < SYMBOL_COUNT && (SYMBOLS[i].0 as usize) < DIRECT_LIMIT {
+        direct[SYMBOLS[i].0 as usize] = SYMBOLS[i].1;
+        i += 1;
+    }
+    direct
+}
+
+/// Looks up the meaning of a symbol.
+#[inline(always)]
+fn classify(symbol: char) -> Symbol {
+    if let Some(&known) = DIRECT_SYMBOLS.get(symbol as usize) {
+        known
+    } else {
+        SYMBOLS.binary_search_by_key(&symbol, |&(known, _)| known)
+            .map_or(Symbol::Unknown, |i| SYMBOLS[i].1)
+    }
+}
+
+/// Parser of IPA strings with configurable options.
+///
+/// `Ipa::try_from(&str)` is a shortcut for parsing with the default options.
+///
+/// # Examples
+///
+/// ```
+/// use ipa_sounds::IpaParser;
+///
+/// let parser = IpaParser::new()
+///     .ascii_substitutes(true)
+///     .doubled_as_long(true);
+/// assert_eq!(format!("{}", parser.parse("n'aa pa:").unwrap()), "nʲaː paː");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IpaParser {
+    lossy: bool,
+    ascii_substitutes: bool,
+    doubled_as_long: bool,
+    ignore_notation: bool,
+    allowed: Option<Vec<char>>,
+    tone_categories: Option<ToneCategories>
+}
+
+impl IpaParser {
+    /// Creates a strict parser accepting every supported symbol.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Skips unrecognized symbols and drops palatalization of vowels instead of failing.
+    pub fn lossy(mut self, lossy: bool) -> Self {
+        self.lossy = lossy;
+        self
+    }
+
+    /// Accepts ASCII substitutes: ':' for 'ː', '\'' for 'ʲ' and digits from 1 to 5 for tone levels.
+    pub fn ascii_substitutes(mut self, ascii_substitutes: bool) -> Self {
+        self.ascii_substitutes = ascii_substitutes;
+        self
+    }
+
+    /// Treats two identical adjacent sounds as one long sound ("nn" as "nː").
+    pub fn doubled_as_long(mut self, doubled_as_long: bool) -> Self {
+        self.doubled_as_long = doubled_as_long;
+        self
+    }
+
+    /// Strips enclosing `/slashes/` or `[brackets]` without recording the [`Notation`],
+    /// so "/ma/" is written back as "ma".
+    pub fn ignore_notation(mut self, ignore_notation: bool) -> Self {
+        self.ignore_notation = ignore_notation;
+        self
+    }
+
+    /// Reads ASCII digits as the tone categories of the table, e.g. "ma3" as "ma˨˩˦" with
+    /// [`ToneCategories::mandarin`], or as Chao levels with ASCII substitutes if `None`.
+    ///
+    /// Digits missing from the table are rejected, also with ASCII substitutes.
+    pub fn tone_categories(mut self, tone_categories: Option<ToneCategories>) -> Self {
+        self.tone_categories = tone_categories;
+        self
+    }
+
+    /// Rejects every symbol (including modifiers, but not spaces) that is not in the given set.
+    ///
+    /// Symbols are checked after ASCII substitution.
+    pub fn allowed_symbols(mut self, symbols: impl IntoIterator<Item = char>) -> Self {
+        self.allowed = Some(symbols.into_iter().collect());
+        self
+    }
+
+    /// Constructs a sequence of IPA sounds from &str.
+    ///
+    /// One pair of `/slashes/` or `[brackets]` around the whole input is stripped and recorded as the
+    /// [`Ipa::notation`]. In lossy mode this never fails.
+    pub fn parse(&self, ipa: &str) -> Result<Ipa, Error> {
+        let (notation, ipa) = self.notation(ipa);
+        let mut sounds = Sounds::new(self, ipa, self.lossy, None);
+        if self.doubled_as_long {
+            return sounds.collect::<Result<Ipa, Error>>().map(|ipa| Ipa(ipa.0, notation));
+        }
+        // Without doubling there is no lookahead, so the lexer is driven directly, which lets it be
+        // inlined into this loop.
+        let mut ipa = Vec::new();
+        while let Some(sound) = sounds.lex() {
+            ipa.push(sound?);
+        }
+        Ok(Ipa(ipa, notation))
+    }
+
+    /// Constructs a sequence of IPA sounds from &str, recovering from errors as in lossy mode
+    /// regardless of the option, and returns all encountered errors along with the result.
+    pub fn parse_with_errors(&self, ipa: &str) -> (Ipa, Vec<Error>) {
+        let (notation, ipa) = self.notation(ipa);
+        let mut sounds = Sounds::new(self, ipa, true, Some(Vec::new()));
+        let ipa = Ipa(sounds.by_ref().flatten().collect(), notation);
+        (ipa, sounds.errors.unwrap_or_default())
+    }
+
+    /// Lazily parses sounds one by one, skipping enclosing delimiters.
+    ///
+    /// In lossy mode errors are skipped instead of being yielded.
+    pub fn sounds<'a>(&'a self, ipa: &'a str) -> Sounds<'a> {
+        Sounds::new(self, notation::strip(ipa).1, self.lossy, None)
+    }
+
+    /// Splits off the enclosing delimiters, forgetting the notation if it is ignored.
+    fn notation<'a>(&self, ipa: &'a str) -> (Option<Notation>, &'a str) {
+        let (notation, ipa) = notation::strip(ipa);
+        (notation.filter(|_| !self.ignore_notation), ipa)
+    }
+
+    #[inline]
+    fn substitute(&self, symbol: char) -> char {
+        match symbol {
+            ':' if self.ascii_substitutes => 'ː',
+            '\'' if self.ascii_substitutes => 'ʲ',
+            '1'..='5' if self.ascii_substitutes && self.tone_categories.is_none() => TONE_LETTERS[symbol as usize - '1' as usize],
+            symbol => symbol
+        }
+    }
+
+    #[inline]
+    fn tone_category(&self, symbol: char) -> Option<Tone> {
+        self.tone_categories.as_ref().filter(|_| symbol.is_ascii_digit())?.contour(symbol)
+    }
+
+    #[inline]
+    fn is_allowed(&self, symbol: char) -> bool {
+        self.allowed.as_ref().is_none_or(|allowed| allowed.contains(&symbol))
+    }
+}
+
+/// Parser with the default options.
+static DEFAULT_PARSER: IpaParser = IpaParser {
+    lossy: false,
+    ascii_substitutes: false,
+    doubled_as_long: false,
+    ignore_notation: false,
+    allowed: None,
+    tone_categories: None
+};
+
+/// Lazily parses sounds from &str with the default options, without collecting the whole input first.
+///
+/// # Examples
+///
+/// ```
+/// use ipa_sounds::{iter_sounds, Error};
+///
+/// let mut sounds = iter_sounds("nʲæ æʲ");
+/// assert_eq!(sounds.by_ref().take(3).filter(Result::is_ok).count(), 3);
+/// assert_eq!(sounds.next(), Some(Err(Error::PalatalizedVowel('æ'))));
+/// ```
+pub fn iter_sounds(ipa: &str) -> Sounds<'_> {
+    DEFAULT_PARSER.sounds(ipa)
+}
+
+/// Lazy iterator over sounds parsed from &str, created by [`iter_sounds`] or [`IpaParser::sounds`].
+///
+/// Symbols are decoded directly from UTF-8 bytes and looked up in a table.
+#[derive(Clone, Debug)]
+pub struct Sounds<'a> {
+    parser: &'a IpaParser,
+    /// Bytes of the remaining input, always starting at a char boundary.
+    bytes: &'a [u8],
+    recover: bool,
+    /// Errors recovered from, if they are recorded.
+    errors: Option<Vec<Error>>,
+    lookahead: Option<Result<Sound, Error>>
+}
+
+impl<'a> Sounds<'a> {
+    fn new(parser: &'a IpaParser, ipa: &'a str, recover: bool, errors: Option<Vec<Error>>) -> Self {
+        Sounds {
+            parser,
+            bytes: ipa.as_bytes(),
+            recover,
+            errors,
+            lookahead: None
+        }
+    }
+
+    /// Decodes and consumes the next symbol.
+    #[inline(always)]
+    fn next_symbol(&mut self) -> Option<char> {
+        let (&first, rest) = self.bytes.split_first()?;
+        let continuation = |i: usize| (rest[i] & 0x3F) as u32;
+        let (code, len) = match first {
+            0x00..0x80 => (first as u32, 1),
+            0x80..0xE0 => (((first & 0x1F) as u32) << 6 | continuation(0), 2),
+            0xE0..0xF0 => (((first & 0x0F) as u32) << 12 | continuation(0) << 6 | continuation(1), 3),
+            _ => (((first & 0x07) as u32) << 18 | continuation(0) << 12 | continuation(1) << 6 | continuation(2), 4)
+        };
+        self.bytes = &self.bytes[len..];
+        char::from_u32(code)
+    }
+
+    /// Consumes the modifier (or its ASCII substitute) if it is the next symbol and it is allowed.
+    ///
+    /// Compares bytes instead of decoding, since most sounds are not followed by a modifier.
+    #[inline(always)]
+    fn modifier(&mut self, modifier: char, substitute: Option<u8>) -> bool {
+        let mut encoded = [0; 4];
+        let len = if self.bytes.starts_with(modifier.encode_utf8(&mut encoded).as_bytes()) {
+            modifier.len_utf8()
+        } else if self.parser.ascii_substitutes && substitute.is_some() && self.bytes.first() == substitute.as_ref() {
+            1
+        } else {
+            return false;
+        };
+        if !self.parser.is_allowed(modifier) {
+            return false;
+        }
+        self.bytes = &self.bytes[len..];
+        true
+    }
+
+    /// Consumes the next symbol if it is an allowed tone level that lengthens the contour.
+    fn longer_tone(&mut self, contour: Tone) -> Option<Tone> {
+        let bytes = self.bytes;
+        let symbol = self.parser.substitute(self.next_symbol()?);
+        let longer = match classify(symbol) {
+            Symbol::Tone { level } if self.parser.is_allowed(symbol) => contour.then(level),
+            _ => None
+        };
+        if longer.is_none() {
+            self.bytes = bytes;
+        }
+        longer
+    }
+
+    /// Records the error if errors are recovered from, otherwise returns it.
+    fn fail(&mut self, error: Error) -> Option<Error> {
+        if !self.recover {
+            return Some(error);
+        }
+        if let Some(errors) = &mut self.errors {
+            errors.push(error);
+        }
+        None
+    }
+
+    /// Parses the next sound, recovering from errors when needed.
+    #[inline(always)]
+    fn lex(&mut self) -> Option<Result<Sound, Error>> {
+        loop {
+            let symbol = self.parser.substitute(self.next_symbol()?);
+            let class = if symbol == ' ' || self.parser.is_allowed(symbol) {
+                match self.parser.tone_category(symbol) {
+                    Some(contour) => return Some(Ok(Sound::Tone { contour })),
+                    None => classify(symbol)
+                }
+            } else {
+                match self.fail(Error::NotAllowed(symbol)) {
+                    Some(error) => return Some(Err(error)),
+                    None => continue
+                }
+            };
+            return Some(match class {
+                Symbol::Space => Ok(Sound::Space),
+
+                Symbol::Stress { is_primary } => Ok(Sound::Stress { is_primary }),
+
+                Symbol::Tone { level } => {
+                    let mut contour = Tone::new(&[level]).unwrap();
+                    while let Some(longer) = self.longer_tone(contour) {
+                        contour = longer;
+                    }
+                    Ok(Sound::Tone { contour })
+                },
+
+                Symbol::Palatalization | Symbol::Length => continue,
+
+                Symbol::Unknown => match self.fail(Error::NotYetImplemented(symbol)) {
+                    Some(error) => Err(error),
+                    None => continue
+                },
+
+                Symbol::Consonant { phoneme, is_reduced } => {
+                    let release = Release::ALL.into_iter()
+                        .find(|release| release.is_allowed(phoneme) && self.modifier(release.symbol(), None));
+                    let is_palatalized = self.modifier('ʲ', Some(b'\''));
+                    Ok(Sound::Consonant {
+                        phoneme,
+                        is_long: self.modifier('ː', Some(b':')),
+                        is_palatalized,
+                        is_reduced,
+                        release
+                    })
+                },
+
+                Symbol::Vowel { phoneme, is_reduced } => {
+                    let is_palatalized = self.modifier('ʲ', Some(b'\''));
+                    let sound = Sound::Vowel {
+                        phoneme,
+                        is_long: self.modifier('ː', Some(b':')),
+                        is_reduced
+                    };
+                    // Lossy parsing keeps the vowel without palatalization.
+                    match is_palatalized.then(|| self.fail(Error::PalatalizedVowel(symbol))).flatten() {
+                        Some(error) => Err(error),
+                        None => Ok(sound)
+                    }
+                }
+            });
+        }
+    }
+}
+
+impl Iterator for Sounds<'_> {
+    type Item = Result<Sound, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.parser.doubled_as_long {
+            return self.lex();
+        }
+        let first = self.lookahead.take().or_else(|| self.lex())?;
+        let can_double = matches!(first,
+            Ok(Sound::Vowel { is_long: false, .. } | Sound::Consonant { is_long: false, .. })
+        );
+        if !can_double {
+            return Some(first);
+        }
+        match self.lex() {
+            Some(Ok(mut sound)) if Ok(sound) == first => {
+                if let Sound::Vowel { is_long, .. } | Sound::Consonant { is_long, .. } = &mut sound {
+                    *is_long = true;
+                }
+                Some(Ok(sound))
+            },
+            second => {
+                self.lookahead = second;
+                Some(first)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod parser_tests {
+    use super::*;
+
+    #[test]
+    fn test_ascii_substitutes() {
+        assert_eq!(
+            IpaParser::new().ascii_substitutes(true).parse("n'a:"),
+            Ipa::try_from("nʲaː")
+        );
+        assert_eq!(
+            IpaParser::new().parse("n'"),
+            Err(Error::NotYetImplemented('\''))
+        );
+    }
+
+    #[test]
+    fn test_doubled_as_long() {
+        assert_eq!(
+            IpaParser::new().doubled_as_long(true).parse("nnʲaa mm"),
+            Ipa::try_from("nnʲaː mː")
+        );
+    }
+
+    #[test]
+    fn test_symbol_table() {
+        assert!(SYMBOLS.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        assert_eq!(classify('ᵊ'), Symbol::Vowel { phoneme: Vowels::MidCentral, is_reduced: true });
+        assert_eq!(classify('p'), Symbol::Consonant {
+            phoneme: Consonants::VoicelessBilabialPlosive,
+            is_reduced: false
+        });
+        assert_eq!(classify('þ'), Symbol::Unknown);
+    }
+
+    #[test]
+    fn test_lazy() {
+        let ipa = "nʲæ".repeat(1000) + "þ";
+        let mut sounds = iter_sounds(&ipa);
+        assert_eq!(sounds.next(), Some(Ok(Ipa::try_from("nʲ").unwrap()[0])));
+        assert_eq!(sounds.find(Result::is_err), Some(Err(Error::NotYetImplemented('þ'))));
+        assert_eq!(sounds.next(), None);
+    }
+
+    #[test]
+    fn test_tones() {
+        let tone = |levels: &[u8]| Sound::Tone { contour: Tone::new(levels).unwrap() };
+        assert_eq!(IpaParser::new().parse("˥˩³⁵").unwrap().as_sounds(), [tone(&[5, 1, 3]), tone(&[5])]);
+        assert_eq!(IpaParser::new().parse("a5"), Err(Error::NotYetImplemented('5')));
+        assert_eq!(IpaParser::new().ascii_substitutes(true).parse("a51").unwrap()[1], tone(&[5, 1]));
+    }
+
+    #[test]
+    fn test_allowed_symbols() {
+        let parser = IpaParser::new().allowed_symbols("nmaː".chars());
+        assert_eq!(parser.parse("naː ma"), Ipa::try_from("naː ma"));
+        assert_eq!(parser.parse("nʲa"), Err(Error::NotAllowed('ʲ')));
+        assert_eq!(
+            parser.lossy(true).parse("pa"),
+            Ipa::try_from("a")
+        );
+    }
+}
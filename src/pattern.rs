@@ -0,0 +1,257 @@
+//! Patterns over sequences of sounds, matched by kind and features like regexes over chars.
+
+use alloc::vec::Vec;
+use core::{fmt, ops::Range, str::FromStr};
+use crate::{Error, FeatureSpec, Ipa, IpaSlice, Sound};
+
+/// What kind of sound an element of a pattern matches.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum Kind {
+    Any,
+    Vowel,
+    Consonant,
+    Space,
+    Sound(Sound),
+    Class(FeatureSpec)
+}
+
+/// One sound of a pattern, with modifiers required to be present.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Element {
+    kind: Kind,
+    is_long: bool,
+    is_palatalized: bool,
+    is_reduced: bool
+}
+
+impl Element {
+    fn matches(&self, sound: &Sound) -> bool {
+        let kind = match self.kind {
+            Kind::Any => !matches!(sound, Sound::Stress { .. }),
+            Kind::Vowel => matches!(sound, Sound::Vowel { .. }),
+            Kind::Consonant => matches!(sound, Sound::Consonant { .. }),
+            Kind::Space => matches!(sound, Sound::Space),
+            Kind::Sound(expected) => expected == *sound,
+            Kind::Class(spec) => sound.matches(&spec)
+        };
+        let (is_long, is_palatalized, is_reduced) = match *sound {
+            Sound::Vowel { is_long, is_reduced, .. } => (is_long, false, is_reduced),
+            Sound::Consonant { is_long, is_palatalized, is_reduced, .. } => (is_long, is_palatalized, is_reduced),
+            Sound::Space | Sound::Stress { .. } | Sound::Tone { .. } => (false, false, false)
+        };
+        kind && (is_long || !self.is_long) && (is_palatalized || !self.is_palatalized) && (is_reduced || !self.is_reduced)
+    }
+}
+
+/// Sequence of sound patterns, built with methods or parsed from text.
+///
+/// The text syntax has elements separated by whitespace: "C" is any consonant, "V" any vowel,
+/// "." any sound but a stress mark, "#" a space, and anything else is parsed as IPA and matches those
+/// exact sounds. A "ː" or "ʲ" after "C", "V" or "." (attached or as a separate element) requires the
+/// sound to be long or palatalized; with nothing before it, parsing fails with [`Error::NotAllowed`].
+///
+/// # Examples
+///
+/// ```
+/// use ipa_sounds::{Ipa, Pattern};
+///
+/// let pattern: Pattern = "C V ː".parse().unwrap();
+/// assert_eq!(pattern, Pattern::new().consonant().vowel().long());
+/// let ipa = Ipa::try_from("pamaːnʲaː").unwrap();
+/// assert_eq!(pattern.find(&ipa), Some(2..4));
+/// assert_eq!(pattern.find_all(&ipa).collect::<Vec<_>>(), [2..4, 4..6]);
+/// assert_eq!(pattern.replace(&ipa, &Ipa::try_from("ə").unwrap()).to_string(), "paəə");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Pattern {
+    elements: Vec<Element>
+}
+
+impl Pattern {
+    /// Creates an empty pattern, matching an empty sequence everywhere.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn element(mut self, kind: Kind) -> Self {
+        self.elements.push(Element { kind, is_long: false, is_palatalized: false, is_reduced: false });
+        self
+    }
+
+    fn modify(mut self, modify: impl FnOnce(&mut Element)) -> Self {
+        if let Some(element) = self.elements.last_mut() {
+            modify(element);
+        }
+        self
+    }
+
+    /// Appends any sound, except a stress mark.
+    pub fn any(self) -> Self {
+        self.element(Kind::Any)
+    }
+
+    /// Appends any vowel.
+    pub fn vowel(self) -> Self {
+        self.element(Kind::Vowel)
+    }
+
+    /// Appends any consonant.
+    pub fn consonant(self) -> Self {
+        self.element(Kind::Consonant)
+    }
+
+    /// Appends a space.
+    pub fn space(self) -> Self {
+        self.element(Kind::Space)
+    }
+
+    /// Appends this exact sound.
+    pub fn sound(self, sound: Sound) -> Self {
+        self.element(Kind::Sound(sound))
+    }
+
+    /// Appends any sound matching the feature specification.
+    pub fn class(self, spec: FeatureSpec) -> Self {
+        self.element(Kind::Class(spec))
+    }
+
+    /// Requires the last sound to be long; does nothing on an empty pattern.
+    pub fn long(self) -> Self {
+        self.modify(|element| element.is_long = true)
+    }
+
+    /// Requires the last sound to be palatalized; does nothing on an empty pattern.
+    pub fn palatalized(self) -> Self {
+        self.modify(|element| element.is_palatalized = true)
+    }
+
+    /// Requires the last sound to be reduced; does nothing on an empty pattern.
+    pub fn reduced(self) -> Self {
+        self.modify(|element| element.is_reduced = true)
+    }
+
+    /// Number of sounds a match spans.
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    /// Whether the pattern has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    /// Whether the sounds starting at the index match.
+    pub fn matches_at(&self, sounds: &[Sound], index: usize) -> bool {
+        sounds.get(index..index + self.len())
+            .is_some_and(|window| self.elements.iter().zip(window).all(|(element, sound)| element.matches(sound)))
+    }
+
+    /// Range of the first match.
+    pub fn find(&self, ipa: &IpaSlice) -> Option<Range<usize>> {
+        self.find_all(ipa).next()
+    }
+
+    /// Iterator over the ranges of non-overlapping matches, from left to right.
+    pub fn find_all<'a>(&'a self, ipa: &'a IpaSlice) -> impl Iterator<Item = Range<usize>> + 'a {
+        let mut start = 0;
+        core::iter::from_fn(move || {
+            let index = (start..=ipa.len()).find(|&i| self.matches_at(ipa, i))?;
+            start = index + self.len().max(1);
+            Some(index..index + self.len())
+        })
+    }
+
+    /// Replaces every non-overlapping match with the replacement.
+    pub fn replace(&self, ipa: &IpaSlice, replacement: &IpaSlice) -> Ipa {
+        let mut result = Vec::with_capacity(ipa.len());
+        let mut end = 0;
+        for range in self.find_all(ipa) {
+            result.extend_from_slice(&ipa[end..range.start]);
+            result.extend_from_slice(replacement);
+            end = range.end;
+        }
+        result.extend_from_slice(&ipa[end.min(ipa.len())..]);
+        result.into_iter().collect()
+    }
+}
+
+impl FromStr for Pattern {
+    type Err = Error;
+
+    fn from_str(pattern: &str) -> Result<Self, Self::Err> {
+        pattern.split_whitespace().try_fold(Pattern::new(), |pattern, token| {
+            let base = token.trim_end_matches(['ː', 'ʲ']);
+            let pattern = match base {
+                "" => pattern,
+                "C" => pattern.consonant(),
+                "V" => pattern.vowel(),
+                "." => pattern.any(),
+                "#" => pattern.space(),
+                _ => return Ipa::try_from(token).map(|ipa| ipa.iter().fold(pattern, |pattern, &sound| pattern.sound(sound)))
+            };
+            token[base.len()..].chars().try_fold(pattern, |pattern, modifier| {
+                let modifiable = pattern.elements.last()
+                    .is_some_and(|element| !matches!(element.kind, Kind::Sound(_) | Kind::Space));
+                match modifier {
+                    _ if !modifiable => Err(Error::NotAllowed(modifier)),
+                    'ː' => Ok(pattern.long()),
+                    _ => Ok(pattern.palatalized())
+                }
+            })
+        })
+    }
+}
+
+/// Writes the pattern in the text syntax; feature specifications and reduction, which the syntax lacks,
+/// are written as "[+voice]" and "(reduced)".
+impl fmt::Display for Pattern {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.elements.iter().enumerate().try_for_each(|(i, element)| {
+            if i > 0 {
+                formatter.write_str(" ")?;
+            }
+            match element.kind {
+                Kind::Any => formatter.write_str(".")?,
+                Kind::Vowel => formatter.write_str("V")?,
+                Kind::Consonant => formatter.write_str("C")?,
+                Kind::Space => formatter.write_str("#")?,
+                Kind::Sound(sound) => return fmt::Display::fmt(&sound, formatter),
+                Kind::Class(spec) => fmt::Display::fmt(&spec, formatter)?
+            }
+            [(element.is_palatalized, "ʲ"), (element.is_long, "ː"), (element.is_reduced, "(reduced)")].into_iter()
+                .filter(|(is_present, _)| *is_present)
+                .try_for_each(|(_, modifier)| formatter.write_str(modifier))
+        })
+    }
+}
+
+#[cfg(test)]
+mod pattern_tests {
+    use super::*;
+    use crate::Feature;
+
+    #[test]
+    fn test_parse() {
+        let pattern: Pattern = "Cʲ Vː # mʲa .".parse().unwrap();
+        assert_eq!(pattern.len(), 6);
+        assert_eq!(pattern.to_string(), "Cʲ Vː # mʲ a .");
+        assert_eq!(pattern.to_string().parse(), Ok(pattern));
+        assert_eq!("ː C".parse::<Pattern>(), Err(Error::NotAllowed('ː')));
+        assert_eq!("# ʲ".parse::<Pattern>(), Err(Error::NotAllowed('ʲ')));
+        assert!("C ʘ".parse::<Pattern>().is_err());
+    }
+
+    #[test]
+    fn test_find() {
+        let ipa = Ipa::try_from("ˈbapʲ ma").unwrap();
+        let stop = Pattern::new().class(FeatureSpec::new().minus(Feature::Continuant).minus(Feature::Sonorant));
+        assert_eq!(stop.find_all(&ipa).collect::<Vec<_>>(), [1..2, 3..4]);
+        assert_eq!(stop.clone().palatalized().find(&ipa), Some(3..4));
+        assert_eq!(Pattern::new().vowel().space().find(&ipa), None);
+        assert_eq!(Pattern::new().find_all(&ipa[..2]).count(), 3);
+        assert_eq!(Pattern::new().replace(&ipa[1..3], &ipa[..1]).to_string(), "ˈbˈaˈ");
+    }
+}
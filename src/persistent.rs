@@ -0,0 +1,137 @@
+//! Persistent (structurally shared) sequences of IPA sounds.
+
+use core::fmt;
+use imbl::Vector;
+use crate::{Ipa, Sound};
+
+/// Persistent sequence of IPA sounds with cheap clones and O(log n) edits.
+///
+/// Every clone shares structure with the original, which makes keeping undo history (one clone per
+/// edit) affordable.
+///
+/// # Examples
+///
+/// ```
+/// use ipa_sounds::{ImIpa, Ipa, Sound};
+///
+/// let before = ImIpa::from(Ipa::try_from("nʲæ").unwrap());
+/// let mut after = before.clone();
+/// after.push_back(Sound::Space);
+/// assert_eq!(format!("{}", before), "nʲæ");
+/// assert_eq!(format!("{}", after), "nʲæ ");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ImIpa(Vector<Sound>);
+
+impl ImIpa {
+    /// Creates an empty sequence.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of sounds.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Whether there are no sounds.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Sound at the given index.
+    pub fn get(&self, index: usize) -> Option<Sound> {
+        self.0.get(index).copied()
+    }
+
+    /// Iterator over the sounds.
+    pub fn iter(&self) -> impl Iterator<Item = Sound> + '_ {
+        self.0.iter().copied()
+    }
+
+    /// Appends a sound.
+    pub fn push_back(&mut self, sound: Sound) {
+        self.0.push_back(sound);
+    }
+
+    /// Inserts a sound at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    pub fn insert(&mut self, index: usize, sound: Sound) {
+        self.0.insert(index, sound);
+    }
+
+    /// Removes the sound at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= len`.
+    pub fn remove(&mut self, index: usize) -> Sound {
+        self.0.remove(index)
+    }
+
+    /// Replaces the sound at the given index, returning the old one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= len`.
+    pub fn set(&mut self, index: usize, sound: Sound) -> Sound {
+        self.0.set(index, sound)
+    }
+
+    /// Appends all sounds of another sequence.
+    pub fn append(&mut self, other: ImIpa) {
+        self.0.append(other.0);
+    }
+
+    /// Splits the sequence in two at the given index, returning the sounds after it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    pub fn split_off(&mut self, index: usize) -> ImIpa {
+        ImIpa(self.0.split_off(index))
+    }
+
+    /// Copies the sounds into a flat sequence.
+    pub fn to_ipa(&self) -> Ipa {
+        Ipa(self.iter().collect(), None)
+    }
+}
+
+impl From<Ipa> for ImIpa {
+    fn from(ipa: Ipa) -> Self {
+        ImIpa(ipa.0.into_iter().collect())
+    }
+}
+
+impl From<&ImIpa> for Ipa {
+    fn from(im_ipa: &ImIpa) -> Self {
+        im_ipa.to_ipa()
+    }
+}
+
+impl fmt::Display for ImIpa {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.to_ipa(), formatter)
+    }
+}
+
+#[cfg(test)]
+mod persistent_tests {
+    use super::*;
+
+    #[test]
+    fn test_edits() {
+        let original = ImIpa::from(Ipa::try_from("nʲæ nʲæn").unwrap());
+        let mut edited = original.clone();
+        edited.remove(0);
+        let tail = edited.split_off(2);
+        edited.insert(0, tail.get(0).unwrap());
+        assert_eq!(format!("{}", edited), "nʲæ ");
+        assert_eq!(format!("{}", tail), "nʲæn");
+        assert_eq!(Ipa::from(&original), Ipa::try_from("nʲæ nʲæn").unwrap());
+    }
+}
@@ -0,0 +1,235 @@
+//! Phonotactic constraints: which clusters and syllable shapes a language allows.
+
+use alloc::vec::Vec;
+use core::{fmt, str::FromStr};
+use crate::{Ipa, IpaSlice, Sound, Syllable};
+
+/// Shape of a syllable as numbers of consonants and vowels, written e.g. "CCVC".
+///
+/// # Examples
+///
+/// ```
+/// use ipa_sounds::SyllableShape;
+///
+/// let shape: SyllableShape = "CCVC".parse().unwrap();
+/// assert_eq!(shape, SyllableShape::new(2, 1, 1));
+/// assert_eq!(shape.to_string(), "CCVC");
+/// assert!("CVCV".parse::<SyllableShape>().is_err());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SyllableShape {
+    pub onset: usize,
+    pub nucleus: usize,
+    pub coda: usize
+}
+
+impl SyllableShape {
+    /// Creates a shape from the lengths of the onset, nucleus and coda.
+    pub const fn new(onset: usize, nucleus: usize, coda: usize) -> Self {
+        Self { onset, nucleus, coda }
+    }
+
+    /// Shape of a syllable.
+    pub fn of(syllable: &Syllable) -> Self {
+        Self::new(syllable.onset.len(), syllable.nucleus.len(), syllable.coda.len())
+    }
+}
+
+/// Enum for possible errors when parsing a syllable shape.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ShapeError {
+    /// Symbol other than C or V, or a V after the coda.
+    InvalidSymbol(char),
+    NoVowel
+}
+
+impl fmt::Display for ShapeError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ShapeError::InvalidSymbol(symbol) => write!(formatter, "Unexpected symbol ({}) in syllable shape", symbol),
+            ShapeError::NoVowel => formatter.write_str("Syllable shape has no V")
+        }
+    }
+}
+
+impl core::error::Error for ShapeError {}
+
+/// Parses consonants (C) followed by vowels (V) and consonants again.
+impl FromStr for SyllableShape {
+    type Err = ShapeError;
+
+    fn from_str(shape: &str) -> Result<Self, Self::Err> {
+        let count = |from: usize, symbol: char| shape[from..].chars().take_while(|&other| other == symbol).count();
+        let onset = count(0, 'C');
+        let nucleus = count(onset, 'V');
+        let coda = count(onset + nucleus, 'C');
+        if let Some(symbol) = shape[onset + nucleus + coda..].chars().next() {
+            return Err(ShapeError::InvalidSymbol(symbol));
+        }
+        if nucleus == 0 {
+            return Err(ShapeError::NoVowel);
+        }
+        Ok(Self::new(onset, nucleus, coda))
+    }
+}
+
+impl fmt::Display for SyllableShape {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        [('C', self.onset), ('V', self.nucleus), ('C', self.coda)].into_iter()
+            .try_for_each(|(symbol, count)| (0..count).try_for_each(|_| fmt::Write::write_char(formatter, symbol)))
+    }
+}
+
+/// What part of a syllable breaks the constraints.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ViolationKind {
+    Onset(Ipa),
+    Coda(Ipa),
+    Shape(SyllableShape)
+}
+
+/// Syllable breaking the constraints of [`Phonotactics`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Violation {
+    /// Index of the word in the transcription.
+    pub word: usize,
+    /// Index of the syllable in the word.
+    pub syllable: usize,
+    pub kind: ViolationKind
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let position = (self.syllable + 1, self.word + 1);
+        match &self.kind {
+            ViolationKind::Onset(onset) => {
+                write!(formatter, "Onset ({}) of syllable {} of word {} is not allowed", onset, position.0, position.1)
+            },
+            ViolationKind::Coda(coda) => {
+                write!(formatter, "Coda ({}) of syllable {} of word {} is not allowed", coda, position.0, position.1)
+            },
+            ViolationKind::Shape(shape) => {
+                write!(formatter, "Shape {} of syllable {} of word {} is not allowed", shape, position.0, position.1)
+            }
+        }
+    }
+}
+
+impl core::error::Error for Violation {}
+
+/// Allowed onset and coda clusters and syllable shapes; everything is allowed until restricted.
+///
+/// Transcriptions are divided into syllables by [`IpaSlice::syllabify`] before checking, so every
+/// nucleus is a single sound. Empty onsets and codas are restricted only by the shapes, and clusters
+/// are compared sound by sound, including length and palatalization.
+///
+/// # Examples
+///
+/// ```
+/// use ipa_sounds::{Ipa, Phonotactics};
+///
+/// let phonotactics = Phonotactics::new()
+///     .shapes(["V", "CV", "CVC"].map(|shape| shape.parse().unwrap()))
+///     .codas([Ipa::try_from("n").unwrap()]);
+/// assert_eq!(phonotactics.check(&Ipa::try_from("ˈpami nan").unwrap()), Ok(()));
+/// let violations = phonotactics.check(&Ipa::try_from("pam spa").unwrap()).unwrap_err();
+/// let violations: Vec<String> = violations.iter().map(|violation| violation.to_string()).collect();
+/// assert_eq!(violations, [
+///     "Coda (m) of syllable 1 of word 1 is not allowed",
+///     "Shape CCV of syllable 1 of word 2 is not allowed"
+/// ]);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Phonotactics {
+    pub(crate) onsets: Option<Vec<Ipa>>,
+    pub(crate) codas: Option<Vec<Ipa>>,
+    pub(crate) shapes: Option<Vec<SyllableShape>>
+}
+
+impl Phonotactics {
+    /// Creates constraints allowing every syllable.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Allows only the given non-empty onset clusters, in addition to previously allowed ones.
+    pub fn onsets(mut self, onsets: impl IntoIterator<Item = Ipa>) -> Self {
+        self.onsets.get_or_insert_with(Vec::new).extend(onsets);
+        self
+    }
+
+    /// Allows only the given non-empty coda clusters, in addition to previously allowed ones.
+    pub fn codas(mut self, codas: impl IntoIterator<Item = Ipa>) -> Self {
+        self.codas.get_or_insert_with(Vec::new).extend(codas);
+        self
+    }
+
+    /// Allows only the given syllable shapes, in addition to previously allowed ones.
+    pub fn shapes(mut self, shapes: impl IntoIterator<Item = SyllableShape>) -> Self {
+        self.shapes.get_or_insert_with(Vec::new).extend(shapes);
+        self
+    }
+
+    /// Checks every syllable, reporting all violations in order.
+    pub fn check(&self, ipa: &IpaSlice) -> Result<(), Vec<Violation>> {
+        let mut violations = Vec::new();
+        for (word, syllables) in ipa.syllabify().iter().enumerate() {
+            for (index, syllable) in syllables.iter().enumerate() {
+                let mut push = |kind| violations.push(Violation { word, syllable: index, kind });
+                if !allows(&self.onsets, &syllable.onset) {
+                    push(ViolationKind::Onset(syllable.onset.iter().copied().collect()));
+                }
+                if !allows(&self.codas, &syllable.coda) {
+                    push(ViolationKind::Coda(syllable.coda.iter().copied().collect()));
+                }
+                let shape = SyllableShape::of(syllable);
+                if self.shapes.as_ref().is_some_and(|shapes| !shapes.contains(&shape)) {
+                    push(ViolationKind::Shape(shape));
+                }
+            }
+        }
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(violations)
+        }
+    }
+}
+
+/// Whether the cluster is empty or among the allowed ones, if they are restricted.
+fn allows(allowed: &Option<Vec<Ipa>>, cluster: &[Sound]) -> bool {
+    cluster.is_empty() || allowed.as_ref().is_none_or(|allowed| allowed.iter().any(|ipa| ipa.as_sounds() == cluster))
+}
+
+#[cfg(test)]
+mod phonotactics_tests {
+    use super::*;
+
+    #[test]
+    fn test_shapes() {
+        ["V", "CV", "CCVC", "VVC", "CCCVVCC"].into_iter().for_each(|shape| {
+            assert_eq!(shape.parse::<SyllableShape>().map(|shape| shape.to_string()).as_deref(), Ok(shape));
+        });
+        assert_eq!("".parse::<SyllableShape>(), Err(ShapeError::NoVowel));
+        assert_eq!("CC".parse::<SyllableShape>(), Err(ShapeError::NoVowel));
+        assert_eq!("CVx".parse::<SyllableShape>(), Err(ShapeError::InvalidSymbol('x')));
+        assert_eq!("VCV".parse::<SyllableShape>(), Err(ShapeError::InvalidSymbol('V')));
+    }
+
+    #[test]
+    fn test_check() {
+        let phonotactics = Phonotactics::new().onsets([Ipa::try_from("pj").unwrap(), Ipa::try_from("m").unwrap()]);
+        assert_eq!(phonotactics.check(&Ipa::try_from("apja ma").unwrap()), Ok(()));
+        let violations = phonotactics.check(&Ipa::try_from("ma nja").unwrap()).unwrap_err();
+        assert_eq!(violations, [Violation {
+            word: 1,
+            syllable: 0,
+            kind: ViolationKind::Onset(Ipa::try_from("nj").unwrap())
+        }]);
+        assert_eq!(violations[0].to_string(), "Onset (nj) of syllable 1 of word 2 is not allowed");
+    }
+}
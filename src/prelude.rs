@@ -0,0 +1,10 @@
+//! Commonly used types and traits.
+//!
+//! ```
+//! use ipa_sounds::prelude::*;
+//!
+//! let ipa: Ipa = "nʲæ".parse().unwrap();
+//! assert_eq!(ipa[..1].to_ipa(), "nʲ".parse().unwrap());
+//! ```
+
+pub use crate::{Consonants, Error, FormatOptions, Ipa, IpaParser, IpaSlice, Sound, SoundsExt, Vowels};
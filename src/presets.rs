@@ -0,0 +1,75 @@
+//! Phoneme inventories of common languages.
+//!
+//! Only sounds the crate supports are listed: affricates are left out (transcribe them as a
+//! stop followed by a fricative, e.g. "tʃ"), as are aspiration, tones and diphthongs. Phonemes are given in their usual broad transcription.
+//!
+//! # Examples
+//!
+//! ```
+//! use ipa_sounds::{presets, Ipa};
+//!
+//! let russian = presets::russian();
+//! assert!(Ipa::try_from("mʲasa").unwrap().iter().all(|sound| russian.contains(sound)));
+//! assert!(!russian.contains(&Ipa::try_from("θ").unwrap()[0]));
+//! ```
+
+use crate::{Inventory, Ipa};
+
+/// Collects an inventory from a string of sounds separated by spaces.
+fn inventory(sounds: &str) -> Inventory {
+    Ipa::try_from(sounds)
+        .expect("presets only contain supported sounds")
+        .into_iter()
+        .collect()
+}
+
+/// General American English.
+pub fn general_american() -> Inventory {
+    inventory("p b t d k ɡ f v θ ð s z ʃ ʒ h m n ŋ l ɹ j w i ɪ e ɛ æ ɑ ɔ o ʊ u ʌ ə")
+}
+
+/// Received Pronunciation (standard southern British English).
+pub fn received_pronunciation() -> Inventory {
+    inventory("p b t d k ɡ f v θ ð s z ʃ ʒ h m n ŋ l ɹ j w iː ɪ e æ ɑː ɒ ɔː ʊ uː ʌ ɜː ə")
+}
+
+/// Russian, with the palatalized consonants as separate phonemes.
+pub fn russian() -> Inventory {
+    inventory(
+        "p pʲ b bʲ t tʲ d dʲ k kʲ ɡ ɡʲ f fʲ v vʲ s sʲ z zʲ ʂ ʐ ɕː x xʲ m mʲ n nʲ l lʲ r rʲ j \
+         i ɨ e o u a"
+    )
+}
+
+/// Castilian Spanish.
+pub fn spanish() -> Inventory {
+    inventory("p b t d k ɡ f θ s x ʝ m n ɲ l ʎ ɾ r w i e a o u")
+}
+
+/// Standard Tokyo Japanese, with long vowels and geminate obstruents.
+pub fn japanese() -> Inventory {
+    inventory(
+        "p b t d k ɡ s z ɕ ʑ h ɸ ç m n ɴ ɾ j w pː tː kː sː ɕː \
+         i e a o ɯ iː eː aː oː ɯː"
+    )
+}
+
+/// Standard Mandarin Chinese, without the distinction of aspirated stops.
+pub fn mandarin() -> Inventory {
+    inventory("p t k f s ʂ ɕ x m n ŋ l ɻ j w ɥ i y u ɤ e o a ə")
+}
+
+#[cfg(test)]
+mod presets_tests {
+    use super::*;
+
+    #[test]
+    fn test_presets() {
+        let presets = [general_american(), received_pronunciation(), russian(), spanish(), japanese(), mandarin()];
+        presets.iter().for_each(|preset| assert!(preset.len() > 20, "{}", preset));
+        assert_eq!(russian().len(), 40);
+        assert!(general_american().difference(&received_pronunciation()).iter().all(|sound| {
+            matches!(sound, crate::Sound::Vowel { .. })
+        }));
+    }
+}
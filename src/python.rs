@@ -0,0 +1,157 @@
+//! Python bindings: the `ipa_sounds` module, built from the crate's `cdylib`, e.g. with
+//! `maturin build --features python`.
+//!
+//! Invalid input raises `ValueError` with the message of the crate's `Display` impls.
+
+use alloc::{format, string::{String, ToString}, vec::Vec};
+use pyo3::{exceptions::PyValueError, prelude::*};
+use crate::{Ipa, Sound};
+
+/// Converts an error of the crate into a Python `ValueError`.
+fn value_error(error: impl ToString) -> PyErr {
+    PyValueError::new_err(error.to_string())
+}
+
+/// Sound exported to Python as `Sound`.
+#[pyclass(name = "Sound", frozen, eq)]
+#[derive(Clone, Copy, PartialEq)]
+pub struct PySound(Sound);
+
+#[pymethods]
+impl PySound {
+    /// "vowel", "consonant", "space", "stress" or "tone".
+    #[getter]
+    fn kind(&self) -> &'static str {
+        match self.0 {
+            Sound::Vowel { .. } => "vowel",
+            Sound::Consonant { .. } => "consonant",
+            Sound::Space => "space",
+            Sound::Stress { .. } => "stress",
+            Sound::Tone { .. } => "tone"
+        }
+    }
+
+    /// Descriptive name, e.g. "palatalized voiced alveolar nasal".
+    #[getter]
+    fn description(&self) -> String {
+        format!("{:#}", self.0)
+    }
+
+    #[getter]
+    fn is_long(&self) -> bool {
+        matches!(self.0, Sound::Vowel { is_long: true, .. } | Sound::Consonant { is_long: true, .. })
+    }
+
+    #[getter]
+    fn is_palatalized(&self) -> bool {
+        matches!(self.0, Sound::Consonant { is_palatalized: true, .. })
+    }
+
+    #[getter]
+    fn is_reduced(&self) -> bool {
+        matches!(self.0, Sound::Vowel { is_reduced: true, .. } | Sound::Consonant { is_reduced: true, .. })
+    }
+
+    /// Release of a consonant: "unreleased", "laterally released", "nasally released" or `None`.
+    #[getter]
+    fn release(&self) -> Option<&'static str> {
+        match self.0 {
+            Sound::Consonant { release, .. } => release.map(|release| release.name()),
+            _ => None
+        }
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Sound('{}')", self.0)
+    }
+}
+
+/// Transcription exported to Python as `Ipa`.
+#[pyclass(name = "Ipa", frozen, eq)]
+#[derive(Clone, PartialEq)]
+pub struct PyIpa(Ipa);
+
+#[pymethods]
+impl PyIpa {
+    #[new]
+    fn new(text: &str) -> PyResult<Self> {
+        Ipa::try_from(text).map(PyIpa).map_err(value_error)
+    }
+
+    #[staticmethod]
+    fn from_xsampa(text: &str) -> PyResult<Self> {
+        Ipa::from_xsampa(text).map(PyIpa).map_err(value_error)
+    }
+
+    #[staticmethod]
+    fn from_kirshenbaum(text: &str) -> PyResult<Self> {
+        Ipa::from_kirshenbaum(text).map(PyIpa).map_err(value_error)
+    }
+
+    #[staticmethod]
+    fn from_arpabet(text: &str) -> PyResult<Self> {
+        Ipa::from_arpabet(text).map(PyIpa).map_err(value_error)
+    }
+
+    /// Sounds in order.
+    fn sounds(&self) -> Vec<PySound> {
+        self.0.iter().copied().map(PySound).collect()
+    }
+
+    /// Syllables of every word.
+    fn syllabify(&self) -> Vec<Vec<String>> {
+        self.0.syllabify().iter()
+            .map(|word| word.iter().map(ToString::to_string).collect())
+            .collect()
+    }
+
+    fn to_xsampa(&self) -> String {
+        self.0.to_xsampa()
+    }
+
+    fn to_kirshenbaum(&self) -> String {
+        self.0.to_kirshenbaum()
+    }
+
+    fn to_arpabet(&self) -> PyResult<String> {
+        self.0.to_arpabet().map_err(value_error)
+    }
+
+    fn __len__(&self) -> usize {
+        self.0.len()
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Ipa('{}')", self.0)
+    }
+}
+
+/// `ipa_sounds` Python module.
+#[pymodule]
+fn ipa_sounds(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyIpa>()?;
+    module.add_class::<PySound>()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod python_tests {
+    use super::*;
+
+    #[test]
+    fn test_bindings() {
+        let ipa = PyIpa::new("ˈnʲæn").unwrap();
+        assert_eq!((ipa.__len__(), ipa.__repr__()), (4, "Ipa('ˈnʲæn')".into()));
+        let sounds = ipa.sounds();
+        assert_eq!((sounds[1].kind(), sounds[1].is_palatalized()), ("consonant", true));
+        assert!(PyIpa::from_xsampa("\"n'{n").is_ok_and(|other| other == ipa));
+    }
+}
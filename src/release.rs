@@ -0,0 +1,81 @@
+//! Release of consonants: unreleased, laterally released and nasally released stops.
+
+use crate::{Consonants, Manner};
+
+/// Non-default release of a consonant, written as a diacritic after it.
+///
+/// The lateral and nasal release marks are superscript letters, so they are only read as releases
+/// directly after a plosive; elsewhere 'ⁿ' is a reduced \[n\]. The parser reads them right after the
+/// symbol of the consonant, before palatalization and length, and writes them there.
+///
+/// # Examples
+///
+/// ```
+/// use ipa_sounds::{Ipa, Release, Sound};
+///
+/// let ipa = Ipa::try_from("ʌt̚ bʌtⁿ").unwrap();
+/// assert!(matches!(ipa[1], Sound::Consonant { release: Some(Release::Unreleased), .. }));
+/// assert!(matches!(ipa[5], Sound::Consonant { release: Some(Release::Nasal), .. }));
+/// assert_eq!(ipa.len(), 6);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+pub enum Release {
+    /// No audible release, '̚' as in [t̚].
+    Unreleased,
+    /// Lateral release, 'ˡ' as in \[tˡ\].
+    Lateral,
+    /// Nasal release, 'ⁿ' as in \[dⁿ\].
+    Nasal
+}
+
+impl Release {
+    /// All releases.
+    pub const ALL: [Release; 3] = [Release::Unreleased, Release::Lateral, Release::Nasal];
+
+    /// Diacritic written after the consonant.
+    pub const fn symbol(self) -> char {
+        match self {
+            Release::Unreleased => '\u{31A}',
+            Release::Lateral => 'ˡ',
+            Release::Nasal => 'ⁿ'
+        }
+    }
+
+    /// Whether the consonant can have the release: lateral and nasal releases are only read after plosives.
+    pub const fn is_allowed(self, consonant: Consonants) -> bool {
+        matches!(self, Release::Unreleased) || matches!(consonant.manner(), Manner::Plosive)
+    }
+
+    /// Word used in descriptive names, e.g. "unreleased voiceless alveolar plosive".
+    pub(crate) const fn name(self) -> &'static str {
+        match self {
+            Release::Unreleased => "unreleased",
+            Release::Lateral => "laterally released",
+            Release::Nasal => "nasally released"
+        }
+    }
+}
+
+#[cfg(test)]
+mod release_tests {
+    use super::*;
+    use crate::{Ipa, Sound};
+
+    #[test]
+    fn test_round_trip() {
+        let ipa = Ipa::try_from("t̚ʲː dˡ kⁿʲ sⁿ m̚").unwrap();
+        assert_eq!(ipa.to_string(), "t̚ʲː dˡ kⁿʲ sⁿ m̚");
+        let releases: Vec<_> = ipa.iter()
+            .filter_map(|sound| match *sound {
+                Sound::Consonant { release, .. } => Some(release),
+                _ => None
+            })
+            .collect();
+        assert_eq!(releases, [Some(Release::Unreleased), Some(Release::Lateral), Some(Release::Nasal), None, None,
+            Some(Release::Unreleased)]);
+        assert_eq!(format!("{:#}", ipa[0]), "long palatalized unreleased voiceless alveolar plosive");
+        assert!(Ipa::try_from("sˡ").is_err());
+    }
+}
@@ -0,0 +1,125 @@
+//! Reader-friendly English respelling, as shown next to IPA by dictionaries.
+
+use alloc::{string::String, vec::Vec};
+use crate::{transliteration::{self, Table}, IpaSlice, Sound, Stress, Syllable};
+
+/// Respellings of sounds and sequences of them, after the respelling key of the English Wikipedia.
+const RESPELLING: &Table = &[
+    // Vowels
+    ("a", "æ"), ("e", "ɛ"), ("i", "ɪ"), ("o", "ɒ"), ("u", "ʌ"), ("uu", "ʊ"), ("uh", "ə"),
+    ("ee", "i"), ("oo", "u"), ("ah", "ɑ"), ("aw", "ɔ"), ("ur", "ɜ"), ("e", "e"), ("oh", "o"), ("a", "a"),
+    ("ay", "eɪ"), ("eye", "aɪ"), ("oy", "ɔɪ"), ("oh", "oʊ"), ("oh", "əʊ"), ("ow", "aʊ"),
+    ("ar", "ɑɹ"), ("or", "ɔɹ"), ("ur", "ɜɹ"), ("eer", "ɪɹ"), ("air", "ɛɹ"), ("oor", "ʊɹ"),
+    // Consonants
+    ("p", "p"), ("b", "b"), ("t", "t"), ("d", "d"), ("k", "k"), ("g", "ɡ"),
+    ("f", "f"), ("v", "v"), ("th", "θ"), ("dh", "ð"), ("s", "s"), ("z", "z"), ("sh", "ʃ"), ("zh", "ʒ"),
+    ("ch", "tʃ"), ("j", "dʒ"), ("kh", "x"), ("wh", "ʍ"), ("h", "h"),
+    ("m", "m"), ("n", "n"), ("ng", "ŋ"), ("l", "l"), ("r", "ɹ"), ("r", "r"), ("r", "ɾ"), ("y", "j"), ("w", "w")
+];
+
+/// Vowels that cannot end a stressed English syllable and take the following consonant instead.
+const CHECKED: &str = "ɪɛæʌɒʊ";
+
+/// Diphthongs that [`IpaSlice::syllabify`] divides into two syllables.
+const DIPHTHONGS: &[&str] = &["eɪ", "aɪ", "ɔɪ", "oʊ", "əʊ", "aʊ"];
+
+/// Symbol of the phoneme of a vowel or consonant.
+fn phoneme(sound: &Sound) -> Option<char> {
+    match *sound {
+        Sound::Vowel { phoneme, .. } => Some(char::from(phoneme)),
+        Sound::Consonant { phoneme, .. } => Some(char::from(phoneme)),
+        Sound::Space | Sound::Stress { .. } | Sound::Tone { .. } => None
+    }
+}
+
+/// Syllables of a word regrouped as English speakers divide them: diphthongs are one nucleus, and
+/// checked vowels take the first consonant (or affricate) of an unstressed syllable after them.
+fn english_syllables(word: Vec<Syllable>) -> Vec<Syllable> {
+    let mut syllables: Vec<Syllable> = Vec::new();
+    for syllable in word {
+        if let Some(previous) = syllables.last_mut() {
+            let pair = [previous.nucleus.last(), syllable.nucleus.first()].map(|sound| sound.and_then(phoneme));
+            let is_diphthong = DIPHTHONGS.iter().any(|diphthong| diphthong.chars().map(Some).eq(pair));
+            if is_diphthong && previous.coda.is_empty() && syllable.onset.is_empty() && syllable.stress == Stress::Unstressed {
+                previous.nucleus.extend(syllable.nucleus);
+                previous.coda = syllable.coda;
+                continue;
+            }
+        }
+        syllables.push(syllable);
+    }
+    for i in 1..syllables.len() {
+        let (before, after) = syllables.split_at_mut(i);
+        let (previous, next) = (&mut before[i - 1], &mut after[0]);
+        let is_checked = matches!(previous.nucleus.as_slice(), [vowel] if phoneme(vowel).is_some_and(|vowel| CHECKED.contains(vowel)));
+        if is_checked && previous.coda.is_empty() && next.stress == Stress::Unstressed && !next.onset.is_empty() {
+            let onset: Vec<Option<char>> = next.onset.iter().take(2).map(phoneme).collect();
+            let count = if matches!(onset.as_slice(), [Some('t' | 'd'), Some('ʃ' | 'ʒ')]) { 2 } else { 1 };
+            previous.coda.extend(next.onset.drain(..count));
+        }
+    }
+    syllables
+}
+
+impl IpaSlice {
+    /// Writes a best-effort English respelling: syllables are separated by hyphens, and the syllable
+    /// with primary stress is capitalized.
+    ///
+    /// Length and palatalization are dropped, reduced sounds are respelled as full ones, and sounds
+    /// without a respelling are kept as IPA.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let ipa = ipa_sounds::Ipa::try_from("fəˈnɛtɪk ˈkɪtʃən ˈaɪlənd ˈlaɪtˌweɪt").unwrap();
+    /// assert_eq!(ipa.to_respelling(), "fuh-NET-ik KICH-uhn EYE-luhnd LYT-wayt");
+    /// ```
+    pub fn to_respelling(&self) -> String {
+        let words: Vec<String> = self.syllabify().into_iter()
+            .map(|word| {
+                let syllables: Vec<String> = english_syllables(word).iter()
+                    .map(|syllable| {
+                        let sounds: Vec<Sound> = syllable.onset.iter().chain(&syllable.nucleus).chain(&syllable.coda)
+                            .map(|&sound| match sound {
+                                Sound::Vowel { phoneme, is_reduced, .. } => Sound::Vowel { phoneme, is_long: false, is_reduced },
+                                Sound::Consonant { phoneme, is_reduced, .. } => {
+                                    Sound::Consonant { phoneme, is_long: false, is_palatalized: false, is_reduced, release: None }
+                                },
+                                sound => sound
+                            })
+                            .collect();
+                        let mut respelling = transliteration::write(IpaSlice::new(&sounds), RESPELLING);
+                        if !syllable.onset.is_empty() {
+                            // "eye" is only written on its own, as in "EYE-land" but "LYT".
+                            respelling = respelling.replacen("eye", "y", 1);
+                        }
+                        if syllable.stress == Stress::Primary {
+                            respelling.to_ascii_uppercase()
+                        } else {
+                            respelling
+                        }
+                    })
+                    .collect();
+                syllables.join("-")
+            })
+            .collect();
+        words.join(" ")
+    }
+}
+
+#[cfg(test)]
+mod respelling_tests {
+    use crate::Ipa;
+
+    fn respelling(ipa: &str) -> String {
+        Ipa::try_from(ipa).unwrap().to_respelling()
+    }
+
+    #[test]
+    fn test_to_respelling() {
+        assert_eq!(respelling("ˈbʌtə"), "BUT-uh");
+        assert_eq!(respelling("ɪˈlɛkʃən"), "i-LEK-shuhn");
+        assert_eq!(respelling("ˈkɑːɹ ˈndʒ"), "KAR NJ");
+        assert_eq!(respelling(""), "");
+    }
+}
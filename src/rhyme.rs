@@ -0,0 +1,115 @@
+//! Rhymes, assonance and consonance between the endings of transcriptions.
+
+use alloc::vec::Vec;
+use crate::{IpaSlice, Sound, Stress, Syllable};
+
+/// Kind of likeness of the endings compared by [`IpaSlice::rhymes_with`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RhymeScheme {
+    /// Same rime with different onsets of the final stressed syllable, e.g. "cat" and "hat".
+    Perfect,
+    /// Same vowels in the rime, e.g. "cat" and "map".
+    Assonance,
+    /// Same consonants in the rime, e.g. "cat" and "kit".
+    Consonance
+}
+
+/// Syllables of all words and the index of the final stressed one.
+fn stressed_syllables(ipa: &IpaSlice) -> (Vec<Syllable>, Option<usize>) {
+    let syllables: Vec<Syllable> = ipa.syllabify().into_iter().flatten().collect();
+    let position = [Stress::Primary, Stress::Secondary].into_iter()
+        .find_map(|stress| syllables.iter().rposition(|syllable| syllable.stress == stress))
+        .or_else(|| syllables.len().checked_sub(1));
+    (syllables, position)
+}
+
+/// Onset and rime of the final stressed syllable, `None` without syllables.
+fn ending(ipa: &IpaSlice) -> Option<(Vec<Sound>, Vec<Sound>)> {
+    let (mut syllables, position) = stressed_syllables(ipa);
+    let later = syllables.split_off(position? + 1);
+    let stressed = syllables.swap_remove(position?);
+    let rime = stressed.nucleus.into_iter().chain(stressed.coda)
+        .chain(later.into_iter().flat_map(|syllable| syllable.onset.into_iter().chain(syllable.nucleus).chain(syllable.coda)))
+        .collect();
+    Some((stressed.onset, rime))
+}
+
+impl IpaSlice {
+    /// Last syllable with primary stress, or with secondary stress if none has primary stress, across all
+    /// words; the last syllable if nothing is stressed, `None` without syllables.
+    pub fn final_stressed_syllable(&self) -> Option<Syllable> {
+        let (mut syllables, position) = stressed_syllables(self);
+        Some(syllables.swap_remove(position?))
+    }
+
+    /// Nucleus and coda of the final stressed syllable followed by the vowels and consonants of all
+    /// later syllables, i.e. the part that has to match for a rhyme.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ipa_sounds::Ipa;
+    ///
+    /// let ipa = Ipa::try_from("ˈhæpi").unwrap();
+    /// assert_eq!(ipa.final_stressed_syllable().unwrap().to_string(), "ˈhæ");
+    /// assert_eq!(ipa.rime(), Ipa::try_from("æpi").unwrap().as_sounds());
+    /// ```
+    pub fn rime(&self) -> Vec<Sound> {
+        ending(self).map(|(_, rime)| rime).unwrap_or_default()
+    }
+
+    /// Whether the endings from the final stressed syllable on are alike in the way of the scheme.
+    ///
+    /// Sounds are compared exactly, so length and palatalization count, while stress marks, tones
+    /// and spaces are ignored. Transcriptions without syllables rhyme with nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ipa_sounds::{Ipa, RhymeScheme};
+    ///
+    /// let [cat, hat, map, kit] = ["kæt", "hæt", "mæp", "kɪt"].map(|word| Ipa::try_from(word).unwrap());
+    /// assert!(cat.rhymes_with(&hat, RhymeScheme::Perfect));
+    /// assert!(!cat.rhymes_with(&cat, RhymeScheme::Perfect));
+    /// assert!(cat.rhymes_with(&map, RhymeScheme::Assonance));
+    /// assert!(cat.rhymes_with(&kit, RhymeScheme::Consonance));
+    /// assert!(Ipa::try_from("ˈhæpi").unwrap().rhymes_with(&Ipa::try_from("ʌnˈhæpi").unwrap(), RhymeScheme::Assonance));
+    /// ```
+    pub fn rhymes_with(&self, other: &IpaSlice, scheme: RhymeScheme) -> bool {
+        let (Some((first_onset, first_rime)), Some((second_onset, second_rime))) = (ending(self), ending(other)) else {
+            return false;
+        };
+        let matching = |keep: fn(&&Sound) -> bool| {
+            let first = first_rime.iter().filter(keep);
+            first.clone().next().is_some() && first.eq(second_rime.iter().filter(keep))
+        };
+        match scheme {
+            RhymeScheme::Perfect => first_rime == second_rime && first_onset != second_onset,
+            RhymeScheme::Assonance => matching(|sound| matches!(sound, Sound::Vowel { .. })),
+            RhymeScheme::Consonance => matching(|sound| matches!(sound, Sound::Consonant { .. }))
+        }
+    }
+}
+
+#[cfg(test)]
+mod rhyme_tests {
+    use super::*;
+    use crate::Ipa;
+
+    fn rhymes(first: &str, second: &str, scheme: RhymeScheme) -> bool {
+        Ipa::try_from(first).unwrap().rhymes_with(&Ipa::try_from(second).unwrap(), scheme)
+    }
+
+    #[test]
+    fn test_rhymes() {
+        assert!(rhymes("ˈnʲæn", "ˈpʲæn", RhymeScheme::Perfect));
+        assert!(rhymes("ˈmʌni ˈhʌni", "ˈfʌni", RhymeScheme::Perfect));
+        assert!(rhymes("ˌmɛləˈdi", "ˈsi", RhymeScheme::Perfect));
+        assert!(!rhymes("ˈnʲæn", "ˈnʲæː", RhymeScheme::Perfect));
+        assert!(!rhymes("ˈsi", "ˈmi", RhymeScheme::Consonance));
+        assert!(!rhymes("", "ˈmi", RhymeScheme::Assonance));
+        assert_eq!(Ipa::try_from("ˈbʌtə").unwrap().rime(), Ipa::try_from("ʌtə").unwrap().as_sounds());
+        assert!(Ipa::default().rime().is_empty());
+    }
+}
@@ -0,0 +1,301 @@
+//! SPE-style rewrite rules "A → B / C _ D" and ordered derivations.
+
+use alloc::{borrow::ToOwned, vec::Vec};
+use core::fmt;
+use crate::{FeatureSpec, Ipa, IpaSlice, Sound};
+
+/// Pattern matching one position of a rule: a sound, a natural class or a word boundary.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Matcher {
+    Sound(Sound),
+    Class(FeatureSpec),
+    /// Start or end of a word, written "#"; matches a space or the edge of the transcription.
+    WordBoundary
+}
+
+impl Matcher {
+    /// Whether the sound matches; a word boundary matches only a space.
+    pub fn matches(&self, sound: &Sound) -> bool {
+        match self {
+            Matcher::Sound(expected) => expected == sound,
+            Matcher::Class(spec) => sound.matches(spec),
+            Matcher::WordBoundary => matches!(sound, Sound::Space)
+        }
+    }
+}
+
+impl From<Sound> for Matcher {
+    fn from(sound: Sound) -> Self {
+        Matcher::Sound(sound)
+    }
+}
+
+impl From<FeatureSpec> for Matcher {
+    fn from(spec: FeatureSpec) -> Self {
+        Matcher::Class(spec)
+    }
+}
+
+/// Writes the sound, the specification or "#".
+impl fmt::Display for Matcher {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Matcher::Sound(sound) => fmt::Display::fmt(sound, formatter),
+            Matcher::Class(spec) => fmt::Display::fmt(spec, formatter),
+            Matcher::WordBoundary => formatter.write_str("#")
+        }
+    }
+}
+
+/// Right-hand side of a rule: what the target becomes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Change {
+    Sound(Sound),
+    /// Changes the specified features as [`Sound::with_features`] does.
+    Features(FeatureSpec),
+    /// Deletes the target, written "∅".
+    Delete
+}
+
+impl From<Sound> for Change {
+    fn from(sound: Sound) -> Self {
+        Change::Sound(sound)
+    }
+}
+
+impl From<FeatureSpec> for Change {
+    fn from(spec: FeatureSpec) -> Self {
+        Change::Features(spec)
+    }
+}
+
+/// Writes the sound, the specification or "∅".
+impl fmt::Display for Change {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Change::Sound(sound) => fmt::Display::fmt(sound, formatter),
+            Change::Features(spec) => fmt::Display::fmt(spec, formatter),
+            Change::Delete => formatter.write_str("∅")
+        }
+    }
+}
+
+/// Rewrite rule "A → B / C _ D": the target A becomes B between the contexts C and D.
+///
+/// Only vowels and consonants are targets. Stress marks are transparent to the contexts, so that
+/// e.g. intervocalic voicing applies across them. A rule is applied simultaneously: contexts are
+/// matched against the input, not against the output of earlier changes.
+///
+/// # Examples
+///
+/// ```
+/// use ipa_sounds::{Feature, FeatureSpec, Ipa, Matcher, Rule};
+///
+/// let obstruent = FeatureSpec::new().minus(Feature::Sonorant);
+/// let final_devoicing = Rule::new(obstruent, FeatureSpec::new().minus(Feature::Voice))
+///     .right([Matcher::WordBoundary]);
+/// assert_eq!(final_devoicing.to_string(), "[-sonorant] → [-voice] / _ #");
+/// assert_eq!(final_devoicing.apply(&Ipa::try_from("ɡlaz ˈdubʲ").unwrap()).to_string(), "ɡlas ˈdupʲ");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rule {
+    target: Matcher,
+    change: Change,
+    left: Vec<Matcher>,
+    right: Vec<Matcher>
+}
+
+impl Rule {
+    /// Creates a rule applying in every context.
+    pub fn new(target: impl Into<Matcher>, change: impl Into<Change>) -> Self {
+        Rule { target: target.into(), change: change.into(), left: Vec::new(), right: Vec::new() }
+    }
+
+    /// Sets the context before the target, in order.
+    pub fn left(mut self, context: impl IntoIterator<Item = Matcher>) -> Self {
+        self.left = context.into_iter().collect();
+        self
+    }
+
+    /// Sets the context after the target, in order.
+    pub fn right(mut self, context: impl IntoIterator<Item = Matcher>) -> Self {
+        self.right = context.into_iter().collect();
+        self
+    }
+
+    /// Whether the rule applies to the sound at the index.
+    pub fn applies_at(&self, sounds: &[Sound], index: usize) -> bool {
+        matches!(sounds[index], Sound::Vowel { .. } | Sound::Consonant { .. })
+            && !matches!(self.target, Matcher::WordBoundary)
+            && self.target.matches(&sounds[index])
+            && self.left.iter().rev().try_fold(index, |end, matcher| match_before(sounds, end, matcher)).is_some()
+            && self.right.iter().try_fold(index + 1, |start, matcher| match_after(sounds, start, matcher)).is_some()
+    }
+
+    /// Applies the rule once at every position where it matches.
+    pub fn apply(&self, ipa: &IpaSlice) -> Ipa {
+        (0..ipa.len())
+            .filter_map(|i| {
+                if !self.applies_at(ipa, i) {
+                    return Some(ipa[i]);
+                }
+                match self.change {
+                    Change::Sound(sound) => Some(sound),
+                    Change::Features(spec) => ipa[i].with_features(&spec),
+                    Change::Delete => None
+                }
+            })
+            .collect()
+    }
+
+    /// Applies the rule repeatedly until the transcription stops changing, at most `limit` times.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ipa_sounds::{Feature, FeatureSpec, Ipa, Matcher, Rule};
+    ///
+    /// let consonant = FeatureSpec::new().plus(Feature::Consonantal);
+    /// let degemination = Rule::new(consonant, ipa_sounds::Change::Delete).right([Matcher::Class(consonant)]);
+    /// assert_eq!(degemination.apply(&Ipa::try_from("amnpa").unwrap()).to_string(), "apa");
+    /// let deletion = Rule::new(consonant, ipa_sounds::Change::Delete).right([Matcher::WordBoundary]);
+    /// assert_eq!(deletion.apply(&Ipa::try_from("amnp").unwrap()).to_string(), "amn");
+    /// assert_eq!(deletion.apply_repeatedly(&Ipa::try_from("amnp").unwrap(), 10).to_string(), "a");
+    /// ```
+    pub fn apply_repeatedly(&self, ipa: &IpaSlice, limit: usize) -> Ipa {
+        let mut current = ipa.to_owned();
+        for _ in 0..limit {
+            let next = self.apply(&current);
+            if next == current {
+                break;
+            }
+            current = next;
+        }
+        current
+    }
+}
+
+/// Writes the rule in SPE notation, e.g. "[-sonorant] → [-voice] / _ #".
+impl fmt::Display for Rule {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "{} → {}", self.target, self.change)?;
+        if self.left.is_empty() && self.right.is_empty() {
+            return Ok(());
+        }
+        formatter.write_str(" /")?;
+        self.left.iter().try_for_each(|matcher| write!(formatter, " {}", matcher))?;
+        formatter.write_str(" _")?;
+        self.right.iter().try_for_each(|matcher| write!(formatter, " {}", matcher))
+    }
+}
+
+/// Matches the sound before `end`, skipping stress marks, and returns where the match starts.
+fn match_before(sounds: &[Sound], end: usize, matcher: &Matcher) -> Option<usize> {
+    let position = sounds[..end].iter().rposition(|sound| !matches!(sound, Sound::Stress { .. }));
+    match position {
+        None => matches!(matcher, Matcher::WordBoundary).then_some(0),
+        Some(i) => matcher.matches(&sounds[i]).then_some(i)
+    }
+}
+
+/// Matches the sound at or after `start`, skipping stress marks, and returns where the match ends.
+fn match_after(sounds: &[Sound], start: usize, matcher: &Matcher) -> Option<usize> {
+    let position = sounds[start.min(sounds.len())..].iter().position(|sound| !matches!(sound, Sound::Stress { .. }));
+    match position {
+        None => matches!(matcher, Matcher::WordBoundary).then_some(sounds.len()),
+        Some(i) => matcher.matches(&sounds[start + i]).then_some(start + i + 1)
+    }
+}
+
+/// Ordered list of rules, each applied to the output of the previous one.
+///
+/// # Examples
+///
+/// ```
+/// use ipa_sounds::{Derivation, Feature, FeatureSpec, Ipa, Matcher, Rule};
+///
+/// let vowel = Matcher::Class(FeatureSpec::new().plus(Feature::Syllabic));
+/// let derivation = Derivation::new()
+///     .rule(Rule::new(FeatureSpec::new().minus(Feature::Sonorant), FeatureSpec::new().plus(Feature::Voice))
+///         .left([vowel])
+///         .right([vowel]))
+///     .rule(Rule::new(FeatureSpec::new().minus(Feature::Sonorant), FeatureSpec::new().minus(Feature::Voice))
+///         .right([Matcher::WordBoundary]));
+/// let stages: Vec<String> = derivation.stages(&Ipa::try_from("ˈatak").unwrap()).iter().map(|ipa| ipa.to_string()).collect();
+/// assert_eq!(stages, ["ˈatak", "ˈadak", "ˈadak"]);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Derivation {
+    pub rules: Vec<Rule>
+}
+
+impl Derivation {
+    /// Creates a derivation without rules.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a rule applied after the previous ones.
+    pub fn rule(mut self, rule: Rule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    /// Applies every rule in order.
+    pub fn apply(&self, ipa: &IpaSlice) -> Ipa {
+        self.rules.iter().fold(ipa.to_owned(), |ipa, rule| rule.apply(&ipa))
+    }
+
+    /// Applies the whole derivation repeatedly until the transcription stops changing, at most `limit` times.
+    pub fn apply_repeatedly(&self, ipa: &IpaSlice, limit: usize) -> Ipa {
+        let mut current = ipa.to_owned();
+        for _ in 0..limit {
+            let next = self.apply(&current);
+            if next == current {
+                break;
+            }
+            current = next;
+        }
+        current
+    }
+
+    /// Input followed by the output of every rule, in order.
+    pub fn stages(&self, ipa: &IpaSlice) -> Vec<Ipa> {
+        let mut stages = Vec::from([ipa.to_owned()]);
+        for rule in &self.rules {
+            let next = rule.apply(stages.last().expect("stages start with the input"));
+            stages.push(next);
+        }
+        stages
+    }
+}
+
+#[cfg(test)]
+mod rule_tests {
+    use super::*;
+    use crate::Feature;
+
+    #[test]
+    fn test_contexts() {
+        let ipa = Ipa::try_from("ˈpam ma").unwrap();
+        let [p, a, m] = [1, 2, 3].map(|i| ipa[i]);
+        let rule = Rule::new(m, p).left([Matcher::WordBoundary]);
+        assert_eq!(rule.apply(&ipa).to_string(), "ˈpam pa");
+        let rule = Rule::new(a, Change::Delete).left([Matcher::Sound(p)]).right([Matcher::Sound(m), Matcher::WordBoundary]);
+        assert_eq!(rule.to_string(), "a → ∅ / p _ m #");
+        assert_eq!(rule.apply(&ipa).to_string(), "ˈpm ma");
+        assert!(!Rule::new(Matcher::WordBoundary, p).applies_at(&ipa, 4));
+    }
+
+    #[test]
+    fn test_simultaneous() {
+        let vowel = Matcher::Class(FeatureSpec::new().plus(Feature::Syllabic));
+        let rule = Rule::new(vowel, Change::Delete).left([vowel]);
+        assert_eq!(rule.apply(&Ipa::try_from("maaa").unwrap()).to_string(), "ma");
+        assert_eq!(Derivation::new().apply(&Ipa::try_from("ma").unwrap()).to_string(), "ma");
+    }
+}
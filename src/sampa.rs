@@ -0,0 +1,134 @@
+//! Original per-language SAMPA tables, as used by legacy lexicons such as CELEX.
+//!
+//! Unlike X-SAMPA, SAMPA only covers the phonemes of one language, and the same symbol can stand for
+//! different sounds in different languages, e.g. "r" is \[ɹ\] in English but \[ɾ\] in Spanish. Phonemes
+//! the crate does not support, such as French nasal vowels, are left out.
+//!
+//! # Examples
+//!
+//! ```
+//! use ipa_sounds::sampa::{self, Language};
+//!
+//! let language = Language::from_code("nl").unwrap();
+//! let ipa = sampa::to_ipa("\"wAt@r", language).unwrap();
+//! assert_eq!(ipa.to_string(), "ˈʋɑtər");
+//! assert_eq!(sampa::from_ipa(&ipa, language), "\"wAt@r");
+//! assert_eq!(sampa::to_ipa("\"wAt@r", Language::English).unwrap().to_string(), "ˈwɑtəɹ");
+//! ```
+
+use alloc::string::String;
+use crate::{transliteration::{self, Table, TransliterationError}, Ipa, IpaSlice};
+
+/// Language of a SAMPA table.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Language {
+    Dutch,
+    English,
+    French,
+    German,
+    Spanish
+}
+
+impl Language {
+    /// Every language with a table.
+    pub const ALL: &'static [Self] = &[Language::Dutch, Language::English, Language::French, Language::German, Language::Spanish];
+
+    /// ISO 639-1 code of the language, e.g. "de".
+    pub const fn code(self) -> &'static str {
+        match self {
+            Language::Dutch => "nl",
+            Language::English => "en",
+            Language::French => "fr",
+            Language::German => "de",
+            Language::Spanish => "es"
+        }
+    }
+
+    /// Language with the ISO 639-1 code, ignoring case.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|language| language.code().eq_ignore_ascii_case(code))
+    }
+
+    fn table(self) -> &'static Table {
+        match self {
+            Language::Dutch => DUTCH,
+            Language::English => ENGLISH,
+            Language::French => FRENCH,
+            Language::German => GERMAN,
+            Language::Spanish => SPANISH
+        }
+    }
+}
+
+/// Reads SAMPA of the language.
+pub fn to_ipa(sampa: &str, language: Language) -> Result<Ipa, TransliterationError> {
+    transliteration::parse(sampa, language.table())
+}
+
+/// Writes the sounds in SAMPA of the language; sounds outside its table are kept as IPA.
+pub fn from_ipa(ipa: &IpaSlice, language: Language) -> String {
+    transliteration::write(ipa, language.table())
+}
+
+const DUTCH: &Table = &[
+    ("p", "p"), ("b", "b"), ("t", "t"), ("d", "d"), ("k", "k"), ("g", "ɡ"),
+    ("f", "f"), ("v", "v"), ("s", "s"), ("z", "z"), ("S", "ʃ"), ("Z", "ʒ"), ("x", "x"), ("G", "ɣ"), ("h", "ɦ"),
+    ("m", "m"), ("n", "n"), ("N", "ŋ"), ("J", "ɲ"), ("l", "l"), ("r", "r"), ("w", "ʋ"), ("j", "j"),
+    ("I", "ɪ"), ("E", "ɛ"), ("A", "ɑ"), ("O", "ɔ"), ("Y", "ʏ"), ("@", "ə"),
+    ("i", "i"), ("y", "y"), ("u", "u"), ("a", "a"), ("e", "e"), ("2", "ø"), ("o", "o"), ("9", "œ"),
+    (":", "ː"), ("\"", "ˈ"), ("%", "ˌ"), (" ", " ")
+];
+
+const ENGLISH: &Table = &[
+    ("p", "p"), ("b", "b"), ("t", "t"), ("d", "d"), ("k", "k"), ("g", "ɡ"),
+    ("f", "f"), ("v", "v"), ("T", "θ"), ("D", "ð"), ("s", "s"), ("z", "z"), ("S", "ʃ"), ("Z", "ʒ"), ("h", "h"),
+    ("m", "m"), ("n", "n"), ("N", "ŋ"), ("l", "l"), ("r", "ɹ"), ("w", "w"), ("j", "j"),
+    ("I", "ɪ"), ("e", "e"), ("{", "æ"), ("Q", "ɒ"), ("V", "ʌ"), ("U", "ʊ"), ("@", "ə"),
+    ("i", "i"), ("3", "ɜ"), ("A", "ɑ"), ("O", "ɔ"), ("u", "u"), ("a", "a"), ("E", "ɛ"),
+    (":", "ː"), ("\"", "ˈ"), ("%", "ˌ"), (" ", " ")
+];
+
+const FRENCH: &Table = &[
+    ("p", "p"), ("b", "b"), ("t", "t"), ("d", "d"), ("k", "k"), ("g", "ɡ"),
+    ("f", "f"), ("v", "v"), ("s", "s"), ("z", "z"), ("S", "ʃ"), ("Z", "ʒ"),
+    ("m", "m"), ("n", "n"), ("J", "ɲ"), ("N", "ŋ"), ("l", "l"), ("R", "ʁ"), ("w", "w"), ("H", "ɥ"), ("j", "j"),
+    ("i", "i"), ("e", "e"), ("E", "ɛ"), ("a", "a"), ("A", "ɑ"), ("O", "ɔ"), ("o", "o"), ("u", "u"),
+    ("y", "y"), ("2", "ø"), ("9", "œ"), ("@", "ə"),
+    (":", "ː"), ("\"", "ˈ"), ("%", "ˌ"), (" ", " ")
+];
+
+const GERMAN: &Table = &[
+    ("p", "p"), ("b", "b"), ("t", "t"), ("d", "d"), ("k", "k"), ("g", "ɡ"), ("?", "ʔ"),
+    ("f", "f"), ("v", "v"), ("s", "s"), ("z", "z"), ("S", "ʃ"), ("Z", "ʒ"), ("C", "ç"), ("x", "x"), ("h", "h"),
+    ("m", "m"), ("n", "n"), ("N", "ŋ"), ("l", "l"), ("R", "ʁ"), ("r", "r"), ("j", "j"),
+    ("I", "ɪ"), ("E", "ɛ"), ("a", "a"), ("O", "ɔ"), ("U", "ʊ"), ("Y", "ʏ"), ("9", "œ"), ("@", "ə"), ("6", "ɐ"),
+    ("i", "i"), ("e", "e"), ("y", "y"), ("2", "ø"), ("o", "o"), ("u", "u"),
+    (":", "ː"), ("\"", "ˈ"), ("%", "ˌ"), (" ", " ")
+];
+
+const SPANISH: &Table = &[
+    ("p", "p"), ("b", "b"), ("t", "t"), ("d", "d"), ("k", "k"), ("g", "ɡ"),
+    ("B", "β"), ("D", "ð"), ("G", "ɣ"),
+    ("f", "f"), ("T", "θ"), ("s", "s"), ("z", "z"), ("x", "x"), ("jj", "ʝ"),
+    ("m", "m"), ("n", "n"), ("J", "ɲ"), ("N", "ŋ"), ("l", "l"), ("L", "ʎ"), ("r", "ɾ"), ("rr", "r"),
+    ("w", "w"), ("j", "j"),
+    ("i", "i"), ("e", "e"), ("a", "a"), ("o", "o"), ("u", "u"),
+    ("\"", "ˈ"), ("%", "ˌ"), (" ", " ")
+];
+
+#[cfg(test)]
+mod sampa_tests {
+    use super::*;
+
+    #[test]
+    fn test_languages() {
+        assert!(Language::ALL.iter().all(|&language| Language::from_code(language.code()) == Some(language)));
+        assert_eq!(Language::from_code("DE"), Some(Language::German));
+        assert_eq!(to_ipa("\"perro \"pero", Language::Spanish).unwrap().to_string(), "ˈpero ˈpeɾo");
+        assert_eq!(to_ipa("\"pe.rro", Language::Spanish).map(|_| ()), Err(TransliterationError::UnknownSymbol('.')));
+        let ipa = to_ipa("\"bu:x", Language::German).unwrap();
+        assert_eq!(from_ipa(&ipa, Language::English), "\"bu:x");
+        assert_eq!(from_ipa(&ipa, Language::Spanish), "\"buːx");
+    }
+}
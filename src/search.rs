@@ -0,0 +1,124 @@
+//! Search for sounds and sequences of sounds in transcriptions.
+
+use core::slice;
+use crate::{sealed::Sealed, Ipa, IpaSlice, Sound};
+
+/// What can be searched for in a transcription: a single sound or a sequence of them.
+///
+/// This trait is sealed; it is implemented for [`Sound`], `[Sound]`, arrays of sounds, [`IpaSlice`] and [`Ipa`].
+pub trait Needle: Sealed {
+    /// Sounds to search for.
+    fn sounds(&self) -> &[Sound];
+}
+
+impl Needle for Sound {
+    fn sounds(&self) -> &[Sound] {
+        slice::from_ref(self)
+    }
+}
+
+impl Needle for [Sound] {
+    fn sounds(&self) -> &[Sound] {
+        self
+    }
+}
+
+impl<const N: usize> Needle for [Sound; N] {
+    fn sounds(&self) -> &[Sound] {
+        self
+    }
+}
+
+impl Needle for IpaSlice {
+    fn sounds(&self) -> &[Sound] {
+        self.as_sounds()
+    }
+}
+
+impl Needle for Ipa {
+    fn sounds(&self) -> &[Sound] {
+        self.as_sounds()
+    }
+}
+
+impl IpaSlice {
+    /// Index of the first occurrence of the needle; an empty needle is found at 0.
+    ///
+    /// Unlike searching in the formatted string, this never matches part of a sound, e.g. "n" in "nʲ".
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ipa_sounds::Ipa;
+    ///
+    /// let ipa = Ipa::try_from("nʲan na").unwrap();
+    /// let n = Ipa::try_from("n").unwrap();
+    /// assert_eq!(ipa.find(&n), Some(2));
+    /// assert_eq!(ipa.rfind(&n), Some(4));
+    /// assert_eq!(ipa.find(&Ipa::try_from("an").unwrap()), Some(1));
+    /// assert!(ipa.contains(&n[0]) && !ipa.contains(&Ipa::try_from("nn").unwrap()));
+    /// assert!(ipa.starts_with(&Ipa::try_from("nʲ").unwrap()) && ipa.ends_with(&ipa[5]));
+    /// ```
+    pub fn find<N: Needle + ?Sized>(&self, needle: &N) -> Option<usize> {
+        self.occurrences(needle).next()
+    }
+
+    /// Index of the last occurrence of the needle; an empty needle is found at the end.
+    pub fn rfind<N: Needle + ?Sized>(&self, needle: &N) -> Option<usize> {
+        let needle = needle.sounds();
+        (0..=self.len().checked_sub(needle.len())?).rev().find(|&i| self[i..].starts_with(needle))
+    }
+
+    /// Whether the needle occurs in the transcription.
+    pub fn contains<N: Needle + ?Sized>(&self, needle: &N) -> bool {
+        self.find(needle).is_some()
+    }
+
+    /// Whether the transcription starts with the needle.
+    pub fn starts_with<N: Needle + ?Sized>(&self, needle: &N) -> bool {
+        self.as_sounds().starts_with(needle.sounds())
+    }
+
+    /// Whether the transcription ends with the needle.
+    pub fn ends_with<N: Needle + ?Sized>(&self, needle: &N) -> bool {
+        self.as_sounds().ends_with(needle.sounds())
+    }
+
+    /// Iterator over the indices of non-overlapping occurrences of the needle, from left to right.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let ipa = ipa_sounds::Ipa::try_from("aaaa").unwrap();
+    /// assert_eq!(ipa.occurrences(&ipa[..2]).collect::<Vec<_>>(), [0, 2]);
+    /// assert_eq!(ipa.occurrences(&ipa[..0]).count(), 5);
+    /// ```
+    pub fn occurrences<'a, N: Needle + ?Sized>(&'a self, needle: &'a N) -> impl Iterator<Item = usize> + 'a {
+        let needle = needle.sounds();
+        let mut start = 0;
+        core::iter::from_fn(move || {
+            let index = (start..=self.len().checked_sub(needle.len())?).find(|&i| self[i..].starts_with(needle))?;
+            start = index + needle.len().max(1);
+            Some(index)
+        })
+    }
+}
+
+#[cfg(test)]
+mod search_tests {
+    use super::*;
+
+    #[test]
+    fn test_find() {
+        let ipa = Ipa::try_from("ˈmʲama mʲ").unwrap();
+        let [mark, palatalized, a, m] = [0, 1, 2, 3].map(|i| ipa[i]);
+        assert_eq!(ipa.occurrences(&palatalized).collect::<Vec<_>>(), [1, 6]);
+        assert_eq!(ipa.find(&[m, a]), Some(3));
+        assert_eq!(ipa.rfind(&[a, m]), Some(2));
+        assert_eq!(ipa.find(&[mark, m]), None);
+        assert!(ipa.starts_with(&mark) && ipa.ends_with(&ipa[6..]));
+        assert_eq!(ipa[..1].find(&ipa[..2]), None);
+        assert_eq!(ipa[..1].rfind(&ipa[..2]), None);
+        assert_eq!(ipa.rfind(&[]), Some(ipa.len()));
+    }
+}
@@ -0,0 +1,136 @@
+//! Serde support.
+//!
+//! `Ipa` is serialized as its IPA string ("nʲæ"); use [`structured`] to serialize it as a sequence of sounds.
+
+use core::fmt;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use crate::Ipa;
+
+impl Serialize for Ipa {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+struct IpaVisitor;
+
+impl de::Visitor<'_> for IpaVisitor {
+    type Value = Ipa;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "a string of IPA sounds")
+    }
+
+    fn visit_str<E: de::Error>(self, ipa: &str) -> Result<Self::Value, E> {
+        Ipa::try_from(ipa).map_err(E::custom)
+    }
+}
+
+impl<'de> Deserialize<'de> for Ipa {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(IpaVisitor)
+    }
+}
+
+/// Structured (per-sound) representation of `Ipa`, for use with `#[serde(with = "ipa_sounds::structured")]`.
+///
+/// The sounds are serialized along with the [`Notation`](crate::Notation).
+///
+/// # Examples
+///
+/// ```
+/// use ipa_sounds::Ipa;
+///
+/// #[derive(serde::Serialize)]
+/// struct Entry {
+///     #[serde(with = "ipa_sounds::structured")]
+///     ipa: Ipa
+/// }
+///
+/// let entry = Entry { ipa: Ipa::try_from("/æ/").unwrap() };
+/// assert_eq!(
+///     serde_json::to_string(&entry).unwrap(),
+///     r#"{"ipa":{"sounds":[{"Vowel":{"phoneme":"NearOpenFrontUrounded","is_long":false,"is_reduced":false}}],"notation":"Phonemic"}}"#
+/// );
+/// ```
+pub mod structured {
+    use alloc::vec::Vec;
+    use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+    use crate::{Ipa, Notation, Sound};
+
+    #[derive(Serialize)]
+    struct StructuredRef<'a> {
+        sounds: &'a [Sound],
+        notation: Option<Notation>
+    }
+
+    #[derive(Deserialize)]
+    struct Structured {
+        sounds: Vec<Sound>,
+        notation: Option<Notation>
+    }
+
+    /// Serializes `Ipa` as its sequence of sounds and notation.
+    pub fn serialize<S: Serializer>(ipa: &Ipa, serializer: S) -> Result<S::Ok, S::Error> {
+        StructuredRef { sounds: &ipa.0, notation: ipa.1 }.serialize(serializer)
+    }
+
+    /// Deserializes `Ipa` from a sequence of sounds and notation, rejecting the sounds
+    /// [`Ipa::from_sounds`] rejects.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Ipa, D::Error> {
+        let Structured { sounds, notation } = Structured::deserialize(deserializer)?;
+        let mut ipa = Ipa::from_sounds(sounds, false).map_err(de::Error::custom)?;
+        ipa.set_notation(notation);
+        Ok(ipa)
+    }
+}
+
+#[cfg(test)]
+mod serialization_tests {
+    use super::*;
+    use crate::Error;
+
+    #[test]
+    fn test_string_round_trip() {
+        let ipa = Ipa::try_from("nʲæ nʲæn").unwrap();
+        let json = serde_json::to_string(&ipa).unwrap();
+        assert_eq!(json, r#""nʲæ nʲæn""#);
+        assert_eq!(serde_json::from_str::<Ipa>(&json).unwrap(), ipa);
+        assert!(serde_json::from_str::<Ipa>(r#""æʲ""#).is_err());
+    }
+
+    #[test]
+    fn test_structured_round_trip() {
+        #[derive(serde::Serialize, serde::Deserialize)]
+        struct Entry {
+            #[serde(with = "structured")]
+            ipa: Ipa
+        }
+
+        let ipa = Ipa::try_from("[ˈnʲæn]").unwrap();
+        let json = serde_json::to_string(&Entry { ipa: ipa.clone() }).unwrap();
+        let entry: Entry = serde_json::from_str(&json).unwrap();
+        assert_eq!(entry.ipa, ipa);
+        assert_eq!(entry.ipa.notation(), ipa.notation());
+        let tones = r#"{"ipa":{"sounds":[{"Tone":{"contour":{"levels":[5,0,0]}}},{"Tone":{"contour":{"levels":[1,0,0]}}}],"notation":null}}"#;
+        let error = serde_json::from_str::<Entry>(tones).err().unwrap();
+        assert!(error.to_string().starts_with("Tone at 1 is read as part of the tone before it"));
+    }
+
+    #[test]
+    fn test_frequency_table() {
+        let table: crate::FrequencyTable<char> = "abca".chars().collect();
+        let json = serde_json::to_string(&table).unwrap();
+        assert_eq!(json, r#"{"counts":[["a",2],["b",1],["c",1]],"total":4}"#);
+        let read: crate::FrequencyTable<char> = serde_json::from_str(&json).unwrap();
+        assert_eq!(read, table);
+        assert_eq!(read.count(&'a'), 2);
+    }
+
+    #[test]
+    fn test_error() {
+        let json = serde_json::to_string(&Error::PalatalizedVowel('æ')).unwrap();
+        assert_eq!(json, r#"{"PalatalizedVowel":"æ"}"#);
+        assert_eq!(serde_json::from_str::<Error>(&json).unwrap(), Error::PalatalizedVowel('æ'));
+    }
+}
@@ -0,0 +1,176 @@
+//! Borrowed view of a sequence of IPA sounds.
+
+use alloc::{borrow::{Borrow, ToOwned}, string::String};
+use core::{fmt, ops::{self, Deref}};
+use crate::{format::write_sound, names::write_name, FormatOptions, Ipa, Sound};
+
+/// Borrowed sequence of IPA sounds, to `Ipa` what `str` is to `String`.
+///
+/// # Examples
+///
+/// ```
+/// use ipa_sounds::{Ipa, IpaSlice};
+///
+/// fn first_word(ipa: &IpaSlice) -> &IpaSlice {
+///     ipa.words().next().unwrap_or(&ipa[..0])
+/// }
+///
+/// let ipa = Ipa::try_from("nʲæ nʲæn").unwrap();
+/// assert_eq!(format!("{}", first_word(&ipa)), "nʲæ");
+/// assert_eq!(format!("{}", &ipa[4..]), "æn");
+/// ```
+#[derive(Debug, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct IpaSlice([Sound]);
+
+impl IpaSlice {
+    /// Wraps a slice of sounds.
+    pub fn new(sounds: &[Sound]) -> &IpaSlice {
+        // SAFETY: `IpaSlice` is a `#[repr(transparent)]` wrapper around `[Sound]`.
+        unsafe { &*(sounds as *const [Sound] as *const IpaSlice) }
+    }
+
+    /// Underlying slice of sounds.
+    pub fn as_sounds(&self) -> &[Sound] {
+        &self.0
+    }
+
+    /// Iterator over words: runs of sounds separated by spaces, skipping empty ones.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let ipa = ipa_sounds::Ipa::try_from(" nʲæ  nʲæn").unwrap();
+    /// assert_eq!(ipa.words().map(|word| word.len()).collect::<Vec<_>>(), [2, 3]);
+    /// ```
+    pub fn words(&self) -> impl Iterator<Item = &IpaSlice> + '_ {
+        self.0.split(|&sound| sound == Sound::Space)
+            .filter(|word| !word.is_empty())
+            .map(IpaSlice::new)
+    }
+
+    /// Number of words.
+    pub fn word_count(&self) -> usize {
+        self.words().count()
+    }
+
+    /// Writes the sounds as IPA without intermediate allocations, like `Display` but to any writer.
+    pub fn write_to(&self, writer: &mut impl fmt::Write) -> fmt::Result {
+        self.write_with(writer, &FormatOptions::default())
+    }
+
+    /// Writes the sounds with the given options.
+    pub fn write_with(&self, writer: &mut impl fmt::Write, options: &FormatOptions) -> fmt::Result {
+        self.0.iter().try_for_each(|&sound| write_sound(writer, sound, options))
+    }
+
+    /// Formats the sounds with the given options.
+    pub fn to_string_with(&self, options: &FormatOptions) -> String {
+        let mut string = String::with_capacity(self.0.len());
+        self.write_with(&mut string, options)
+            .expect("writing to a String cannot fail");
+        string
+    }
+}
+
+impl Deref for IpaSlice {
+    type Target = [Sound];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+macro_rules! impl_index {
+    ($($range:ty),*) => {$(
+        impl ops::Index<$range> for IpaSlice {
+            type Output = IpaSlice;
+
+            fn index(&self, range: $range) -> &Self::Output {
+                IpaSlice::new(&self.0[range])
+            }
+        }
+    )*};
+}
+
+impl_index!(
+    ops::Range<usize>,
+    ops::RangeFrom<usize>,
+    ops::RangeFull,
+    ops::RangeInclusive<usize>,
+    ops::RangeTo<usize>,
+    ops::RangeToInclusive<usize>
+);
+
+impl ops::Index<usize> for IpaSlice {
+    type Output = Sound;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
+impl<'a> IntoIterator for &'a IpaSlice {
+    type Item = &'a Sound;
+    type IntoIter = core::slice::Iter<'a, Sound>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl Borrow<IpaSlice> for Ipa {
+    fn borrow(&self) -> &IpaSlice {
+        self
+    }
+}
+
+impl ToOwned for IpaSlice {
+    type Owned = Ipa;
+
+    fn to_owned(&self) -> Self::Owned {
+        Ipa(self.0.to_vec(), None)
+    }
+}
+
+impl AsRef<IpaSlice> for Ipa {
+    fn as_ref(&self) -> &IpaSlice {
+        self
+    }
+}
+
+impl<'a> From<&'a [Sound]> for &'a IpaSlice {
+    fn from(sounds: &'a [Sound]) -> Self {
+        IpaSlice::new(sounds)
+    }
+}
+
+/// Writes the sounds as IPA, or with `{:#}` their descriptive names separated by commas.
+impl fmt::Display for IpaSlice {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !formatter.alternate() {
+            return self.write_to(formatter);
+        }
+        self.0.iter().enumerate().try_for_each(|(i, &sound)| {
+            if i > 0 {
+                formatter.write_str(", ")?;
+            }
+            write_name(formatter, sound)
+        })
+    }
+}
+
+#[cfg(test)]
+mod slice_tests {
+    use super::*;
+
+    #[test]
+    fn test_sub_slices() {
+        let ipa = Ipa::try_from("nʲæ nʲæn").unwrap();
+        let words: Vec<_> = ipa.words().collect();
+        assert_eq!(words[1].to_owned(), Ipa::try_from("nʲæn").unwrap());
+        assert_eq!(format!("{}", &words[1][1..]), "æn");
+        assert_eq!(ipa[0], words[0][0]);
+        assert_eq!(&ipa[..], IpaSlice::new(&ipa));
+    }
+}
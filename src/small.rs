@@ -0,0 +1,120 @@
+//! Sequences of sounds stored inline up to a small length.
+
+use core::{fmt, ops::Deref};
+use smallvec::SmallVec;
+use crate::{iter_sounds, Error, Ipa, IpaSlice, Sound};
+
+/// Number of sounds [`SmallIpa`] stores without allocating, enough for most single words.
+const INLINE: usize = 12;
+
+/// Sequence of sounds kept inline up to [`SmallIpa::INLINE`] sounds and on the heap beyond that,
+/// for holding millions of short transcriptions without an allocation each.
+///
+/// It dereferences to [`IpaSlice`], so every slice method is available.
+///
+/// # Examples
+///
+/// ```
+/// use ipa_sounds::{Ipa, SmallIpa};
+///
+/// let word = SmallIpa::try_from("ˈnʲæn").unwrap();
+/// assert!(!word.spilled());
+/// assert_eq!(word.syllabify().len(), 1);
+/// assert_eq!(Ipa::from(word.clone()), Ipa::try_from("ˈnʲæn").unwrap());
+/// let sentence: SmallIpa = Ipa::try_from("ˈnʲæ ˈnʲæn ˈnʲæ ˈnʲæn").unwrap().iter().copied().collect();
+/// assert!(sentence.spilled());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SmallIpa(SmallVec<[Sound; INLINE]>);
+
+impl SmallIpa {
+    /// Number of sounds stored without allocating.
+    pub const INLINE: usize = INLINE;
+
+    /// Creates an empty sequence.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a sound, moving the sounds to the heap if they no longer fit inline.
+    pub fn push(&mut self, sound: Sound) {
+        self.0.push(sound);
+    }
+
+    /// Whether the sounds were moved to the heap.
+    pub fn spilled(&self) -> bool {
+        self.0.spilled()
+    }
+
+    /// Copies the sounds into a new sequence.
+    pub fn to_ipa(&self) -> Ipa {
+        self.iter().copied().collect()
+    }
+}
+
+impl Deref for SmallIpa {
+    type Target = IpaSlice;
+
+    fn deref(&self) -> &Self::Target {
+        IpaSlice::new(&self.0)
+    }
+}
+
+/// Parses with the default options without an intermediate [`Ipa`]; delimiters are stripped.
+impl TryFrom<&str> for SmallIpa {
+    type Error = Error;
+
+    fn try_from(ipa: &str) -> Result<Self, Self::Error> {
+        iter_sounds(ipa).collect()
+    }
+}
+
+impl From<&IpaSlice> for SmallIpa {
+    fn from(ipa: &IpaSlice) -> Self {
+        SmallIpa(SmallVec::from_slice(ipa.as_sounds()))
+    }
+}
+
+impl From<SmallIpa> for Ipa {
+    fn from(small: SmallIpa) -> Self {
+        small.0.into_iter().collect()
+    }
+}
+
+impl FromIterator<Sound> for SmallIpa {
+    fn from_iter<I: IntoIterator<Item = Sound>>(sounds: I) -> Self {
+        SmallIpa(sounds.into_iter().collect())
+    }
+}
+
+impl Extend<Sound> for SmallIpa {
+    fn extend<I: IntoIterator<Item = Sound>>(&mut self, sounds: I) {
+        self.0.extend(sounds)
+    }
+}
+
+impl fmt::Display for SmallIpa {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&**self, formatter)
+    }
+}
+
+#[cfg(test)]
+mod small_tests {
+    use super::*;
+    use crate::corpus;
+
+    #[test]
+    fn test_inline() {
+        for entry in corpus::strings() {
+            let small = SmallIpa::try_from(entry.as_str()).unwrap();
+            assert_eq!(*small, *Ipa::try_from(entry).unwrap());
+            assert_eq!(small.spilled(), small.len() > SmallIpa::INLINE);
+        }
+        let mut small = SmallIpa::new();
+        small.extend(Ipa::try_from("nʲæn").unwrap().iter().copied());
+        small.push(Sound::Space);
+        assert_eq!(small.to_string(), "nʲæn ");
+        assert!(core::mem::size_of::<SmallIpa>() < (SmallIpa::INLINE + 4) * core::mem::size_of::<Sound>());
+    }
+}
@@ -0,0 +1,60 @@
+//! Sonority hierarchy.
+
+use crate::{Height, Manner, Sound, Voicing};
+
+impl Sound {
+    /// Rank on the sonority hierarchy, from 1 for voiceless stops to 10 for open vowels; 0 for a space or a stress mark.
+    ///
+    /// | Sounds | Sonority |
+    /// |---|---|
+    /// | voiceless, voiced stops | 1, 2 |
+    /// | voiceless, voiced fricatives | 3, 4 |
+    /// | nasals | 5 |
+    /// | liquids (laterals, trills, taps) | 6 |
+    /// | glides | 7 |
+    /// | close, mid, open vowels | 8, 9, 10 |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let ipa = ipa_sounds::Ipa::try_from("pmjia").unwrap();
+    /// assert_eq!(ipa.iter().map(|sound| sound.sonority()).collect::<Vec<_>>(), [1, 5, 7, 8, 10]);
+    /// ```
+    pub const fn sonority(&self) -> u8 {
+        match *self {
+            Sound::Vowel { phoneme, .. } => match phoneme.height() {
+                Height::Close | Height::NearClose => 8,
+                Height::CloseMid | Height::Mid | Height::OpenMid => 9,
+                Height::NearOpen | Height::Open => 10
+            },
+            Sound::Consonant { phoneme, .. } => {
+                let voiced = matches!(phoneme.voicing(), Voicing::Voiced) as u8;
+                match phoneme.manner() {
+                    Manner::Plosive => 1 + voiced,
+                    Manner::Fricative | Manner::LateralFricative => 3 + voiced,
+                    Manner::Nasal => 5,
+                    Manner::Trill | Manner::Tap | Manner::LateralApproximant => 6,
+                    Manner::Approximant => 7
+                }
+            }
+            Sound::Space | Sound::Stress { .. } | Sound::Tone { .. } => 0
+        }
+    }
+}
+
+#[cfg(test)]
+mod sonority_tests {
+    use crate::{Consonants, Sound, Vowels};
+
+    #[test]
+    fn test_vowels_above_consonants() {
+        let least_sonorous_vowel = Vowels::ALL.iter()
+            .map(|&phoneme| Sound::Vowel { phoneme, is_long: false, is_reduced: false }.sonority())
+            .min();
+        let most_sonorous_consonant = Consonants::ALL.iter()
+            .map(|&phoneme| Sound::Consonant { phoneme, is_long: false, is_palatalized: false, is_reduced: false, release: None }.sonority())
+            .max();
+        assert!(least_sonorous_vowel > most_sonorous_consonant);
+        assert_eq!(Sound::Space.sonority(), 0);
+    }
+}
@@ -0,0 +1,51 @@
+//! SSML phoneme markup, for the text-to-speech engines of cloud providers.
+
+use alloc::{format, string::{String, ToString}};
+use crate::IpaSlice;
+
+/// Escapes the characters with a meaning in XML text and attribute values.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for symbol in text.chars() {
+        match symbol {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            symbol => escaped.push(symbol)
+        }
+    }
+    escaped
+}
+
+impl IpaSlice {
+    /// Writes an SSML `phoneme` element pronouncing the text as the sounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let ipa = ipa_sounds::Ipa::try_from("təˈmɑːtoʊ").unwrap();
+    /// assert_eq!(
+    ///     ipa.to_ssml_phoneme("tomato"),
+    ///     r#"<phoneme alphabet="ipa" ph="təˈmɑːtoʊ">tomato</phoneme>"#
+    /// );
+    /// ```
+    pub fn to_ssml_phoneme(&self, text: &str) -> String {
+        format!(r#"<phoneme alphabet="ipa" ph="{}">{}</phoneme>"#, escape(&self.to_string()), escape(text))
+    }
+}
+
+#[cfg(test)]
+mod ssml_tests {
+    use crate::Ipa;
+
+    #[test]
+    fn test_escaping() {
+        let ipa = Ipa::try_from("ˈtɒm ən ˈdʒɛɹi").unwrap();
+        assert_eq!(
+            ipa.to_ssml_phoneme("Tom & \"Jerry\" <3"),
+            r#"<phoneme alphabet="ipa" ph="ˈtɒm ən ˈdʒɛɹi">Tom &amp; &quot;Jerry&quot; &lt;3</phoneme>"#
+        );
+    }
+}
@@ -0,0 +1,214 @@
+//! Frequency statistics of sounds, bigrams and syllable shapes.
+
+use alloc::{collections::BTreeMap, vec::Vec};
+use crate::{IpaSlice, Sound, SyllableShape};
+
+/// Numbers of occurrences of values, in order of their first occurrence.
+///
+/// Values are looked up in a `BTreeMap`, so counting takes logarithmic time in the number of distinct
+/// values.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(
+    from = "Counts<T>",
+    into = "Counts<T>",
+    bound(serialize = "T: Clone + serde::Serialize", deserialize = "T: Ord + Clone + serde::Deserialize<'de>")
+))]
+pub struct FrequencyTable<T> {
+    counts: Vec<(T, usize)>,
+    /// Index of every value in `counts`.
+    positions: BTreeMap<T, usize>,
+    total: usize
+}
+
+/// Serialized form of a [`FrequencyTable`], without the index.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct Counts<T> {
+    counts: Vec<(T, usize)>,
+    total: usize
+}
+
+#[cfg(feature = "serde")]
+impl<T> From<FrequencyTable<T>> for Counts<T> {
+    fn from(table: FrequencyTable<T>) -> Self {
+        Counts { counts: table.counts, total: table.total }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: Ord + Clone> From<Counts<T>> for FrequencyTable<T> {
+    fn from(counts: Counts<T>) -> Self {
+        let mut table = Self::new();
+        counts.counts.into_iter().for_each(|(value, count)| table.add_count(value, count));
+        table
+    }
+}
+
+impl<T> Default for FrequencyTable<T> {
+    fn default() -> Self {
+        Self { counts: Vec::new(), positions: BTreeMap::new(), total: 0 }
+    }
+}
+
+impl<T: Ord + Clone> FrequencyTable<T> {
+    /// Creates an empty table.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Counts one more occurrence of the value.
+    pub fn add(&mut self, value: T) {
+        self.add_count(value, 1);
+    }
+
+    /// Counts occurrences of the value.
+    fn add_count(&mut self, value: T, count: usize) {
+        match self.positions.get(&value) {
+            Some(&i) => self.counts[i].1 += count,
+            None => {
+                self.positions.insert(value.clone(), self.counts.len());
+                self.counts.push((value, count));
+            }
+        }
+        self.total += count;
+    }
+
+    /// Number of occurrences of the value.
+    pub fn count(&self, value: &T) -> usize {
+        self.positions.get(value).map_or(0, |&i| self.counts[i].1)
+    }
+
+    /// Share of the occurrences of the value among all occurrences, 0.0 for an empty table.
+    pub fn frequency(&self, value: &T) -> f64 {
+        if self.total == 0 {
+            0.0
+        } else {
+            self.count(value) as f64 / self.total as f64
+        }
+    }
+
+    /// Number of occurrences of all values.
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    /// Number of distinct values.
+    pub fn len(&self) -> usize {
+        self.counts.len()
+    }
+
+    /// Whether nothing was counted.
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    /// Iterator over the values with their counts, in order of first occurrence.
+    pub fn iter(&self) -> impl Iterator<Item = (&T, usize)> + '_ {
+        self.counts.iter().map(|(value, count)| (value, *count))
+    }
+
+    /// Values with their counts from the most to the least common; equally common values keep their order.
+    pub fn most_common(&self) -> Vec<(&T, usize)> {
+        let mut counts: Vec<_> = self.iter().collect();
+        counts.sort_by(|(_, count), (_, other)| other.cmp(count));
+        counts
+    }
+}
+
+impl<T: Ord + Clone> Extend<T> for FrequencyTable<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, values: I) {
+        values.into_iter().for_each(|value| self.add(value));
+    }
+}
+
+impl<T: Ord + Clone> FromIterator<T> for FrequencyTable<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(values: I) -> Self {
+        let mut table = Self::new();
+        table.extend(values);
+        table
+    }
+}
+
+/// Frequencies accumulated over a corpus of transcriptions.
+///
+/// Only vowels and consonants are counted; bigrams are pairs of them adjacent in a word, ignoring
+/// stress marks, and syllable shapes come from [`IpaSlice::syllabify`].
+///
+/// # Examples
+///
+/// ```
+/// use ipa_sounds::{Ipa, Statistics, SyllableShape};
+///
+/// let mut statistics = Statistics::new();
+/// for text in ["ˈmama", "ˈpapa mam"] {
+///     statistics.add(&Ipa::try_from(text).unwrap());
+/// }
+/// let sounds = Ipa::try_from("ma").unwrap();
+/// assert_eq!(statistics.sounds.count(&sounds[0]), 4);
+/// assert_eq!(statistics.sounds.frequency(&sounds[1]), 5.0 / 11.0);
+/// assert_eq!(statistics.bigrams.count(&(sounds[0], sounds[1])), 3);
+/// assert_eq!(statistics.shapes.most_common()[0], (&SyllableShape::new(1, 1, 0), 4));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Statistics {
+    pub sounds: FrequencyTable<Sound>,
+    pub bigrams: FrequencyTable<(Sound, Sound)>,
+    pub shapes: FrequencyTable<SyllableShape>
+}
+
+impl Statistics {
+    /// Creates statistics of an empty corpus.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the sounds, bigrams and syllable shapes of a transcription.
+    pub fn add(&mut self, ipa: &IpaSlice) {
+        self.sounds.extend(segments(ipa));
+        for word in ipa.words() {
+            let segments: Vec<Sound> = segments(word).collect();
+            self.bigrams.extend(segments.windows(2).map(|pair| (pair[0], pair[1])));
+        }
+        self.shapes.extend(ipa.syllabify().iter().flatten().map(SyllableShape::of));
+    }
+}
+
+impl IpaSlice {
+    /// Numbers of occurrences of the vowels and consonants.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let ipa = ipa_sounds::Ipa::try_from("ˈmama pa").unwrap();
+    /// let counts = ipa.phoneme_counts();
+    /// assert_eq!(counts.most_common()[0], (&ipa[2], 3));
+    /// assert_eq!(counts.total(), 6);
+    /// ```
+    pub fn phoneme_counts(&self) -> FrequencyTable<Sound> {
+        segments(self).collect()
+    }
+}
+
+/// Vowels and consonants of the transcription, in order.
+fn segments(ipa: &IpaSlice) -> impl Iterator<Item = Sound> + '_ {
+    ipa.iter().copied().filter(|sound| matches!(sound, Sound::Vowel { .. } | Sound::Consonant { .. }))
+}
+
+#[cfg(test)]
+mod statistics_tests {
+    use super::*;
+    use crate::Ipa;
+
+    #[test]
+    fn test_statistics() {
+        let ipa = Ipa::try_from("aˈpa ap").unwrap();
+        let mut statistics = Statistics::new();
+        statistics.add(&ipa);
+        let [a, p] = [ipa[0], ipa[2]];
+        assert_eq!(statistics.bigrams.iter().collect::<Vec<_>>(), [(&(a, p), 2), (&(p, a), 1)]);
+        assert_eq!(statistics.shapes.iter().map(|(shape, _)| shape.to_string()).collect::<Vec<_>>(), ["V", "CV", "VC"]);
+        assert_eq!(FrequencyTable::<Sound>::new().frequency(&ipa[0]), 0.0);
+    }
+}
@@ -0,0 +1,119 @@
+//! Proptest strategies for property-based testing of code consuming IPA.
+
+use proptest::{prelude::*, sample::{select, Index}};
+use crate::{corpus, Ipa, Sound, Vowels};
+
+/// Symbols the parser rejects, used to inject errors.
+const UNRECOGNIZED: &[char] = &['þ', 'ʘ', ':', 'á', '3', '!'];
+
+/// Any sound the parser can produce, including spaces, stress marks and tones.
+pub fn sound() -> impl Strategy<Value = Sound> {
+    prop_oneof![
+        8 => select(corpus::sounds().collect::<Vec<_>>()),
+        2 => Just(Sound::Space),
+        1 => any::<bool>().prop_map(|is_primary| Sound::Stress { is_primary })
+    ]
+}
+
+/// Valid sequences of up to 32 sounds, in the canonical form of [`Ipa::canonicalize`].
+///
+/// # Examples
+///
+/// ```
+/// use ipa_sounds::{strategies, Ipa};
+/// use proptest::prelude::*;
+///
+/// proptest!(|(ipa in strategies::ipa())| {
+///     prop_assert_eq!(Ipa::try_from(ipa.to_string()), Ok(ipa));
+/// });
+/// ```
+pub fn ipa() -> impl Strategy<Value = Ipa> {
+    prop::collection::vec(sound(), 0..32).prop_map(|sounds| {
+        let mut ipa = Ipa(sounds, None);
+        ipa.canonicalize();
+        ipa
+    })
+}
+
+/// Sequences of up to 32 sounds taken only from the given inventory.
+///
+/// # Panics
+///
+/// Panics if the inventory is empty.
+pub fn ipa_from_inventory(inventory: &[Sound]) -> impl Strategy<Value = Ipa> {
+    prop::collection::vec(select(inventory.to_vec()), 0..32).prop_map(Ipa::from_sounds_unchecked)
+}
+
+/// Strings that are valid IPA except for one injected error: an unrecognized symbol
+/// or a palatalized vowel.
+pub fn almost_valid() -> impl Strategy<Value = String> {
+    let injection = prop_oneof![
+        select(UNRECOGNIZED).prop_map(String::from),
+        select(Vowels::ALL).prop_map(|vowel| format!("{}ʲ", char::from(vowel)))
+    ];
+    (ipa(), any::<Index>(), injection).prop_map(|(ipa, index, injection)| {
+        let mut ipa = ipa.to_string();
+        let boundaries: Vec<_> = ipa.char_indices()
+            .map(|(i, _)| i)
+            .chain([ipa.len()])
+            .collect();
+        ipa.insert_str(*index.get(&boundaries), &injection);
+        ipa
+    })
+}
+
+#[cfg(test)]
+mod strategies_tests {
+    use super::*;
+    use crate::{Consonants, Release};
+
+    /// Any value of `Sound`, including reductions no symbol can write.
+    fn any_sound() -> impl Strategy<Value = Sound> {
+        prop_oneof![
+            (select(Vowels::ALL), any::<(bool, bool)>())
+                .prop_map(|(phoneme, (is_long, is_reduced))| Sound::Vowel { phoneme, is_long, is_reduced }),
+            (select(Consonants::ALL), any::<(bool, bool, bool)>(), prop::option::of(select(&Release::ALL[..])))
+                .prop_map(|(phoneme, (is_long, is_palatalized, is_reduced), release)| {
+                    Sound::Consonant { phoneme, is_long, is_palatalized, is_reduced, release }
+                }),
+            Just(Sound::Space),
+            any::<bool>().prop_map(|is_primary| Sound::Stress { is_primary }),
+            prop::collection::vec(1..=5u8, 1..=3)
+                .prop_map(|levels| Sound::Tone { contour: crate::Tone::new(&levels).unwrap() })
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn test_round_trip(ipa in ipa()) {
+            prop_assert_eq!(Ipa::try_from(ipa.to_string()), Ok(ipa));
+        }
+
+        #[test]
+        fn test_inventory(ipa in ipa_from_inventory(&[Sound::Space])) {
+            prop_assert!(ipa.iter().all(|&sound| sound == Sound::Space));
+        }
+
+        #[test]
+        fn test_canonicalize(sounds in prop::collection::vec(any_sound(), 0..32)) {
+            let mut ipa = Ipa(sounds, None);
+            ipa.canonicalize();
+            prop_assert_eq!(Ipa::try_from(ipa.to_string()), Ok(ipa.clone()));
+            let mut again = ipa.clone();
+            again.canonicalize();
+            prop_assert_eq!(again, ipa);
+        }
+
+        #[test]
+        fn test_from_sounds(sounds in prop::collection::vec(prop_oneof![3 => sound(), 1 => any_sound()], 0..16)) {
+            if let Ok(ipa) = Ipa::from_sounds(sounds, false) {
+                prop_assert_eq!(Ipa::try_from(ipa.to_string()), Ok(ipa));
+            }
+        }
+
+        #[test]
+        fn test_almost_valid(ipa in almost_valid()) {
+            prop_assert!(Ipa::try_from(ipa).is_err());
+        }
+    }
+}
@@ -0,0 +1,141 @@
+//! "Did you mean" suggestions for unrecognized symbols.
+
+use core::fmt;
+
+/// Suggested replacement for a symbol that is not recognized as IPA.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Suggestion {
+    /// IPA to use instead of the unrecognized symbol.
+    pub replacement: &'static str,
+    /// Why the replacement is suggested.
+    pub reason: &'static str,
+}
+
+impl fmt::Display for Suggestion {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "did you mean '{}'? ({})", self.replacement, self.reason)
+    }
+}
+
+/// Symbols commonly typed instead of IPA ones.
+const CONFUSABLES: &[(char, &str, &str)] = &[
+    (':', "ː", "the length mark is 'ː', not a colon"),
+    ('\'', "ʲ", "palatalization is marked with 'ʲ'"),
+    ('ʹ', "ʲ", "palatalization is marked with 'ʲ'"),
+    ('ь', "ʲ", "palatalization is marked with 'ʲ'"),
+    ('ǝ', "ə", "mid central vowel is 'ə', not turned 'e'"),
+    ('g', "ɡ", "voiced velar plosive is 'ɡ' with an open tail"),
+    ('?', "ʔ", "the glottal stop is 'ʔ', not a question mark"),
+    ('ε', "ɛ", "Greek epsilon looks like 'ɛ'"),
+    ('α', "ɑ", "Greek alpha looks like 'ɑ'"),
+    ('ι', "ɪ", "Greek iota looks like 'ɪ'"),
+    ('ɩ', "ɪ", "Latin iota looks like 'ɪ'"),
+    ('υ', "ʊ", "Greek upsilon looks like 'ʊ'"),
+    ('ö', "ø", "close-mid front rounded vowel is 'ø'"),
+    ('ü', "y", "close front rounded vowel is 'y'"),
+    ('а', "a", "Cyrillic letter looks like Latin 'a'"),
+    ('е', "e", "Cyrillic letter looks like Latin 'e'"),
+    ('һ', "h", "Cyrillic letter looks like Latin 'h'"),
+    ('і', "i", "Cyrillic letter looks like Latin 'i'"),
+    ('ј', "j", "Cyrillic letter looks like Latin 'j'"),
+    ('о', "o", "Cyrillic letter looks like Latin 'o'"),
+    ('р', "p", "Cyrillic letter looks like Latin 'p'"),
+    ('у', "y", "Cyrillic letter looks like Latin 'y'"),
+];
+
+/// Precomposed Latin vowels with tone diacritics (acute, grave, circumflex, caron) and the vowels
+/// with tone letters of the same contour.
+const TONE_MARKED: &[(char, &str)] = &[
+    ('á', "a˥"), ('à', "a˩"), ('â', "a˥˩"), ('ǎ', "a˩˥"),
+    ('é', "e˥"), ('è', "e˩"), ('ê', "e˥˩"), ('ě', "e˩˥"),
+    ('í', "i˥"), ('ì', "i˩"), ('î', "i˥˩"), ('ǐ', "i˩˥"),
+    ('ó', "o˥"), ('ò', "o˩"), ('ô', "o˥˩"), ('ǒ', "o˩˥"),
+    ('ú', "u˥"), ('ù', "u˩"), ('û', "u˥˩"), ('ǔ', "u˩˥"),
+    ('ý', "y˥"), ('ỳ', "y˩"), ('ŷ', "y˥˩"),
+];
+
+/// Tone numbers, read only with ASCII substitutes, and their tone letters.
+const TONE_NUMBERS: &[(char, &str)] = &[('1', "˩"), ('2', "˨"), ('3', "˧"), ('4', "˦"), ('5', "˥")];
+
+/// Precomposed Latin vowels with macron, which is often used for length.
+const MACRON: &[(char, &str)] = &[
+    ('ā', "aː"),
+    ('ē', "eː"),
+    ('ī', "iː"),
+    ('ō', "oː"),
+    ('ū', "uː"),
+    ('ȳ', "yː"),
+];
+
+/// Looks for a supported IPA symbol that was probably meant instead of `symbol`.
+pub(crate) fn suggest(symbol: char) -> Option<Suggestion> {
+    CONFUSABLES.iter()
+        .find(|&&(confusable, _, _)| confusable == symbol)
+        .map(|&(_, replacement, reason)| Suggestion { replacement, reason })
+        .or_else(|| TONE_MARKED.iter()
+            .find(|&&(marked, _)| marked == symbol)
+            .map(|&(_, replacement)| Suggestion {
+                replacement,
+                reason: "tones are written with tone letters after the vowel"
+            })
+        )
+        .or_else(|| TONE_NUMBERS.iter()
+            .find(|&&(number, _)| number == symbol)
+            .map(|&(_, replacement)| Suggestion {
+                replacement,
+                reason: "tone numbers are only read with ASCII substitutes"
+            })
+        )
+        .or_else(|| MACRON.iter()
+            .find(|&&(long, _)| long == symbol)
+            .map(|&(_, replacement)| Suggestion {
+                replacement,
+                reason: "length is marked with 'ː', not a macron"
+            })
+        )
+}
+
+#[cfg(test)]
+mod suggestion_tests {
+    use super::*;
+    use crate::{Error, Ipa};
+
+    #[test]
+    fn test_colon() {
+        assert_eq!(
+            Ipa::try_from("aː:").unwrap_err().suggestion(),
+            Some(Suggestion {
+                replacement: "ː",
+                reason: "the length mark is 'ː', not a colon"
+            })
+        );
+    }
+
+    #[test]
+    fn test_tone_marked() {
+        assert_eq!(
+            Error::NotYetImplemented('á').suggestion().map(|s| s.replacement),
+            Some("a˥")
+        );
+        assert_eq!(Error::NotYetImplemented('ǒ').suggestion().map(|s| s.replacement), Some("o˩˥"));
+        assert_eq!(
+            Ipa::try_from("ma3").unwrap_err().to_string(),
+            "'3' is a tone number; did you mean '˧'? (tone numbers are only read with ASCII substitutes)"
+        );
+    }
+
+    #[test]
+    fn test_replacements_are_valid() {
+        CONFUSABLES.iter().map(|&(_, replacement, _)| replacement)
+            .chain(TONE_MARKED.iter().chain(TONE_NUMBERS).map(|&(_, replacement)| replacement))
+            .chain(MACRON.iter().map(|&(_, replacement)| replacement))
+            .for_each(|replacement| {
+                let ipa = if replacement == "ʲ" || replacement == "ː" {
+                    format!("n{}", replacement)
+                } else {
+                    replacement.to_owned()
+                };
+                assert!(Ipa::try_from(ipa.as_str()).is_ok(), "{}", replacement);
+            });
+    }
+}
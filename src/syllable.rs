@@ -0,0 +1,265 @@
+//! Syllables and division of words into them.
+
+use alloc::vec::Vec;
+use core::fmt;
+use crate::{IpaSlice, Sound, Tone, Vowels};
+
+/// Stress of a syllable.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Stress {
+    #[default]
+    Unstressed,
+    Secondary,
+    Primary
+}
+
+impl Stress {
+    /// Stress mark written before the syllable, `None` if it is unstressed.
+    pub const fn mark(self) -> Option<Sound> {
+        match self {
+            Stress::Unstressed => None,
+            Stress::Secondary => Some(Sound::Stress { is_primary: false }),
+            Stress::Primary => Some(Sound::Stress { is_primary: true })
+        }
+    }
+}
+
+/// Enum for possible errors when constructing a syllable.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SyllableError {
+    EmptyNucleus,
+    NucleusWithoutVowel,
+    VowelInMargin(Vowels),
+    NotASegment(Sound)
+}
+
+impl fmt::Display for SyllableError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SyllableError::EmptyNucleus => formatter.write_str("Syllable nucleus is empty"),
+            SyllableError::NucleusWithoutVowel => {
+                formatter.write_str("Syllable nucleus has neither a vowel nor a single syllabic consonant")
+            },
+            SyllableError::VowelInMargin(vowel) => {
+                write!(formatter, "Vowel ({}) cannot be in the onset or coda", vowel)
+            },
+            SyllableError::NotASegment(sound) => {
+                write!(formatter, "{:#} cannot be part of a syllable", sound)
+            }
+        }
+    }
+}
+
+impl core::error::Error for SyllableError {}
+
+/// Syllable: onset and coda consonants around a nucleus of vowels or a single syllabic consonant,
+/// possibly with a tone.
+///
+/// # Examples
+///
+/// ```
+/// use ipa_sounds::{Ipa, Stress, Syllable};
+///
+/// let sounds = Ipa::try_from("pjan").unwrap();
+/// let syllable = Syllable::new(sounds[..2].to_vec(), sounds[2..3].to_vec(), sounds[3..].to_vec(), Stress::Primary)
+///     .unwrap();
+/// assert_eq!(syllable.to_string(), "ˈpjan");
+/// assert!(Syllable::new(Vec::new(), sounds[..1].to_vec(), sounds[2..3].to_vec(), Stress::Unstressed).is_err());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Syllable {
+    pub onset: Vec<Sound>,
+    pub nucleus: Vec<Sound>,
+    pub coda: Vec<Sound>,
+    pub stress: Stress,
+    /// Tone written after the syllable.
+    pub tone: Option<Tone>
+}
+
+impl Syllable {
+    /// Constructs a syllable without a tone, checking it as [`Syllable::validate`] does.
+    pub fn new(onset: Vec<Sound>, nucleus: Vec<Sound>, coda: Vec<Sound>, stress: Stress) -> Result<Self, SyllableError> {
+        let syllable = Syllable { onset, nucleus, coda, stress, tone: None };
+        syllable.validate().map(|()| syllable)
+    }
+
+    /// Checks that the onset and coda consist of consonants and the nucleus of vowels (possibly with glides)
+    /// or of a single syllabic consonant.
+    pub fn validate(&self) -> Result<(), SyllableError> {
+        let margins = self.onset.iter().chain(&self.coda);
+        if let Some(&sound) = margins.clone().chain(&self.nucleus).find(|sound| !is_segment(sound)) {
+            return Err(SyllableError::NotASegment(sound));
+        }
+        if let Some(&Sound::Vowel { phoneme, .. }) = margins.clone().find(|sound| matches!(sound, Sound::Vowel { .. })) {
+            return Err(SyllableError::VowelInMargin(phoneme));
+        }
+        match self.nucleus.as_slice() {
+            [] => Err(SyllableError::EmptyNucleus),
+            [Sound::Consonant { .. }] => Ok(()),
+            nucleus if nucleus.iter().any(|sound| matches!(sound, Sound::Vowel { .. })) => Ok(()),
+            _ => Err(SyllableError::NucleusWithoutVowel)
+        }
+    }
+
+    /// Iterator over the sounds of the syllable, starting with its stress mark if it is stressed and
+    /// ending with its tone if it has one.
+    pub fn sounds(&self) -> impl Iterator<Item = Sound> + '_ {
+        self.stress.mark().into_iter()
+            .chain(self.onset.iter().chain(&self.nucleus).chain(&self.coda).copied())
+            .chain(self.tone.map(|contour| Sound::Tone { contour }))
+    }
+
+    /// Splits a part of a word into a syllable: leading stress marks give the stress, the nucleus is
+    /// the first vowel or, without vowels, the most sonorous consonant, the last tone is the tone, and
+    /// other stress marks and tones are dropped.
+    fn from_sounds(sounds: &[Sound]) -> Option<Self> {
+        let marks = sounds.iter().take_while(|sound| matches!(sound, Sound::Stress { .. })).count();
+        let stress = sounds[..marks].iter()
+            .map(|sound| match sound {
+                Sound::Stress { is_primary: true } => Stress::Primary,
+                _ => Stress::Secondary
+            })
+            .max()
+            .unwrap_or_default();
+        let segments: Vec<Sound> = sounds[marks..].iter().copied().filter(is_segment).collect();
+        let nucleus = segments.iter().position(|sound| matches!(sound, Sound::Vowel { .. }))
+            .or_else(|| (0..segments.len()).rev().max_by_key(|&i| segments[i].sonority()))?;
+        let tone = sounds.iter().rev().find_map(|sound| match *sound {
+            Sound::Tone { contour } => Some(contour),
+            _ => None
+        });
+        Some(Syllable {
+            onset: segments[..nucleus].to_vec(),
+            nucleus: segments[nucleus..=nucleus].to_vec(),
+            coda: segments[nucleus + 1..].to_vec(),
+            stress,
+            tone
+        })
+    }
+}
+
+/// Writes the stress mark and the sounds, e.g. "ˈpjan".
+impl fmt::Display for Syllable {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.sounds().try_for_each(|sound| fmt::Display::fmt(&sound, formatter))
+    }
+}
+
+/// Whether the sound is a vowel or a consonant.
+fn is_segment(sound: &Sound) -> bool {
+    matches!(sound, Sound::Vowel { .. } | Sound::Consonant { .. })
+}
+
+impl IpaSlice {
+    /// Divides every word into syllables by the maximal onset principle.
+    ///
+    /// Every vowel is the nucleus of its own syllable. Consonants between two vowels go to the onset
+    /// of the second syllable as long as their sonority rises towards its nucleus, the rest to the coda
+    /// of the first one; a stress mark between them starts the second syllable instead, and a tone ends
+    /// the first one. A word without
+    /// vowels is a single syllable with its most sonorous consonant as a syllabic nucleus.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let ipa = ipa_sounds::Ipa::try_from("amˈpja nʲæ").unwrap();
+    /// let syllables: Vec<Vec<String>> = ipa.syllabify().iter()
+    ///     .map(|word| word.iter().map(|syllable| syllable.to_string()).collect())
+    ///     .collect();
+    /// assert_eq!(syllables, [vec!["am", "ˈpja"], vec!["nʲæ"]]);
+    /// ```
+    pub fn syllabify(&self) -> Vec<Vec<Syllable>> {
+        self.words()
+            .map(|word| {
+                let starts = syllable_starts(word);
+                starts.iter().enumerate()
+                    .filter_map(|(i, &start)| {
+                        Syllable::from_sounds(&word[start..starts.get(i + 1).copied().unwrap_or(word.len())])
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+/// Indices at which the syllables of a word start; the first one is always 0.
+fn syllable_starts(word: &[Sound]) -> Vec<usize> {
+    let mut starts = Vec::from([0]);
+    let mut nuclei = word.iter().enumerate()
+        .filter(|(_, sound)| matches!(sound, Sound::Vowel { .. }))
+        .map(|(i, _)| i);
+    let Some(mut previous) = nuclei.next() else {
+        return starts;
+    };
+    for nucleus in nuclei {
+        let cluster = &word[previous + 1..nucleus];
+        let start = match cluster.iter().rposition(|sound| matches!(sound, Sound::Stress { .. })) {
+            Some(mark) => previous + 1 + mark,
+            None => {
+                let first = cluster.iter().rposition(|sound| matches!(sound, Sound::Tone { .. }))
+                    .map_or(previous + 1, |tone| previous + 2 + tone);
+                let mut start = nucleus;
+                while start > first && word[start - 1].sonority() < word[start].sonority() {
+                    start -= 1;
+                }
+                start
+            }
+        };
+        starts.push(start);
+        previous = nucleus;
+    }
+    starts
+}
+
+#[cfg(test)]
+mod syllable_tests {
+    use super::*;
+    use crate::Ipa;
+
+    fn syllables(ipa: &str) -> Vec<Vec<String>> {
+        Ipa::try_from(ipa).unwrap().syllabify().iter()
+            .map(|word| word.iter().map(|syllable| syllable.to_string()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_syllabify() {
+        assert_eq!(syllables("mia"), [["mi", "a"]]);
+        assert_eq!(syllables("pnʲː"), [["pnʲː"]]);
+        assert_eq!(syllables("ampmjanm"), [["am", "pmjanm"]]);
+        assert_eq!(syllables("anpjam"), [["an", "pjam"]]);
+        assert_eq!(syllables(" anʲ  pi "), [["anʲ"], ["pi"]]);
+        assert!(syllables("").is_empty());
+    }
+
+    #[test]
+    fn test_tones() {
+        assert_eq!(syllables("ma˧˥pa˩ ni˥˩ŋ"), [vec!["ma˧˥", "pa˩"], vec!["niŋ˥˩"]]);
+        assert_eq!(syllables("man˥ta"), [["man˥", "ta"]]);
+    }
+
+    #[test]
+    fn test_stress() {
+        assert_eq!(syllables("ˌmiˈam"), [["ˌmi", "ˈam"]]);
+        assert_eq!(syllables("ˈˌpaˈ"), [["ˈpa"]]);
+        let ipa = Ipa::try_from("pˈn").unwrap();
+        let syllable = &ipa.syllabify()[0][0];
+        assert_eq!(syllable.nucleus, ipa[2..].to_vec());
+        assert_eq!(syllable.stress, Stress::Unstressed);
+    }
+
+    #[test]
+    fn test_validate() {
+        let ipa = Ipa::try_from("pma ˈ").unwrap();
+        let [p, m, a, space, mark] = [0, 1, 2, 3, 4].map(|i| ipa[i]);
+        assert_eq!(Syllable::new(vec![p], vec![m], vec![], Stress::Primary).map(|syllable| syllable.to_string()), Ok("ˈpm".into()));
+        assert_eq!(Syllable::new(vec![p], vec![], vec![], Stress::Unstressed), Err(SyllableError::EmptyNucleus));
+        assert_eq!(Syllable::new(vec![], vec![p, m], vec![], Stress::Unstressed), Err(SyllableError::NucleusWithoutVowel));
+        assert_eq!(Syllable::new(vec![a], vec![a], vec![], Stress::Unstressed).map(|_| ()), Err(SyllableError::VowelInMargin(Vowels::OpenFrontUnrounded)));
+        assert_eq!(Syllable::new(vec![], vec![a], vec![space], Stress::Unstressed), Err(SyllableError::NotASegment(space)));
+        assert_eq!(Syllable::new(vec![mark], vec![a], vec![], Stress::Unstressed), Err(SyllableError::NotASegment(mark)));
+    }
+}
@@ -0,0 +1,83 @@
+//! LaTeX TIPA markup, for embedding transcriptions in papers.
+
+use alloc::{format, string::String};
+use crate::{transliteration::{self, Table}, IpaSlice, Sound};
+
+/// TIPA input of the supported sounds and modifiers inside `\textipa`.
+///
+/// Sounds with a TIPA shortcut character use it, the rest use macros ended with "{}" so that a
+/// following letter is not taken as part of the name.
+const TIPA: &Table = &[
+    // Vowels
+    ("i", "i"), ("y", "y"), ("\\textbari{}", "ɨ"), ("\\textbaru{}", "ʉ"), ("W", "ɯ"), ("u", "u"),
+    ("I", "ɪ"), ("Y", "ʏ"), ("U", "ʊ"),
+    ("e", "e"), ("\\o{}", "ø"), ("\\textreve{}", "ɘ"), ("\\textbaro{}", "ɵ"), ("\\textramshorns{}", "ɤ"), ("o", "o"),
+    ("@", "ə"),
+    ("E", "ɛ"), ("\\oe{}", "œ"), ("\\textrevepsilon{}", "ɜ"), ("\\textcloserevepsilon{}", "ɞ"),
+    ("\\textturnv{}", "ʌ"), ("O", "ɔ"),
+    ("\\ae{}", "æ"), ("\\textturna{}", "ɐ"),
+    ("a", "a"), ("\\OE{}", "ɶ"), ("A", "ɑ"), ("\\textturnscripta{}", "ɒ"),
+    // Plosives
+    ("p", "p"), ("b", "b"), ("t", "t"), ("d", "d"), ("\\textrtailt{}", "ʈ"), ("\\textrtaild{}", "ɖ"),
+    ("c", "c"), ("\\textbardotlessj{}", "ɟ"), ("k", "k"), ("g", "ɡ"), ("q", "q"), ("\\textscg{}", "ɢ"),
+    ("P", "ʔ"), ("\\textbarglotstop{}", "ʡ"),
+    // Nasals
+    ("m", "m"), ("M", "ɱ"), ("n", "n"), ("\\textrtailn{}", "ɳ"), ("\\textltailn{}", "ɲ"), ("N", "ŋ"),
+    ("\\textscn{}", "ɴ"),
+    // Trills and taps
+    ("\\textscb{}", "ʙ"), ("r", "r"), ("\\textscr{}", "ʀ"), ("R", "ɾ"), ("\\textfishhookr{}", "ɽ"),
+    // Fricatives
+    ("F", "ɸ"), ("B", "β"), ("f", "f"), ("v", "v"), ("T", "θ"), ("D", "ð"), ("s", "s"), ("z", "z"),
+    ("S", "ʃ"), ("Z", "ʒ"), ("\\textrtails{}", "ʂ"), ("\\textrtailz{}", "ʐ"), ("C", "ç"), ("J", "ʝ"),
+    ("x", "x"), ("G", "ɣ"), ("X", "χ"), ("K", "ʁ"), ("\\textcrh{}", "ħ"), ("Q", "ʕ"),
+    ("\\textsch{}", "ʜ"), ("\\textbarrevglotstop{}", "ʢ"), ("h", "h"), ("H", "ɦ"),
+    ("\\textbeltl{}", "ɬ"), ("\\textlyoghlig{}", "ɮ"), ("\\textctc{}", "ɕ"), ("\\textctz{}", "ʑ"),
+    ("\\textturnw{}", "ʍ"),
+    // Approximants
+    ("V", "ʋ"), ("\\textturnr{}", "ɹ"), ("\\textturnrrtail{}", "ɻ"), ("j", "j"), ("\\textturnmrleg{}", "ɰ"),
+    ("l", "l"), ("\\textrtaill{}", "ɭ"), ("L", "ʎ"), ("\\textscl{}", "ʟ"), ("w", "w"), ("\\textturnh{}", "ɥ"),
+    // Modifiers
+    (":", "ː"), ("\\super{j}", "ʲ"), ("\\textcorner{}", "\u{31A}"), ("\\super{l}", "ˡ"), ("\\super{n}", "ⁿ"),
+    ("\"\"", "ˌ"), ("\"", "ˈ"), (" ", " ")
+];
+
+impl IpaSlice {
+    /// Writes the sounds as TIPA markup in a `\textipa` command.
+    ///
+    /// Reduced sounds are written as full ones, tones as contours of the `\tone` macro, e.g. `\tone{51}`,
+    /// and sounds TIPA has no input for are kept as IPA.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let ipa = ipa_sounds::Ipa::try_from("ˈθæŋks ˌɹʌʃ").unwrap();
+    /// assert_eq!(ipa.to_tipa(), r#"\textipa{"T\ae{}Nks ""\textturnr{}\textturnv{}S}"#);
+    /// ```
+    pub fn to_tipa(&self) -> String {
+        let is_tone = |sound: &Sound| matches!(sound, Sound::Tone { .. });
+        let markup: String = self.as_sounds()
+            .chunk_by(|first, second| !is_tone(first) && !is_tone(second))
+            .map(|run| match *run {
+                [Sound::Tone { contour }] => {
+                    let levels: String = contour.levels().iter().map(|&level| char::from(b'0' + level)).collect();
+                    format!("\\tone{{{}}}", levels)
+                },
+                _ => transliteration::write(IpaSlice::new(run), TIPA)
+            })
+            .collect();
+        format!("\\textipa{{{}}}", markup)
+    }
+}
+
+#[cfg(test)]
+mod tipa_tests {
+    use crate::Ipa;
+
+    #[test]
+    fn test_to_tipa() {
+        assert_eq!(Ipa::try_from("tʲoːʁ").unwrap().to_tipa(), "\\textipa{t\\super{j}o:K}");
+        assert_eq!(Ipa::try_from("ⱱ").unwrap().to_tipa(), "\\textipa{ⱱ}");
+        assert_eq!(Ipa::try_from("t̚ tˡ dⁿ").unwrap().to_tipa(), "\\textipa{t\\textcorner{} t\\super{l} d\\super{n}}");
+        assert_eq!(Ipa::try_from("ma˥˩ma˧").unwrap().to_tipa(), "\\textipa{ma\\tone{51}ma\\tone{3}}");
+    }
+}
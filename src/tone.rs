@@ -0,0 +1,174 @@
+//! Tones as pitch contours of Chao levels, written as tone letters or tone numbers.
+
+use alloc::{string::{String, ToString}, vec::Vec};
+use crate::{FormatOptions, IpaSlice, Sound};
+
+/// Chao tone letters from the lowest level 1 to the highest level 5.
+pub(crate) const TONE_LETTERS: [char; 5] = ['˩', '˨', '˧', '˦', '˥'];
+
+/// Superscript tone numbers from 1 to 5.
+pub(crate) const TONE_NUMBERS: [char; 5] = ['¹', '²', '³', '⁴', '⁵'];
+
+/// Pitch contour of a tone as one to three Chao levels, from 1 (lowest) to 5 (highest), e.g. 35 for
+/// a high rising tone.
+///
+/// Tones are sounds of their own, [`Sound::Tone`](crate::Sound::Tone), following the syllable they
+/// belong to. The parser reads tone letters ("ma˧˥") and superscript tone numbers ("ma³⁵"), and with
+/// ASCII substitutes also plain digits ("ma35"); longer runs of levels are split into several tones.
+/// Tone categories such as Mandarin pinyin "ma3" differ between languages, so they are only read
+/// with [`IpaParser::tone_categories`](crate::IpaParser::tone_categories).
+///
+/// # Examples
+///
+/// ```
+/// use ipa_sounds::{Ipa, IpaParser, Sound, Tone};
+///
+/// let ipa = Ipa::try_from("ma³⁵").unwrap();
+/// assert_eq!(ipa[2], Sound::Tone { contour: Tone::new(&[3, 5]).unwrap() });
+/// assert_eq!(ipa.to_tone_letters(), "ma˧˥");
+/// assert_eq!(IpaParser::new().ascii_substitutes(true).parse("ma35"), Ok(ipa));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+pub struct Tone {
+    /// Levels, followed by zeros if there are fewer than three.
+    levels: [u8; 3]
+}
+
+impl Tone {
+    /// Creates a tone of one to three levels from 1 to 5, `None` for other levels.
+    pub fn new(levels: &[u8]) -> Option<Tone> {
+        if levels.is_empty() || levels.len() > 3 || levels.iter().any(|level| !(1..=5).contains(level)) {
+            return None;
+        }
+        let mut tone = Tone { levels: [0; 3] };
+        tone.levels[..levels.len()].copy_from_slice(levels);
+        Some(tone)
+    }
+
+    /// Levels of the contour.
+    pub fn levels(&self) -> &[u8] {
+        let len = self.levels.iter().position(|&level| level == 0).unwrap_or(3);
+        &self.levels[..len]
+    }
+
+    /// Tone with one more level, `None` if it already has three.
+    pub(crate) fn then(self, level: u8) -> Option<Tone> {
+        let len = self.levels().len();
+        let mut tone = self;
+        *tone.levels.get_mut(len)? = level;
+        Some(tone)
+    }
+}
+
+/// Tone categories of a language written as single trailing digits, e.g. pinyin "ma3", with their contours.
+///
+/// # Examples
+///
+/// ```
+/// use ipa_sounds::{IpaParser, Tone, ToneCategories};
+///
+/// let categories = ToneCategories::new().category('1', Tone::new(&[3, 3]).unwrap());
+/// let ipa = IpaParser::new().tone_categories(Some(categories.clone())).parse("ma1").unwrap();
+/// assert_eq!(ipa.to_string(), "ma˧˧");
+/// assert_eq!(ipa.to_tone_categories(&categories), "ma1");
+/// assert_eq!(ipa.to_tone_categories(&ToneCategories::mandarin()), "ma˧˧");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ToneCategories {
+    categories: Vec<(char, Tone)>
+}
+
+impl ToneCategories {
+    /// Creates a table without categories.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Four tones of Standard Mandarin as numbered in pinyin: 1 ˥˥, 2 ˧˥, 3 ˨˩˦ and 4 ˥˩.
+    ///
+    /// The neutral tone, written 5 or 0, has no contour of its own and is not included.
+    pub fn mandarin() -> Self {
+        Self::new()
+            .category('1', Tone::new(&[5, 5]).unwrap())
+            .category('2', Tone::new(&[3, 5]).unwrap())
+            .category('3', Tone::new(&[2, 1, 4]).unwrap())
+            .category('4', Tone::new(&[5, 1]).unwrap())
+    }
+
+    /// Adds a category written as the digit, replacing an earlier one with the same digit.
+    ///
+    /// Only ASCII digits are read by the parser.
+    pub fn category(mut self, digit: char, contour: Tone) -> Self {
+        self.categories.retain(|&(other, _)| other != digit);
+        self.categories.push((digit, contour));
+        self
+    }
+
+    /// Contour of the category written as the digit.
+    pub fn contour(&self, digit: char) -> Option<Tone> {
+        self.categories.iter().find(|&&(other, _)| other == digit).map(|&(_, contour)| contour)
+    }
+
+    /// Digit of the category with the contour, the first one if several have it.
+    pub fn digit(&self, contour: Tone) -> Option<char> {
+        self.categories.iter().find(|&&(_, other)| other == contour).map(|&(digit, _)| digit)
+    }
+}
+
+impl IpaSlice {
+    /// Writes the sounds with tones as superscript tone numbers, e.g. "ma³⁵".
+    pub fn to_tone_numbers(&self) -> String {
+        self.to_string_with(&FormatOptions::new().tone_numbers(true))
+    }
+
+    /// Writes the sounds with tones as Chao tone letters, e.g. "ma˧˥", as `Display` does.
+    pub fn to_tone_letters(&self) -> String {
+        self.to_string_with(&FormatOptions::new())
+    }
+
+    /// Writes the sounds with tones as the digits of their categories, e.g. "ma3"; tones without a
+    /// category are written as Chao tone letters.
+    pub fn to_tone_categories(&self, categories: &ToneCategories) -> String {
+        self.iter()
+            .map(|sound| match *sound {
+                Sound::Tone { contour } => categories.digit(contour).map_or_else(|| sound.to_string(), String::from),
+                _ => sound.to_string()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tone_tests {
+    use super::*;
+    use crate::{Error, Ipa, IpaParser, Sound};
+
+    #[test]
+    fn test_conversions() {
+        let ipa = Ipa::try_from("ma˨˩˦ ma˥˥ ma˥˩˧˥").unwrap();
+        assert_eq!(ipa.to_tone_numbers(), "ma²¹⁴ ma⁵⁵ ma⁵¹³⁵");
+        assert_eq!(Ipa::try_from(ipa.to_tone_numbers()), Ok(ipa.clone()));
+        let ascii = FormatOptions::new().ascii_substitutes(true);
+        assert_eq!(ipa.to_string_with(&ascii), "ma214 ma55 ma5135");
+        assert_eq!(IpaParser::new().ascii_substitutes(true).parse("ma214 ma55 ma5135"), Ok(ipa.clone()));
+        assert_eq!(ipa.iter().filter(|sound| matches!(sound, Sound::Tone { .. })).count(), 4);
+        assert_eq!(Tone::new(&[1, 6]), None);
+        assert_eq!(Tone::new(&[2, 1, 4]).unwrap().levels(), [2, 1, 4]);
+    }
+
+    #[test]
+    fn test_categories() {
+        let mandarin = IpaParser::new().ascii_substitutes(true).tone_categories(Some(ToneCategories::mandarin()));
+        let ipa = mandarin.parse("ni3 xau3 ma1").unwrap();
+        assert_eq!(ipa.to_string(), "ni˨˩˦ xau˨˩˦ ma˥˥");
+        assert_eq!(ipa.to_tone_categories(&ToneCategories::mandarin()), "ni3 xau3 ma1");
+        assert_eq!(IpaParser::new().ascii_substitutes(true).parse("ma3").unwrap().to_string(), "ma˧");
+        assert_eq!(mandarin.parse("ma5"), Err(Error::NotYetImplemented('5')));
+        assert_eq!(mandarin.parse("ma4˩").unwrap().as_sounds()[2..].len(), 2);
+        let changed = ToneCategories::mandarin().category('3', Tone::new(&[2, 1]).unwrap());
+        assert_eq!(changed.contour('3'), Tone::new(&[2, 1]));
+        assert_eq!(changed.digit(Tone::new(&[2, 1, 4]).unwrap()), None);
+    }
+}
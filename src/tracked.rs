@@ -0,0 +1,156 @@
+//! Sequences of IPA sounds with stable segment IDs.
+
+use alloc::vec::Vec;
+use core::fmt;
+use crate::{Ipa, Sound};
+
+/// Identifier of a segment in a [`TrackedIpa`].
+///
+/// IDs are assigned in order starting from 0 and are never reused, so the same sequence of edits
+/// always produces the same IDs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SegmentId(u64);
+
+/// Sequence of IPA sounds whose segments keep their IDs when other segments are inserted or removed.
+///
+/// # Examples
+///
+/// ```
+/// use ipa_sounds::{Ipa, TrackedIpa};
+///
+/// let mut tracked = TrackedIpa::from(Ipa::try_from("nʲæ").unwrap());
+/// let vowel = tracked.id_at(1).unwrap();
+/// tracked.insert(0, tracked.get(vowel).unwrap());
+/// assert_eq!(tracked.index_of(vowel), Some(2));
+/// assert_eq!(format!("{}", tracked), "ænʲæ");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TrackedIpa {
+    segments: Vec<(SegmentId, Sound)>,
+    next_id: u64
+}
+
+impl TrackedIpa {
+    /// Creates an empty sequence.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn next_id(&mut self) -> SegmentId {
+        let id = SegmentId(self.next_id);
+        self.next_id += 1;
+        id
+    }
+
+    /// Current number of segments.
+    pub fn len(&self) -> usize {
+        self.segments.len()
+    }
+
+    /// Whether there are no segments.
+    pub fn is_empty(&self) -> bool {
+        self.segments.is_empty()
+    }
+
+    /// IDs of the segments in their current order.
+    pub fn ids(&self) -> impl Iterator<Item = SegmentId> + '_ {
+        self.segments.iter().map(|&(id, _)| id)
+    }
+
+    /// Current index of the segment with the given ID.
+    pub fn index_of(&self, id: SegmentId) -> Option<usize> {
+        self.segments.iter().position(|&(segment_id, _)| segment_id == id)
+    }
+
+    /// ID of the segment currently at the given index.
+    pub fn id_at(&self, index: usize) -> Option<SegmentId> {
+        self.segments.get(index).map(|&(id, _)| id)
+    }
+
+    /// Sound of the segment with the given ID.
+    pub fn get(&self, id: SegmentId) -> Option<Sound> {
+        self.index_of(id).map(|index| self.segments[index].1)
+    }
+
+    /// Appends a sound and returns the ID of the new segment.
+    pub fn push(&mut self, sound: Sound) -> SegmentId {
+        let id = self.next_id();
+        self.segments.push((id, sound));
+        id
+    }
+
+    /// Inserts a sound at the given index and returns the ID of the new segment.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    pub fn insert(&mut self, index: usize, sound: Sound) -> SegmentId {
+        let id = self.next_id();
+        self.segments.insert(index, (id, sound));
+        id
+    }
+
+    /// Removes the segment with the given ID, returning its sound.
+    pub fn remove(&mut self, id: SegmentId) -> Option<Sound> {
+        self.index_of(id).map(|index| self.segments.remove(index).1)
+    }
+
+    /// Replaces the sound of the segment with the given ID, keeping the ID and returning the old sound.
+    pub fn replace(&mut self, id: SegmentId, sound: Sound) -> Option<Sound> {
+        self.index_of(id).map(|index| core::mem::replace(&mut self.segments[index].1, sound))
+    }
+
+    /// Sequence of the current sounds without IDs.
+    pub fn to_ipa(&self) -> Ipa {
+        Ipa(self.segments.iter().map(|&(_, sound)| sound).collect(), None)
+    }
+}
+
+impl From<Ipa> for TrackedIpa {
+    fn from(ipa: Ipa) -> Self {
+        let mut tracked = Self::new();
+        ipa.0.into_iter().for_each(|sound| {
+            tracked.push(sound);
+        });
+        tracked
+    }
+}
+
+impl From<&TrackedIpa> for Ipa {
+    fn from(tracked: &TrackedIpa) -> Self {
+        tracked.to_ipa()
+    }
+}
+
+impl fmt::Display for TrackedIpa {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.to_ipa(), formatter)
+    }
+}
+
+#[cfg(test)]
+mod tracked_tests {
+    use super::*;
+
+    #[test]
+    fn test_ids_survive_edits() {
+        let mut tracked = TrackedIpa::from(Ipa::try_from("nʲæn").unwrap());
+        let ids: Vec<_> = tracked.ids().collect();
+        tracked.remove(ids[0]);
+        let inserted = tracked.insert(1, Sound::Space);
+        assert_eq!(tracked.index_of(ids[1]), Some(0));
+        assert_eq!(tracked.index_of(ids[2]), Some(2));
+        assert_eq!(tracked.index_of(ids[0]), None);
+        assert_eq!(tracked.id_at(1), Some(inserted));
+        assert_eq!(format!("{}", tracked), "æ n");
+    }
+
+    #[test]
+    fn test_deterministic_ids() {
+        let edit = || {
+            let mut tracked = TrackedIpa::from(Ipa::try_from("æ").unwrap());
+            tracked.push(Sound::Space)
+        };
+        assert_eq!(edit(), edit());
+    }
+}
@@ -0,0 +1,200 @@
+//! Common phonological transformations of whole sequences.
+//!
+//! `Sound` can represent sequences that do not survive writing and parsing, such as reductions
+//! without a superscript symbol or releases on consonants other than plosives, and
+//! [`Ipa::canonicalize`] rewrites them into a form that does. The other transformations, except
+//! [`Ipa::map_sounds`] with an arbitrary function, keep a canonical sequence canonical: voicing only
+//! changes where the opposite sound is supported, and palatalization and length can be written on
+//! every consonant and vowel.
+
+use alloc::vec::Vec;
+use crate::{to_superscript, Backness, Consonants, Feature, FeatureSpec, Ipa, Place, Release, Sound, Tone};
+
+impl Ipa {
+    /// Replaces every sound with the result of the function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ipa_sounds::{Ipa, Sound};
+    ///
+    /// let mut ipa = Ipa::try_from("maː").unwrap();
+    /// ipa.map_sounds(|sound| match sound {
+    ///     Sound::Vowel { phoneme, is_reduced, .. } => Sound::Vowel { phoneme, is_long: false, is_reduced },
+    ///     sound => sound
+    /// });
+    /// assert_eq!(ipa.to_string(), "ma");
+    /// ```
+    pub fn map_sounds(&mut self, mut function: impl FnMut(Sound) -> Sound) {
+        for sound in &mut self.0 {
+            *sound = function(*sound);
+        }
+    }
+
+    /// Devoices the obstruents at the end of every word, e.g. "ɡrozd" becomes "ɡrost".
+    ///
+    /// Stress marks at the end of a word are skipped, and voiced obstruents without a voiceless
+    /// counterpart are kept.
+    pub fn devoice_finals(&mut self) {
+        let obstruent = FeatureSpec::new().plus(Feature::Consonantal).minus(Feature::Sonorant);
+        let devoiced = FeatureSpec::new().minus(Feature::Voice);
+        let mut is_final = true;
+        for sound in self.0.iter_mut().rev() {
+            match *sound {
+                Sound::Space => is_final = true,
+                Sound::Stress { .. } => {},
+                _ if is_final && sound.matches(&obstruent) => {
+                    *sound = sound.with_features(&devoiced).unwrap_or(*sound);
+                },
+                _ => is_final = false
+            }
+        }
+    }
+
+    /// Palatalizes consonants directly before front vowels, e.g. "ten" becomes "tʲen".
+    ///
+    /// Palatal and alveolo-palatal consonants are already palatal and stay as they are.
+    pub fn palatalize_before_front_vowels(&mut self) {
+        for i in 1..self.0.len() {
+            let Sound::Vowel { phoneme: vowel, .. } = self.0[i] else {
+                continue;
+            };
+            if let Sound::Consonant { phoneme, ref mut is_palatalized, .. } = self.0[i - 1] {
+                let is_palatal = matches!(phoneme.place(), Place::Palatal | Place::AlveoloPalatal);
+                *is_palatalized |= vowel.backness() <= Backness::NearFront && !is_palatal;
+            }
+        }
+    }
+
+    /// Lengthens the first vowel after every primary stress mark in the same word.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut ipa = ipa_sounds::Ipa::try_from("ˈdeti ˌdom").unwrap();
+    /// ipa.lengthen_stressed_vowels();
+    /// assert_eq!(ipa.to_string(), "ˈdeːti ˌdom");
+    /// ```
+    pub fn lengthen_stressed_vowels(&mut self) {
+        let mut is_stressed = false;
+        for sound in &mut self.0 {
+            match sound {
+                Sound::Stress { is_primary } => is_stressed = *is_primary,
+                Sound::Space => is_stressed = false,
+                Sound::Vowel { is_long, .. } if is_stressed => {
+                    *is_long = true;
+                    is_stressed = false;
+                },
+                _ => {}
+            }
+        }
+    }
+
+    /// Rewrites equivalent sequences into one canonical form, which survives writing and parsing.
+    ///
+    /// Two identical adjacent short vowels or consonants become one long sound, and sounds without a
+    /// superscript symbol lose their reduction, which cannot be written for them. Lateral and nasal
+    /// releases are dropped from consonants other than plosives, and a plain plosive followed by a
+    /// reduced \[ⁿ\] takes it as its nasal release, as the parser reads "tⁿ". Adjacent tones are
+    /// regrouped into contours of up to three levels, as the parser reads them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ipa_sounds::Ipa;
+    ///
+    /// let mut ipa = Ipa::try_from("ˈnʲnʲaa nːa").unwrap();
+    /// ipa.canonicalize();
+    /// assert_eq!(ipa, Ipa::try_from("ˈnʲːaː nːa").unwrap());
+    /// assert_eq!(Ipa::try_from(ipa.to_string()), Ok(ipa));
+    /// ```
+    pub fn canonicalize(&mut self) {
+        self.map_sounds(|sound| match sound {
+            Sound::Vowel { phoneme, is_long, is_reduced } => Sound::Vowel {
+                phoneme,
+                is_long,
+                is_reduced: is_reduced && to_superscript(char::from(phoneme)).is_some()
+            },
+            Sound::Consonant { phoneme, is_long, is_palatalized, is_reduced, release } => Sound::Consonant {
+                phoneme,
+                is_long,
+                is_palatalized,
+                is_reduced: is_reduced && to_superscript(char::from(phoneme)).is_some(),
+                release: release.filter(|release| release.is_allowed(phoneme))
+            },
+            sound => sound
+        });
+        let mut sounds = Vec::with_capacity(self.0.len());
+        for sound in core::mem::take(&mut self.0) {
+            if let Some(last) = sounds.last_mut() {
+                if let Some(released) = nasally_released(*last, sound) {
+                    *last = released;
+                    continue;
+                }
+            }
+            let Sound::Tone { contour } = sound else {
+                sounds.push(sound);
+                continue;
+            };
+            for &level in contour.levels() {
+                let longer = match sounds.last() {
+                    Some(&Sound::Tone { contour }) => contour.then(level),
+                    _ => None
+                };
+                match (longer, sounds.last_mut()) {
+                    (Some(contour), Some(last)) => *last = Sound::Tone { contour },
+                    _ => sounds.extend(Tone::new(&[level]).map(|contour| Sound::Tone { contour }))
+                }
+            }
+        }
+        self.0 = sounds;
+        self.0.dedup_by(|next, previous| {
+            let is_double = next == previous;
+            match previous {
+                Sound::Vowel { is_long, .. } | Sound::Consonant { is_long, .. } if is_double && !*is_long => {
+                    *is_long = true;
+                    true
+                },
+                _ => false
+            }
+        });
+    }
+}
+
+/// The plain plosive with the following reduced [ⁿ] as its nasal release, as the parser reads "tⁿ".
+fn nasally_released(plosive: Sound, next: Sound) -> Option<Sound> {
+    match (plosive, next) {
+        (
+            Sound::Consonant { phoneme, is_long: false, is_palatalized: false, is_reduced, release: None },
+            Sound::Consonant { phoneme: Consonants::VoicedAlveolarNasal, is_long, is_palatalized, is_reduced: true, .. }
+        ) if Release::Nasal.is_allowed(phoneme) => {
+            Some(Sound::Consonant { phoneme, is_long, is_palatalized, is_reduced, release: Some(Release::Nasal) })
+        },
+        _ => None
+    }
+}
+
+#[cfg(test)]
+mod transform_tests {
+    use super::*;
+
+    fn transformed(ipa: &str, transform: impl FnOnce(&mut Ipa)) -> String {
+        let mut ipa = Ipa::try_from(ipa).unwrap();
+        transform(&mut ipa);
+        ipa.to_string()
+    }
+
+    #[test]
+    fn test_devoice_finals() {
+        assert_eq!(transformed("ɡrozd ˈluɡˈ", Ipa::devoice_finals), "ɡrost ˈlukˈ");
+        assert_eq!(transformed("dom drozʲ ʁ", Ipa::devoice_finals), "dom drosʲ χ");
+        assert_eq!(transformed("daɡʲ dʲeɡʲ ɢʲː", Ipa::devoice_finals), "dakʲ dʲekʲ qʲː");
+        assert_eq!(transformed("", Ipa::devoice_finals), "");
+    }
+
+    #[test]
+    fn test_palatalize_and_lengthen() {
+        assert_eq!(transformed("tenʲi kæ ju ɕe", Ipa::palatalize_before_front_vowels), "tʲenʲi kʲæ ju ɕe");
+        assert_eq!(transformed("ˈdeː ˈa", Ipa::lengthen_stressed_vowels), "ˈdeː ˈaː");
+    }
+}
@@ -0,0 +1,102 @@
+//! Conversion between IPA and ASCII notations by tables of symbols.
+
+use alloc::string::{String, ToString};
+use core::fmt;
+use crate::{Error, Ipa, IpaSlice, Sound};
+
+/// Table of a notation: pairs of a symbol of the notation and the IPA it stands for.
+///
+/// When several symbols stand for the same IPA, the first one is written.
+pub(crate) type Table = [(&'static str, &'static str)];
+
+/// Enum for possible errors when reading a notation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TransliterationError {
+    /// Symbol that is not in the notation or stands for an unsupported sound.
+    UnknownSymbol(char),
+    /// Symbols of the notation form invalid IPA, e.g. a palatalized vowel.
+    Ipa(Error)
+}
+
+impl fmt::Display for TransliterationError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TransliterationError::UnknownSymbol(symbol) => {
+                write!(formatter, "'{}' is not a supported symbol of the notation", symbol)
+            },
+            TransliterationError::Ipa(error) => fmt::Display::fmt(error, formatter)
+        }
+    }
+}
+
+impl core::error::Error for TransliterationError {}
+
+impl From<Error> for TransliterationError {
+    fn from(error: Error) -> Self {
+        TransliterationError::Ipa(error)
+    }
+}
+
+/// Replaces the longest matching symbols on one side of the table with the other side. A char no
+/// symbol starts with is kept when writing the notation and is the error when reading it.
+fn transliterate(text: &str, table: &Table, from_ipa: bool) -> Result<String, char> {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(symbol) = rest.chars().next() {
+        let mut best: Option<(&str, &str)> = None;
+        for &(notation, ipa) in table {
+            let (from, to) = if from_ipa { (ipa, notation) } else { (notation, ipa) };
+            if rest.starts_with(from) && best.is_none_or(|(longest, _)| from.len() > longest.len()) {
+                best = Some((from, to));
+            }
+        }
+        match best {
+            Some((from, to)) => {
+                result.push_str(to);
+                rest = &rest[from.len()..];
+            },
+            None if from_ipa => {
+                result.push(symbol);
+                rest = &rest[symbol.len_utf8()..];
+            },
+            None => return Err(symbol)
+        }
+    }
+    Ok(result)
+}
+
+/// Reads text in the notation.
+pub(crate) fn parse(text: &str, table: &Table) -> Result<Ipa, TransliterationError> {
+    let ipa = transliterate(text, table, false).map_err(TransliterationError::UnknownSymbol)?;
+    Ok(Ipa::try_from(ipa.as_str())?)
+}
+
+/// Writes the sounds in the notation; reduced sounds are written as full ones, and symbols missing
+/// from the table are kept as IPA.
+pub(crate) fn write(ipa: &IpaSlice, table: &Table) -> String {
+    let full: Ipa = ipa.iter()
+        .map(|&sound| match sound {
+            Sound::Vowel { phoneme, is_long, .. } => Sound::Vowel { phoneme, is_long, is_reduced: false },
+            Sound::Consonant { phoneme, is_long, is_palatalized, release, .. } => {
+                Sound::Consonant { phoneme, is_long, is_palatalized, is_reduced: false, release }
+            },
+            sound => sound
+        })
+        .collect();
+    transliterate(&full.to_string(), table, true).expect("unknown symbols are kept when writing")
+}
+
+#[cfg(test)]
+mod transliteration_tests {
+    use super::*;
+
+    #[test]
+    fn test_longest_match() {
+        let table: &Table = &[("a", "a"), ("n", "n"), ("n'", "nʲ"), ("N", "ŋ"), (":", "ː"), ("'", "ʲ")];
+        assert_eq!(parse("n'a:N", table).unwrap().to_string(), "nʲaːŋ");
+        assert_eq!(parse("nab", table), Err(TransliterationError::UnknownSymbol('b')));
+        assert_eq!(parse("a'", table), Err(TransliterationError::Ipa(Error::PalatalizedVowel('a'))));
+        assert_eq!(write(&Ipa::try_from("nʲaːmᵊ").unwrap(), table), "n'a:mə");
+    }
+}
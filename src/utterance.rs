@@ -0,0 +1,143 @@
+//! Hierarchical view of a sequence of sounds: utterances of words of syllables.
+
+use alloc::vec::Vec;
+use core::fmt;
+use crate::{Ipa, IpaSlice, Sound, Stress, Syllable};
+
+/// Word: a sequence of syllables.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Word {
+    pub syllables: Vec<Syllable>
+}
+
+impl Word {
+    /// Creates a word from syllables.
+    pub fn new(syllables: Vec<Syllable>) -> Self {
+        Word { syllables }
+    }
+
+    /// Index of the syllable with primary stress, if any.
+    pub fn stressed_syllable(&self) -> Option<usize> {
+        self.syllables.iter().position(|syllable| syllable.stress == Stress::Primary)
+    }
+
+    /// Iterator over the sounds of the word, including stress marks.
+    pub fn sounds(&self) -> impl Iterator<Item = Sound> + '_ {
+        self.syllables.iter().flat_map(Syllable::sounds)
+    }
+}
+
+/// Utterance: a sequence of words, written with single spaces between them.
+///
+/// Converting to [`Ipa`] is lossless, and the result has no notation. Converting from it keeps every
+/// vowel and consonant in order, but loses:
+///
+/// - extra spaces, and stress marks not at the start of a syllable;
+/// - tones other than the last one of each syllable, which is moved after its coda, so "ni˥˩ŋ"
+///   comes back as "niŋ˥˩";
+/// - the [`Notation`](crate::Notation), since words and syllables only hold sounds.
+///
+/// # Examples
+///
+/// ```
+/// use ipa_sounds::{Ipa, Stress};
+///
+/// let ipa = Ipa::try_from("ˈmia nʲæ").unwrap();
+/// let utterance = ipa.to_utterance();
+/// assert_eq!(utterance.words.len(), 2);
+/// assert_eq!(utterance.words[0].syllables[0].stress, Stress::Primary);
+/// assert_eq!(utterance.to_ipa(), ipa);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Utterance {
+    pub words: Vec<Word>
+}
+
+impl Utterance {
+    /// Creates an utterance from words.
+    pub fn new(words: Vec<Word>) -> Self {
+        Utterance { words }
+    }
+
+    /// Iterator over the sounds of the utterance, with a space between words.
+    pub fn sounds(&self) -> impl Iterator<Item = Sound> + '_ {
+        self.words.iter().enumerate().flat_map(|(i, word)| {
+            (i > 0).then_some(Sound::Space).into_iter().chain(word.sounds())
+        })
+    }
+
+    /// Flattens the utterance into a sequence of sounds.
+    pub fn to_ipa(&self) -> Ipa {
+        self.sounds().collect()
+    }
+}
+
+impl IpaSlice {
+    /// Groups the sounds into words and syllables, as [`IpaSlice::syllabify`] does.
+    pub fn to_utterance(&self) -> Utterance {
+        Utterance::new(self.syllabify().into_iter().map(Word::new).collect())
+    }
+}
+
+impl From<&IpaSlice> for Utterance {
+    fn from(ipa: &IpaSlice) -> Self {
+        ipa.to_utterance()
+    }
+}
+
+impl From<&Utterance> for Ipa {
+    fn from(utterance: &Utterance) -> Self {
+        utterance.to_ipa()
+    }
+}
+
+impl From<Utterance> for Ipa {
+    fn from(utterance: Utterance) -> Self {
+        utterance.to_ipa()
+    }
+}
+
+/// Writes the word as IPA, e.g. "ˈmia".
+impl fmt::Display for Word {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.syllables.iter().try_for_each(|syllable| fmt::Display::fmt(syllable, formatter))
+    }
+}
+
+/// Writes the utterance as IPA, e.g. "ˈmia nʲæ".
+impl fmt::Display for Utterance {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.words.iter().enumerate().try_for_each(|(i, word)| {
+            if i > 0 {
+                formatter.write_str(" ")?;
+            }
+            fmt::Display::fmt(word, formatter)
+        })
+    }
+}
+
+#[cfg(test)]
+mod utterance_tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let ipa = Ipa::try_from("ˌpaˈmi nʲæn ˈmjaa").unwrap();
+        let utterance = ipa.to_utterance();
+        assert_eq!(utterance.to_ipa(), ipa);
+        assert_eq!(utterance.to_string(), ipa.to_string());
+        assert_eq!(utterance.words.iter().map(Word::stressed_syllable).collect::<Vec<_>>(), [Some(1), None, Some(0)]);
+        assert_eq!(Utterance::from(&ipa[..]), utterance);
+    }
+
+    #[test]
+    fn test_normalization() {
+        let ipa = Ipa::try_from("  miˈ   pa ").unwrap();
+        assert_eq!(Ipa::from(ipa.to_utterance()), Ipa::try_from("mi pa").unwrap());
+        assert_eq!(Utterance::default().to_ipa(), Ipa::default());
+        assert_eq!(Ipa::try_from("ni˥˩ŋ").unwrap().to_utterance().to_string(), "niŋ˥˩");
+        assert_eq!(Ipa::try_from("/ma/").unwrap().to_utterance().to_ipa().notation(), None);
+    }
+}
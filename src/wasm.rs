@@ -0,0 +1,83 @@
+//! wasm-bindgen bindings for pronunciation tools in the browser.
+//!
+//! Errors are thrown as JavaScript `Error`s with the messages of the crate's `Display` impls.
+
+use alloc::{string::{String, ToString}, vec::Vec};
+use wasm_bindgen::prelude::{wasm_bindgen, JsError};
+use crate::Ipa;
+
+/// Transcription exported to JavaScript as `Ipa`.
+#[wasm_bindgen(js_name = Ipa)]
+pub struct WasmIpa(Ipa);
+
+#[wasm_bindgen(js_class = Ipa)]
+impl WasmIpa {
+    /// Parses IPA, throwing if it is invalid.
+    #[wasm_bindgen(constructor)]
+    pub fn new(text: &str) -> Result<WasmIpa, JsError> {
+        Ipa::try_from(text).map(WasmIpa).map_err(|error| JsError::new(&error.to_string()))
+    }
+
+    /// Reads X-SAMPA.
+    #[wasm_bindgen(js_name = fromXsampa)]
+    pub fn from_xsampa(text: &str) -> Result<WasmIpa, JsError> {
+        Ipa::from_xsampa(text).map(WasmIpa).map_err(|error| JsError::new(&error.to_string()))
+    }
+
+    /// Reads ARPAbet with the default options.
+    #[wasm_bindgen(js_name = fromArpabet)]
+    pub fn from_arpabet(text: &str) -> Result<WasmIpa, JsError> {
+        Ipa::from_arpabet(text).map(WasmIpa).map_err(|error| JsError::new(&error.to_string()))
+    }
+
+    /// Number of sounds, including spaces and stress marks.
+    #[wasm_bindgen(getter)]
+    pub fn length(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Writes the IPA.
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_ipa_string(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Syllables of every word, separated by ".".
+    pub fn syllabify(&self) -> Vec<String> {
+        self.0.syllabify().iter()
+            .map(|word| word.iter().map(ToString::to_string).collect::<Vec<_>>().join("."))
+            .collect()
+    }
+
+    /// Writes X-SAMPA.
+    #[wasm_bindgen(js_name = toXsampa)]
+    pub fn to_xsampa(&self) -> String {
+        self.0.to_xsampa()
+    }
+
+    /// Writes Kirshenbaum ASCII-IPA.
+    #[wasm_bindgen(js_name = toKirshenbaum)]
+    pub fn to_kirshenbaum(&self) -> String {
+        self.0.to_kirshenbaum()
+    }
+
+    /// Writes ARPAbet, throwing for sounds without a phoneme.
+    #[wasm_bindgen(js_name = toArpabet)]
+    pub fn to_arpabet(&self) -> Result<String, JsError> {
+        self.0.to_arpabet().map_err(|error| JsError::new(&error.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod wasm_tests {
+    use super::*;
+
+    #[test]
+    fn test_bindings() {
+        let Ok(ipa) = WasmIpa::new("amˈpja nʲæ") else {
+            panic!("valid IPA");
+        };
+        assert_eq!(ipa.syllabify(), ["am.ˈpja", "nʲæ"]);
+        assert_eq!((ipa.length(), ipa.to_ipa_string()), (9, "amˈpja nʲæ".into()));
+    }
+}
@@ -0,0 +1,94 @@
+//! X-SAMPA, the ASCII encoding of IPA used by many speech corpora and tools.
+
+use alloc::string::String;
+use crate::{transliteration::{self, Table, TransliterationError}, Ipa, IpaSlice};
+
+/// X-SAMPA symbols of the supported sounds and modifiers; alternatives read but not written come last.
+pub(crate) const XSAMPA: &Table = &[
+    // Vowels
+    ("i", "i"), ("y", "y"), ("1", "ɨ"), ("}", "ʉ"), ("M", "ɯ"), ("u", "u"),
+    ("I", "ɪ"), ("Y", "ʏ"), ("U", "ʊ"),
+    ("e", "e"), ("2", "ø"), ("@\\", "ɘ"), ("8", "ɵ"), ("7", "ɤ"), ("o", "o"),
+    ("@", "ə"),
+    ("E", "ɛ"), ("9", "œ"), ("3", "ɜ"), ("3\\", "ɞ"), ("V", "ʌ"), ("O", "ɔ"),
+    ("{", "æ"), ("6", "ɐ"),
+    ("a", "a"), ("&", "ɶ"), ("A", "ɑ"), ("Q", "ɒ"),
+    // Plosives
+    ("p", "p"), ("b", "b"), ("t", "t"), ("d", "d"), ("t`", "ʈ"), ("d`", "ɖ"), ("c", "c"), ("J\\", "ɟ"),
+    ("k", "k"), ("g", "ɡ"), ("q", "q"), ("G\\", "ɢ"), ("?", "ʔ"), (">\\", "ʡ"),
+    // Nasals
+    ("m", "m"), ("F", "ɱ"), ("n", "n"), ("n`", "ɳ"), ("J", "ɲ"), ("N", "ŋ"), ("N\\", "ɴ"),
+    // Trills and taps
+    ("B\\", "ʙ"), ("r", "r"), ("R\\", "ʀ"), ("4", "ɾ"), ("r`", "ɽ"),
+    // Fricatives
+    ("p\\", "ɸ"), ("B", "β"), ("f", "f"), ("v", "v"), ("T", "θ"), ("D", "ð"), ("s", "s"), ("z", "z"),
+    ("S", "ʃ"), ("Z", "ʒ"), ("s`", "ʂ"), ("z`", "ʐ"), ("C", "ç"), ("j\\", "ʝ"), ("x", "x"), ("G", "ɣ"),
+    ("X", "χ"), ("R", "ʁ"), ("X\\", "ħ"), ("?\\", "ʕ"), ("K", "ɬ"), ("K\\", "ɮ"),
+    ("H\\", "ʜ"), ("<\\", "ʢ"), ("h", "h"), ("h\\", "ɦ"), ("s\\", "ɕ"), ("z\\", "ʑ"), ("W", "ʍ"),
+    // Approximants
+    ("P", "ʋ"), ("r\\", "ɹ"), ("r\\`", "ɻ"), ("j", "j"), ("M\\", "ɰ"),
+    ("l", "l"), ("l`", "ɭ"), ("L", "ʎ"), ("L\\", "ʟ"), ("w", "w"), ("H", "ɥ"),
+    // Modifiers
+    (":", "ː"), ("'", "ʲ"), ("_}", "\u{31A}"), ("_l", "ˡ"), ("_n", "ⁿ"), ("\"", "ˈ"), ("%", "ˌ"), (" ", " "),
+    ("_T", "˥"), ("_H", "˦"), ("_M", "˧"), ("_L", "˨"), ("_B", "˩"),
+    // Alternatives
+    ("v\\", "ʋ"), ("_j", "ʲ")
+];
+
+impl Ipa {
+    /// Reads X-SAMPA, e.g. "\"n'{n" for "ˈnʲæn".
+    ///
+    /// Diacritics and sounds the crate does not support, such as aspiration "_h" or clicks, are
+    /// reported as unknown symbols.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ipa_sounds::{Ipa, TransliterationError};
+    ///
+    /// let ipa = Ipa::from_xsampa("\"n'{n r\\at_j").unwrap();
+    /// assert_eq!(ipa.to_string(), "ˈnʲæn ɹatʲ");
+    /// assert_eq!(ipa.to_xsampa(), "\"n'{n r\\at'");
+    /// assert_eq!(Ipa::from_xsampa("t_h"), Err(TransliterationError::UnknownSymbol('_')));
+    /// ```
+    pub fn from_xsampa(xsampa: &str) -> Result<Ipa, TransliterationError> {
+        transliteration::parse(xsampa, XSAMPA)
+    }
+}
+
+impl IpaSlice {
+    /// Writes the sounds in X-SAMPA; reduced sounds are written as full ones, and 'ⱱ', which has no
+    /// X-SAMPA symbol, is kept as IPA.
+    pub fn to_xsampa(&self) -> String {
+        transliteration::write(self, XSAMPA)
+    }
+}
+
+#[cfg(test)]
+mod xsampa_tests {
+    use super::*;
+    use crate::{corpus, Sound};
+
+    #[test]
+    fn test_round_trip() {
+        let ipa: Ipa = corpus::sounds()
+            .filter(|sound| !matches!(sound, Sound::Consonant { is_reduced: true, .. } | Sound::Vowel { is_reduced: true, .. }))
+            .filter(|sound| !sound.to_string().starts_with('ⱱ'))
+            .filter(|sound| !matches!(sound, Sound::Tone { .. }))
+            .collect();
+        let written = ipa.to_xsampa();
+        assert!(written.is_ascii());
+        assert_eq!(Ipa::from_xsampa(&written), Ok(ipa));
+        // Separated by vowels, as adjacent tones are read as one contour.
+        let vowel = Ipa::try_from("a").unwrap()[0];
+        let tones: Ipa = corpus::sounds()
+            .filter(|sound| matches!(sound, Sound::Tone { .. }))
+            .flat_map(|tone| [vowel, tone])
+            .collect();
+        let written = tones.to_xsampa();
+        assert!(written.is_ascii());
+        assert_eq!(Ipa::from_xsampa(&written), Ok(tones));
+        assert_eq!(Ipa::from_xsampa("ma_H_L").unwrap().to_string(), "ma˦˨");
+        assert_eq!(Ipa::from_xsampa("gʲ"), Err(TransliterationError::UnknownSymbol('ʲ')));
+    }
+}